@@ -34,7 +34,7 @@ fn oauth_client() -> TwitterOauth {
 async fn root(cookies: Cookies) -> impl IntoResponse {
     let oauth = oauth_client();
     let res = oauth.oauth_url();
-    cookies.add(Cookie::new(PKCE_VERIFIER, res.pkce_verifier.clone()));
+    cookies.add(Cookie::new(PKCE_VERIFIER, res.pkce_verifier.expose().to_owned()));
     Html(format!("<a href='{}'>oauth<a>", res.oauth_url)).into_response()
 }
 
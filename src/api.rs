@@ -1,13 +1,23 @@
 use std::time::Duration;
 
+use http::StatusCode;
+#[cfg(feature = "reqwest")]
 use reqwest::RequestBuilder;
+#[cfg(feature = "reqwest")]
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "reqwest")]
+use crate::telemetry;
 use crate::{
     error::{Error, TwitterError},
     headers::Headers,
+    request::TwapiRequest,
+    secret::Secret,
+    validation::{AuthContext, Validator},
 };
 
+// Generated from maker/api/*.yaml by `cargo xtask codegen`.
 pub mod delete_2_lists_id;
 pub mod delete_2_lists_id_members_user_id;
 pub mod delete_2_tweets_id;
@@ -76,7 +86,6 @@ pub mod post_2_dm_conversations_dm_conversation_id_message;
 pub mod post_2_dm_conversations_with_participant_id_message;
 pub mod post_2_lists;
 pub mod post_2_lists_id_members;
-pub mod post_2_oauth2_token_refresh_token;
 pub mod post_2_tweets;
 pub mod post_2_tweets_search_stream_rules;
 pub mod post_2_users_id_blocking;
@@ -89,27 +98,96 @@ pub mod post_2_users_id_pinned_lists;
 pub mod post_2_users_id_retweets;
 pub mod put_2_lists_id;
 pub mod put_2_tweets_id_hidden;
+// End of generated modules.
+
+// Hand-written, not defined in maker/api.
+pub mod post_2_oauth2_token_refresh_token;
 
 const ENV_KEY: &str = "TWAPI_V2_TWITTER_API_PREFIX_API";
 const PREFIX_URL_TWITTER: &str = "https://api.twitter.com";
 
+#[deprecated(note = "process global, use TwapiOptions::prefix_url")]
 pub fn clear_prefix_url() {
-    std::env::set_var(ENV_KEY, PREFIX_URL_TWITTER);
+    crate::runtime::set_var(ENV_KEY, PREFIX_URL_TWITTER);
 }
 
+#[deprecated(note = "process global, use TwapiOptions::prefix_url")]
 pub fn setup_prefix_url(url: &str) {
-    std::env::set_var(ENV_KEY, url);
+    crate::runtime::set_var(ENV_KEY, url);
 }
 
-#[derive(Debug, Clone, Default)]
+/// Client configuration. Share one value between the Apis of a client,
+/// clients with different values can be used in the same process.
+/// Serialized with the Apis, it holds no credentials.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct TwapiOptions {
+    /// Default is https://api.twitter.com
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prefix_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout: Option<Duration>,
+    /// Used by the v1.1 media APIs. Default is `prefix_url` if set, otherwise https://upload.twitter.com
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_prefix_url: Option<String>,
+    /// Default is https://twitter.com/i/oauth2/authorize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oauth_authorize_url: Option<String>,
+    /// Default is `prefix_url` + /2/oauth2/token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oauth_token_url: Option<String>,
+    #[cfg(feature = "upload")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_api_version: Option<crate::upload::MediaApiVersion>,
+    /// Checks fields and expansions before `execute`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator: Option<Validator>,
+}
+
+impl TwapiOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn prefix_url(mut self, value: &str) -> Self {
+        self.prefix_url = Some(value.to_owned());
+        self
+    }
+
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = Some(value);
+        self
+    }
+
+    pub fn upload_prefix_url(mut self, value: &str) -> Self {
+        self.upload_prefix_url = Some(value.to_owned());
+        self
+    }
+
+    pub fn oauth_authorize_url(mut self, value: &str) -> Self {
+        self.oauth_authorize_url = Some(value.to_owned());
+        self
+    }
+
+    pub fn oauth_token_url(mut self, value: &str) -> Self {
+        self.oauth_token_url = Some(value.to_owned());
+        self
+    }
+
+    #[cfg(feature = "upload")]
+    pub fn media_api_version(mut self, value: crate::upload::MediaApiVersion) -> Self {
+        self.media_api_version = Some(value);
+        self
+    }
+
+    pub fn validator(mut self, value: Validator) -> Self {
+        self.validator = Some(value);
+        self
+    }
 }
 
 pub(crate) fn make_url(twapi_options: &Option<TwapiOptions>, post_url: &str) -> String {
     make_url_with_prefix(
-        &std::env::var(ENV_KEY).unwrap_or(PREFIX_URL_TWITTER.to_owned()),
+        &crate::runtime::get_var(ENV_KEY).unwrap_or(PREFIX_URL_TWITTER.to_owned()),
         twapi_options,
         post_url,
     )
@@ -133,44 +211,112 @@ pub(crate) fn make_url_with_prefix(
 }
 
 pub trait Authentication {
+    /// Authorization header value of a request to `uri` with the `options` query, None to send none.
+    fn authorization(&self, method: &str, uri: &str, options: &[(&str, &str)]) -> Option<String>;
+
+    /// Used by `Validator`, context rules are skipped for `AuthContext::Unknown`.
+    fn auth_context(&self) -> AuthContext {
+        AuthContext::Unknown
+    }
+
+    /// Adds the Authorization header to `request`, independent of the http client.
+    fn sign(&self, request: TwapiRequest) -> TwapiRequest {
+        let query = request.query_refs();
+        match self.authorization(&request.method.to_string(), &request.url, &query) {
+            Some(value) => request.header("authorization", &value),
+            None => request,
+        }
+    }
+
+    /// Adds the Authorization header to a reqwest builder.
+    #[cfg(feature = "reqwest")]
     fn execute(
         &self,
         builder: RequestBuilder,
         method: &str,
         uri: &str,
         options: &[(&str, &str)],
-    ) -> RequestBuilder;
+    ) -> RequestBuilder {
+        let value = self
+            .authorization(method, uri, options)
+            .and_then(|it| reqwest::header::HeaderValue::from_str(&it).ok());
+        match value {
+            Some(mut value) => {
+                value.set_sensitive(true);
+                builder.header(reqwest::header::AUTHORIZATION, value)
+            }
+            None => builder,
+        }
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct BearerAuthentication {
-    bearer_code: String,
+    bearer_code: Secret,
+    auth_context: AuthContext,
 }
 
 impl BearerAuthentication {
-    pub fn new<T: Into<String>>(bearer_code: T) -> Self {
+    pub fn new<T: Into<Secret>>(bearer_code: T) -> Self {
         Self {
             bearer_code: bearer_code.into(),
+            auth_context: AuthContext::Unknown,
         }
     }
+
+    /// App only bearer token. Default is unknown, the token may be an OAuth 2.0 user token.
+    pub fn app_only(mut self) -> Self {
+        self.auth_context = AuthContext::App;
+        self
+    }
+
+    /// OAuth 2.0 user token.
+    pub fn user_context(mut self) -> Self {
+        self.auth_context = AuthContext::User;
+        self
+    }
 }
 
 impl Authentication for BearerAuthentication {
-    fn execute(
+    fn auth_context(&self) -> AuthContext {
+        self.auth_context
+    }
+
+    fn authorization(
         &self,
-        builder: RequestBuilder,
         _method: &str,
         _uri: &str,
         _options: &[(&str, &str)],
-    ) -> RequestBuilder {
-        builder.bearer_auth(&self.bearer_code)
+    ) -> Option<String> {
+        Some(format!("Bearer {}", self.bearer_code.expose()))
     }
 }
 
+#[cfg(feature = "reqwest")]
 pub async fn execute_twitter<T>(builder: RequestBuilder) -> Result<(T, Headers), Error>
 where
     T: DeserializeOwned,
 {
-    let response = builder.send().await?;
+    execute_twitter_attempt(builder, 0).await
+}
+
+#[cfg(feature = "reqwest")]
+pub(crate) async fn execute_twitter_attempt<T>(
+    builder: RequestBuilder,
+    attempt: usize,
+) -> Result<(T, Headers), Error>
+where
+    T: DeserializeOwned,
+{
+    let response = telemetry::send(builder, attempt).await?;
+    parse_twitter(response).await
+}
+
+#[cfg(feature = "reqwest")]
+pub(crate) async fn parse_twitter<T>(response: reqwest::Response) -> Result<(T, Headers), Error>
+where
+    T: DeserializeOwned,
+{
     let status_code = response.status();
     let header = response.headers();
     let headers = Headers::new(header);
@@ -179,26 +325,30 @@ where
         Ok((response.json::<T>().await?, headers))
     } else {
         let text = response.text().await?;
-        match serde_json::from_str(&text) {
-            Ok(value) => Err(Error::Twitter(
-                TwitterError::new(&value, status_code),
-                value,
-                Box::new(headers),
-            )),
-            Err(err) => Err(Error::Other(format!("{:?}", err), Some(status_code))),
-        }
+        Err(make_twitter_error(&text, status_code, headers))
     }
 }
 
-pub(crate) fn apply_options(
-    client: RequestBuilder,
-    options: &Option<TwapiOptions>,
-) -> RequestBuilder {
-    let Some(options) = options else {
-        return client;
-    };
-    let Some(timeout) = options.timeout else {
-        return client;
-    };
-    client.timeout(timeout)
+pub(crate) fn make_twitter_error(text: &str, status_code: StatusCode, headers: Headers) -> Error {
+    match serde_json::from_str(text) {
+        Ok(value) => Error::Twitter(
+            Box::new(TwitterError::new(&value, status_code)),
+            value,
+            Box::new(headers),
+        ),
+        Err(err) => Error::Other(format!("{:?}", err), Some(status_code)),
+    }
+}
+
+// Compares the tail of `path`, the prefix url may contain a path.
+pub(crate) fn matches_template(template: &str, path: &str) -> bool {
+    let template: Vec<&str> = template.split('/').filter(|it| !it.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').filter(|it| !it.is_empty()).collect();
+    if template.len() > path.len() {
+        return false;
+    }
+    template
+        .iter()
+        .zip(path[path.len() - template.len()..].iter())
+        .all(|(t, p)| t.starts_with(':') || t == p)
 }
@@ -1,17 +1,19 @@
 use crate::responses::errors::Errors;
 use crate::{
-    api::{apply_options, execute_twitter, make_url, Authentication, TwapiOptions},
+    api::{make_url, Authentication, TwapiOptions},
+    endpoint::Endpoint,
     error::Error,
     headers::Headers,
+    request::{execute_request, HttpClient, Method, TwapiRequest},
 };
-use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 
 const URL: &str = "/2/lists/:id";
 
-#[derive(Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Api {
     id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     twapi_options: Option<TwapiOptions>,
 }
 
@@ -28,23 +30,55 @@ impl Api {
         self
     }
 
-    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
-        let client = reqwest::Client::new();
+    pub fn request(self) -> TwapiRequest {
         let url = make_url(&self.twapi_options, &URL.replace(":id", &self.id));
-        let builder = client.delete(&url);
-        authentication.execute(
-            apply_options(builder, &self.twapi_options),
-            "DELETE",
-            &url,
-            &[],
-        )
+        TwapiRequest::new(Method::Delete, &url).twapi_options(&self.twapi_options)
     }
 
+    #[cfg(feature = "reqwest")]
+    pub fn build(self, authentication: &impl Authentication) -> reqwest::RequestBuilder {
+        self.request().build(authentication)
+    }
+
+    #[cfg(feature = "reqwest")]
     pub async fn execute(
         self,
         authentication: &impl Authentication,
     ) -> Result<(Response, Headers), Error> {
-        execute_twitter(self.build(authentication)).await
+        let request = self.request().validate(authentication)?;
+        crate::api::execute_twitter(request.build(authentication)).await
+    }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        execute_request(client, request.authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        crate::blocking::execute_twitter(request.build(authentication))
+    }
+}
+
+impl Endpoint for Api {
+    type Response = Response;
+    const METHOD: Method = Method::Delete;
+    const TEMPLATE: &'static str = URL;
+
+    fn path_params(&self) -> Vec<(&'static str, String)> {
+        vec![("id", self.id.clone())]
+    }
+
+    fn request(self) -> TwapiRequest {
+        Api::request(self)
     }
 }
 
@@ -1,10 +1,11 @@
 use crate::responses::{jobs::Jobs, meta::Meta};
 use crate::{
-    api::{apply_options, execute_twitter, make_url, Authentication, TwapiOptions},
+    api::{make_url, Authentication, TwapiOptions},
+    endpoint::Endpoint,
     error::Error,
     headers::Headers,
+    request::{execute_request, HttpClient, Method, TwapiRequest},
 };
-use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 
 const URL: &str = "/2/compliance/jobs";
@@ -61,10 +62,12 @@ impl Default for Status {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Api {
     r#type: Type,
+    #[serde(skip_serializing_if = "Option::is_none")]
     status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     twapi_options: Option<TwapiOptions>,
 }
 
@@ -86,31 +89,62 @@ impl Api {
         self
     }
 
-    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+    pub fn request(self) -> TwapiRequest {
         let mut query_parameters = vec![];
         query_parameters.push(("type", self.r#type.to_string()));
         if let Some(status) = self.status {
             query_parameters.push(("status", status.to_string()));
         }
-        let client = reqwest::Client::new();
         let url = make_url(&self.twapi_options, URL);
-        let builder = client.get(&url).query(&query_parameters);
-        authentication.execute(
-            apply_options(builder, &self.twapi_options),
-            "GET",
-            &url,
-            &query_parameters
-                .iter()
-                .map(|it| (it.0, it.1.as_str()))
-                .collect::<Vec<_>>(),
-        )
+        TwapiRequest::new(Method::Get, &url)
+            .query(query_parameters)
+            .twapi_options(&self.twapi_options)
     }
 
+    #[cfg(feature = "reqwest")]
+    pub fn build(self, authentication: &impl Authentication) -> reqwest::RequestBuilder {
+        self.request().build(authentication)
+    }
+
+    #[cfg(feature = "reqwest")]
     pub async fn execute(
         self,
         authentication: &impl Authentication,
     ) -> Result<(Response, Headers), Error> {
-        execute_twitter(self.build(authentication)).await
+        let request = self.request().validate(authentication)?;
+        crate::api::execute_twitter(request.build(authentication)).await
+    }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        execute_request(client, request.authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        crate::blocking::execute_twitter(request.build(authentication))
+    }
+}
+
+impl Endpoint for Api {
+    type Response = Response;
+    const METHOD: Method = Method::Get;
+    const TEMPLATE: &'static str = URL;
+
+    fn path_params(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+
+    fn request(self) -> TwapiRequest {
+        Api::request(self)
     }
 }
 
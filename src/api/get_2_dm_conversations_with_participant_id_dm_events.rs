@@ -4,12 +4,13 @@ use crate::fields::{
 };
 use crate::responses::{dm_events::DmEvents, errors::Errors, includes::Includes, meta::Meta};
 use crate::{
-    api::{apply_options, execute_twitter, make_url, Authentication, TwapiOptions},
+    api::{make_url, Authentication, TwapiOptions},
+    endpoint::Endpoint,
     error::Error,
     headers::Headers,
+    request::{execute_request, HttpClient, Method, TwapiRequest},
 };
 use itertools::Itertools;
-use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -81,17 +82,26 @@ impl Default for Expansions {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Api {
     participant_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     dm_event_fields: Option<HashSet<DmEventFields>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     event_types: Option<EventTypes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     expansions: Option<HashSet<Expansions>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     max_results: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     media_fields: Option<HashSet<MediaFields>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pagination_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     tweet_fields: Option<HashSet<TweetFields>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     user_fields: Option<HashSet<UserFields>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     twapi_options: Option<TwapiOptions>,
 }
 
@@ -174,7 +184,7 @@ impl Api {
         self
     }
 
-    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+    pub fn request(self) -> TwapiRequest {
         let mut query_parameters = vec![];
         if let Some(dm_event_fields) = self.dm_event_fields {
             query_parameters.push(("dm_event.fields", dm_event_fields.iter().join(",")));
@@ -200,28 +210,68 @@ impl Api {
         if let Some(user_fields) = self.user_fields {
             query_parameters.push(("user.fields", user_fields.iter().join(",")));
         }
-        let client = reqwest::Client::new();
         let url = make_url(
             &self.twapi_options,
             &URL.replace(":participant_id", &self.participant_id),
         );
-        let builder = client.get(&url).query(&query_parameters);
-        authentication.execute(
-            apply_options(builder, &self.twapi_options),
-            "GET",
-            &url,
-            &query_parameters
-                .iter()
-                .map(|it| (it.0, it.1.as_str()))
-                .collect::<Vec<_>>(),
-        )
+        TwapiRequest::new(Method::Get, &url)
+            .query(query_parameters)
+            .twapi_options(&self.twapi_options)
+    }
+
+    #[cfg(feature = "reqwest")]
+    pub fn build(self, authentication: &impl Authentication) -> reqwest::RequestBuilder {
+        self.request().build(authentication)
     }
 
+    #[cfg(feature = "reqwest")]
     pub async fn execute(
         self,
         authentication: &impl Authentication,
     ) -> Result<(Response, Headers), Error> {
-        execute_twitter(self.build(authentication)).await
+        let request = self.request().validate(authentication)?;
+        crate::api::execute_twitter(request.build(authentication)).await
+    }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        execute_request(client, request.authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        crate::blocking::execute_twitter(request.build(authentication))
+    }
+}
+
+impl Endpoint for Api {
+    type Response = Response;
+    const METHOD: Method = Method::Get;
+    const TEMPLATE: &'static str = URL;
+    const PAGINATION: Option<&'static str> = Some("pagination_token");
+
+    fn path_params(&self) -> Vec<(&'static str, String)> {
+        vec![("participant_id", self.participant_id.clone())]
+    }
+
+    fn request(self) -> TwapiRequest {
+        Api::request(self)
+    }
+
+    fn next_token(response: &Response) -> Option<String> {
+        response.meta.as_ref().and_then(|it| it.next_token.clone())
+    }
+
+    fn with_pagination_token(self, token: &str) -> Self {
+        self.pagination_token(token)
     }
 }
 
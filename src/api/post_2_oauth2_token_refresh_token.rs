@@ -1,9 +1,10 @@
 use crate::{
-    api::{execute_twitter, make_url, TwapiOptions},
+    api::{make_url, TwapiOptions},
     error::Error,
     headers::Headers,
+    request::{execute_request, HttpClient, Method, TwapiRequest},
+    secret::Secret,
 };
-use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 
 const URL: &str = "/2/oauth2/token";
@@ -11,8 +12,8 @@ const URL: &str = "/2/oauth2/token";
 #[derive(Debug, Clone, Default)]
 pub struct Api {
     api_key_code: String,
-    api_secret_code: String,
-    refresh_token: String,
+    api_secret_code: Secret,
+    refresh_token: Secret,
     twapi_options: Option<TwapiOptions>,
 }
 
@@ -20,8 +21,8 @@ impl Api {
     pub fn new(api_key_code: &str, api_secret_code: &str, refresh_token: &str) -> Self {
         Self {
             api_key_code: api_key_code.to_owned(),
-            api_secret_code: api_secret_code.to_owned(),
-            refresh_token: refresh_token.to_owned(),
+            api_secret_code: api_secret_code.into(),
+            refresh_token: refresh_token.into(),
             ..Default::default()
         }
     }
@@ -31,30 +32,53 @@ impl Api {
         self
     }
 
-    pub fn build(self) -> RequestBuilder {
+    pub fn request(self) -> TwapiRequest {
         let form_parameters = vec![
             ("client_id", self.api_key_code.clone()),
             ("grant_type", "refresh_token".to_owned()),
-            ("refresh_token", self.refresh_token),
+            ("refresh_token", self.refresh_token.expose().to_owned()),
         ];
 
-        let client = reqwest::Client::new();
-        let url = make_url(&self.twapi_options, URL);
-        client
-            .post(url)
-            .form(&form_parameters)
-            .basic_auth(&self.api_key_code, Some(&self.api_secret_code))
+        let url = match self
+            .twapi_options
+            .as_ref()
+            .and_then(|it| it.oauth_token_url.as_ref())
+        {
+            Some(url) => url.clone(),
+            None => make_url(&self.twapi_options, URL),
+        };
+        TwapiRequest::new(Method::Post, &url)
+            .form(form_parameters)
+            .basic_auth(&self.api_key_code, self.api_secret_code.expose())
     }
 
+    #[cfg(feature = "reqwest")]
+    pub fn build(self) -> reqwest::RequestBuilder {
+        self.request().into_reqwest()
+    }
+
+    #[cfg(feature = "reqwest")]
     pub async fn execute(self) -> Result<(Response, Headers), Error> {
-        execute_twitter(self.build()).await
+        crate::api::execute_twitter(self.build()).await
+    }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+    ) -> Result<(Response, Headers), Error> {
+        execute_request(client, self.request()).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(self) -> Result<(Response, Headers), Error> {
+        crate::blocking::execute_twitter(self.build())
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Response {
-    pub access_token: Option<String>,
-    pub refresh_token: Option<String>,
+    pub access_token: Option<Secret>,
+    pub refresh_token: Option<Secret>,
     pub expires_in: Option<i64>,
     pub token_type: Option<String>,
     pub scope: Option<String>,
@@ -0,0 +1,118 @@
+use reqwest::blocking;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    api::make_twitter_error,
+    error::Error,
+    headers::Headers,
+    request::{Body, Method, TwapiRequest},
+    telemetry::RequestSpan,
+};
+
+/// Convert a request built by `Api::build`. Streaming bodies such as multipart are not supported.
+pub fn into_blocking(builder: reqwest::RequestBuilder) -> Result<blocking::RequestBuilder, Error> {
+    let request = builder.build()?;
+    let mut result = blocking::Client::new()
+        .request(request.method().clone(), request.url().clone())
+        .headers(request.headers().clone());
+    if let Some(timeout) = request.timeout() {
+        result = result.timeout(*timeout);
+    }
+    if let Some(body) = request.body() {
+        let bytes = body.as_bytes().ok_or(Error::Other(
+            "streaming body is not supported".to_owned(),
+            None,
+        ))?;
+        result = result.body(bytes.to_vec());
+    }
+    Ok(result)
+}
+
+/// Convert a `TwapiRequest`, multipart bodies included.
+pub fn from_request(request: TwapiRequest) -> blocking::RequestBuilder {
+    let method = match request.method {
+        Method::Get => reqwest::Method::GET,
+        Method::Post => reqwest::Method::POST,
+        Method::Put => reqwest::Method::PUT,
+        Method::Delete => reqwest::Method::DELETE,
+    };
+    let mut builder = blocking::Client::new().request(method, &request.url);
+    if !request.query.is_empty() {
+        builder = builder.query(&request.query);
+    }
+    for (name, value) in request.headers.iter() {
+        builder = builder.header(name, value);
+    }
+    builder = match request.body {
+        Some(Body::Json(value)) => builder.json(&value),
+        Some(Body::Form(value)) => builder.form(&value),
+        #[cfg(feature = "upload")]
+        Some(Body::Multipart(value)) => {
+            let mut form = blocking::multipart::Form::new();
+            for (name, part) in value.parts {
+                form = match part {
+                    crate::request::Part::Text(text) => form.text(name, text),
+                    crate::request::Part::Bytes(bytes) => {
+                        form.part(name, blocking::multipart::Part::bytes(bytes))
+                    }
+                };
+            }
+            builder.multipart(form)
+        }
+        None => builder,
+    };
+    match request.timeout {
+        Some(timeout) => builder.timeout(timeout),
+        None => builder,
+    }
+}
+
+pub fn execute_twitter<T>(builder: reqwest::RequestBuilder) -> Result<(T, Headers), Error>
+where
+    T: DeserializeOwned,
+{
+    execute_twitter_blocking(into_blocking(builder)?)
+}
+
+pub fn execute_twitter_blocking<T>(builder: blocking::RequestBuilder) -> Result<(T, Headers), Error>
+where
+    T: DeserializeOwned,
+{
+    let response = send(builder)?;
+    let status_code = response.status();
+    let headers = Headers::new(response.headers());
+    if status_code.is_success() {
+        Ok((response.json::<T>()?, headers))
+    } else {
+        let text = response.text()?;
+        Err(make_twitter_error(&text, status_code, headers))
+    }
+}
+
+#[cfg(feature = "upload")]
+pub(crate) fn execute_no_response(builder: blocking::RequestBuilder) -> Result<Headers, Error> {
+    let response = send(builder)?;
+    let status_code = response.status();
+    let headers = Headers::new(response.headers());
+    if status_code.is_success() {
+        Ok(headers)
+    } else {
+        let body = response.text()?;
+        Err(Error::Other(body, Some(status_code)))
+    }
+}
+
+fn send(builder: blocking::RequestBuilder) -> Result<blocking::Response, Error> {
+    // builders are always created from a default client
+    let request = builder.build()?;
+    let span = RequestSpan::new(request.method(), request.url(), 0);
+    let result = {
+        let _enter = span.span.enter();
+        blocking::Client::new().execute(request)
+    };
+    match &result {
+        Ok(response) => span.finish(Ok((response.status(), response.headers()))),
+        Err(err) => span.finish(Err(err)),
+    }
+    Ok(result?)
+}
@@ -0,0 +1,442 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::Duration,
+};
+
+use chrono::prelude::*;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method, Request, RequestBuilder,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    api::{execute_twitter, make_twitter_error},
+    error::Error,
+    headers::Headers,
+    request::{auth_identity, encode, fnv1a},
+    telemetry::{self, endpoint_template},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    Hit,
+    Miss,
+    /// Not a cacheable request, or the ttl is zero.
+    Bypass,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CacheEntry {
+    pub key: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl CacheEntry {
+    pub(crate) fn new(key: String, header_map: &HeaderMap, body: String, ttl: Duration) -> Self {
+        Self {
+            key,
+            headers: header_map
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_owned()))
+                })
+                .collect(),
+            body,
+            expires_at: Utc::now() + ttl,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    // A 200 response for `middleware::CacheResponses`, reqwest can not make one on wasm32.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn response(&self) -> reqwest::Response {
+        let mut response = http::Response::new(self.body.clone());
+        *response.status_mut() = reqwest::StatusCode::OK;
+        *response.headers_mut() = self.header_map();
+        reqwest::Response::from(response)
+    }
+
+    // Rate limit headers of the stored response are stale on a hit, they are dropped.
+    fn header_map(&self) -> HeaderMap {
+        let mut result = HeaderMap::new();
+        for (name, value) in self.headers.iter() {
+            if name.to_lowercase().starts_with("x-rate-limit-") {
+                continue;
+            }
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                result.append(name, value);
+            }
+        }
+        result
+    }
+}
+
+pub trait Cache {
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+    fn put(&self, entry: CacheEntry);
+    fn remove(&self, key: &str);
+}
+
+#[derive(Default)]
+struct MemoryState {
+    tick: u64,
+    // key -> last used tick and entry
+    entries: HashMap<String, (u64, CacheEntry)>,
+    // last used tick -> key, the first one is the least recently used
+    used: BTreeMap<u64, String>,
+}
+
+impl MemoryState {
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some((used, _)) = self.entries.get_mut(key) {
+            self.used.remove(used);
+            *used = tick;
+            self.used.insert(tick, key.to_owned());
+        }
+    }
+}
+
+/// Least recently used entries are evicted when `capacity` is exceeded.
+pub struct MemoryCache {
+    capacity: usize,
+    state: Mutex<MemoryState>,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(MemoryState::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let mut state = self.state.lock().unwrap();
+        state.touch(key);
+        state.entries.get(key).map(|(_, entry)| entry.clone())
+    }
+
+    fn put(&self, entry: CacheEntry) {
+        let mut state = self.state.lock().unwrap();
+        let key = entry.key.clone();
+        if let Some((used, _)) = state.entries.insert(key.clone(), (0, entry)) {
+            state.used.remove(&used);
+        }
+        state.used.insert(0, key.clone());
+        state.touch(&key);
+        while state.entries.len() > self.capacity {
+            let Some((_, oldest)) = state.used.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let mut state = self.state.lock().unwrap();
+        if let Some((used, _)) = state.entries.remove(key) {
+            state.used.remove(&used);
+        }
+    }
+}
+
+/// One JSON file per entry in `dir`. Not available on wasm32.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DiskCache {
+    pub fn new<T: Into<PathBuf>>(dir: T) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", fnv1a(key)))
+    }
+
+    /// Delete expired files.
+    pub fn purge(&self) -> std::io::Result<()> {
+        for file in std::fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            let expired = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|it| serde_json::from_str::<CacheEntry>(&it).ok())
+                .map(|it| it.is_expired())
+                .unwrap_or(false);
+            if expired {
+                std::fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Cache for DiskCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let text = std::fs::read_to_string(self.path(key)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&text).ok()?;
+        // different keys may share a file name
+        (entry.key == key).then_some(entry)
+    }
+
+    fn put(&self, entry: CacheEntry) {
+        if let Ok(text) = serde_json::to_string(&entry) {
+            let _ = std::fs::write(self.path(&entry.key), text);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let _ = std::fs::remove_file(self.path(key));
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CacheOptions {
+    ttl: Duration,
+    endpoint_ttls: Vec<(String, Duration)>,
+}
+
+impl Default for CacheOptions {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(60))
+    }
+}
+
+impl CacheOptions {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            endpoint_ttls: vec![],
+        }
+    }
+
+    /// `url` is an endpoint template such as `/2/users/:id`. Zero disables caching for it.
+    pub fn endpoint_ttl(mut self, url: &str, ttl: Duration) -> Self {
+        self.endpoint_ttls.push((url.to_owned(), ttl));
+        self
+    }
+
+    // The most specific template of the path, `/2/users/:id` does not apply to `/2/users/me`.
+    pub(crate) fn ttl(&self, path: &str) -> Duration {
+        let Some(template) = endpoint_template("GET", path) else {
+            return self.ttl;
+        };
+        self.endpoint_ttls
+            .iter()
+            .find(|(url, _)| url == template)
+            .map(|(_, ttl)| *ttl)
+            .unwrap_or(self.ttl)
+    }
+}
+
+/// `None` for requests that should not be cached.
+pub fn cache_key(builder: &RequestBuilder) -> Option<(String, String)> {
+    request_cache_key(&builder.try_clone()?.build().ok()?)
+}
+
+pub(crate) fn request_cache_key(request: &Request) -> Option<(String, String)> {
+    if request.method() != Method::GET {
+        return None;
+    }
+    let url = request.url();
+    let mut query: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            // HashSet order is random, other values are kept as they are.
+            if key == "expansions" || key.ends_with(".fields") {
+                let mut values: Vec<&str> = value.split(',').collect();
+                values.sort();
+                (key.to_string(), values.join(","))
+            } else {
+                (key.to_string(), value.to_string())
+            }
+        })
+        .collect();
+    query.sort();
+    // Escaped, `a=b%26c` and `a=b&c` are different keys.
+    let query = query
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    let key = format!(
+        "GET {}://{}{}{}?{} {}",
+        url.scheme(),
+        url.host_str().unwrap_or_default().to_lowercase(),
+        url.port().map(|it| format!(":{}", it)).unwrap_or_default(),
+        url.path(),
+        query,
+        auth_identity(request.headers())
+    );
+    Some((key, url.path().to_owned()))
+}
+
+pub async fn execute_cache<T>(
+    builder: RequestBuilder,
+    cache: &impl Cache,
+    options: &CacheOptions,
+) -> Result<(T, Headers, CacheStatus), Error>
+where
+    T: DeserializeOwned,
+{
+    let Some((key, path)) = cache_key(&builder) else {
+        let (response, headers) = execute_twitter(builder).await?;
+        return Ok((response, headers, CacheStatus::Bypass));
+    };
+    let ttl = options.ttl(&path);
+    if ttl.is_zero() {
+        let (response, headers) = execute_twitter(builder).await?;
+        return Ok((response, headers, CacheStatus::Bypass));
+    }
+
+    if let Some(entry) = cache.get(&key) {
+        if !entry.is_expired() {
+            if let Ok(response) = serde_json::from_str(&entry.body) {
+                return Ok((
+                    response,
+                    Headers::new(&entry.header_map()),
+                    CacheStatus::Hit,
+                ));
+            }
+        }
+        cache.remove(&key);
+    }
+
+    let response = telemetry::send(builder, 0).await?;
+    let status_code = response.status();
+    let header_map = response.headers().clone();
+    let headers = Headers::new(&header_map);
+    let text = response.text().await?;
+    if !status_code.is_success() {
+        return Err(make_twitter_error(&text, status_code, headers));
+    }
+    let result = serde_json::from_str(&text)?;
+    cache.put(CacheEntry::new(key, &header_map, text, ttl));
+    Ok((result, headers, CacheStatus::Miss))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{get_2_tweets_search_recent, get_2_users_id, post_2_tweets, BearerAuthentication},
+        fields::user_fields::UserFields,
+    };
+
+    #[test]
+    fn it_cache_key() {
+        let auth = BearerAuthentication::new("token");
+        let (key1, path) = cache_key(
+            &get_2_users_id::Api::new("1")
+                .user_fields([UserFields::Id, UserFields::Name].into())
+                .build(&auth),
+        )
+        .unwrap();
+        let (key2, _) = cache_key(
+            &get_2_users_id::Api::new("1")
+                .user_fields([UserFields::Name, UserFields::Id].into())
+                .build(&auth),
+        )
+        .unwrap();
+        assert_eq!(key1, key2);
+        assert_eq!(path, "/2/users/1");
+        let other = BearerAuthentication::new("other");
+        let (key3, _) = cache_key(&get_2_users_id::Api::new("1").build(&other)).unwrap();
+        assert_ne!(key1, key3);
+        assert!(cache_key(&post_2_tweets::Api::new(Default::default()).build(&auth)).is_none());
+
+        let options = CacheOptions::default().endpoint_ttl("/2/users/:id", Duration::ZERO);
+        assert!(options.ttl("/2/users/1").is_zero());
+        assert!(!options.ttl("/2/users/1/followers").is_zero());
+        assert!(!options.ttl("/2/users/me").is_zero());
+        assert!(!options.ttl("/2/users/by").is_zero());
+    }
+
+    #[test]
+    fn it_cache_key_escape() {
+        let auth = BearerAuthentication::new("token");
+        let (key1, _) =
+            cache_key(&get_2_tweets_search_recent::Api::new("a&since_id=1").build(&auth)).unwrap();
+        let (key2, _) = cache_key(
+            &get_2_tweets_search_recent::Api::new("a")
+                .since_id("1")
+                .build(&auth),
+        )
+        .unwrap();
+        assert_ne!(key1, key2);
+    }
+
+    #[test]
+    fn it_header_map() {
+        let entry = CacheEntry {
+            key: "a".to_owned(),
+            headers: vec![
+                ("content-type".to_owned(), "application/json".to_owned()),
+                ("x-rate-limit-remaining".to_owned(), "10".to_owned()),
+            ],
+            body: "{}".to_owned(),
+            expires_at: Utc::now(),
+        };
+        let headers = entry.header_map();
+        assert!(headers.contains_key("content-type"));
+        assert!(!headers.contains_key("x-rate-limit-remaining"));
+    }
+
+    #[test]
+    fn it_memory_cache() {
+        let cache = MemoryCache::new(2);
+        let entry = |key: &str| CacheEntry {
+            key: key.to_owned(),
+            headers: vec![],
+            body: "{}".to_owned(),
+            expires_at: Utc::now(),
+        };
+        cache.put(entry("a"));
+        cache.put(entry("b"));
+        cache.get("a");
+        cache.put(entry("c"));
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+
+        // Putting an existing key refreshes it without growing the cache.
+        cache.put(entry("a"));
+        cache.put(entry("d"));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_none());
+        cache.remove("a");
+        cache.put(entry("e"));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("d").is_some());
+    }
+}
@@ -0,0 +1,355 @@
+//! Record and replay requests, VCR style.
+//!
+//! `Cassette` is an `HttpClient`. In record mode it sends requests through the inner client and keeps the
+//! interactions, in replay mode it answers from them. Use it with `execute_with`.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error::Error,
+    request::{Body, HttpClient, HttpResponse, ReqwestClient, TwapiRequest},
+    secret::{REDACTED, SENSITIVE_HEADERS},
+    telemetry::endpoint_template,
+};
+
+/// Response headers which are not recorded.
+const SCRUBBED_HEADERS: [&str; 2] = ["x-transaction-id", "set-cookie"];
+/// Response body keys which are recorded as `[REDACTED]`.
+const SCRUBBED_KEYS: [&str; 2] = ["access_token", "refresh_token"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Record,
+    Replay,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Interaction {
+    pub method: String,
+    /// Endpoint template such as `/2/users/:id`, or the path for unknown endpoints.
+    pub endpoint: String,
+    pub url: String,
+    pub query: Vec<(String, String)>,
+    pub request_headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<Value>,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    /// Text bodies which are not JSON are stored as a string.
+    pub body: Value,
+}
+
+impl Interaction {
+    fn new(request: &TwapiRequest, response: &HttpResponse) -> Self {
+        let request_headers = request
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                if SENSITIVE_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+                    (name.clone(), REDACTED.to_owned())
+                } else {
+                    (name.clone(), value.clone())
+                }
+            })
+            .collect();
+        let request_body = match &request.body {
+            Some(Body::Json(value)) => Some(value.clone()),
+            _ => None,
+        };
+        let headers = response
+            .headers
+            .iter()
+            .filter(|(name, _)| {
+                let name = name.to_ascii_lowercase();
+                !SCRUBBED_HEADERS.contains(&name.as_str())
+            })
+            .cloned()
+            .collect();
+        let body = match serde_json::from_slice(&response.body) {
+            Ok(mut value) => {
+                scrub(&mut value);
+                value
+            }
+            Err(_) => Value::String(String::from_utf8_lossy(&response.body).to_string()),
+        };
+        Self {
+            method: request.method.to_string(),
+            endpoint: endpoint(request),
+            url: request.url.clone(),
+            query: request.query.clone(),
+            request_headers,
+            request_body,
+            status: response.status,
+            headers,
+            body,
+        }
+    }
+
+    fn matches(&self, request: &TwapiRequest) -> bool {
+        self.method == request.method.to_string()
+            && self.endpoint == endpoint(request)
+            && normalize(&self.query) == normalize(&request.query)
+    }
+
+    fn response(&self) -> HttpResponse {
+        let body = match &self.body {
+            Value::String(text) => text.clone().into_bytes(),
+            value => value.to_string().into_bytes(),
+        };
+        HttpResponse {
+            status: self.status,
+            headers: self.headers.clone(),
+            body,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+}
+
+#[derive(Debug)]
+pub struct Cassette<C = ReqwestClient> {
+    mode: Mode,
+    client: Option<C>,
+    state: Mutex<State>,
+}
+
+impl Cassette {
+    pub fn replay(interactions: Vec<Interaction>) -> Self {
+        Self::replaying(interactions)
+    }
+
+    pub fn from_json(src: &str) -> Result<Self, Error> {
+        Ok(Self::replay(serde_json::from_str(src)?))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> Result<Self, Error> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+}
+
+impl<C: HttpClient> Cassette<C> {
+    pub fn record(client: C) -> Self {
+        Self {
+            mode: Mode::Record,
+            client: Some(client),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Replay when the file exists, otherwise record with the client.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open<P: AsRef<std::path::Path>>(path: P, client: C) -> Result<Self, Error> {
+        if !path.as_ref().exists() {
+            return Ok(Self::record(client));
+        }
+        let interactions = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        Ok(Self::replaying(interactions))
+    }
+}
+
+impl<C> Cassette<C> {
+    fn replaying(interactions: Vec<Interaction>) -> Self {
+        Self {
+            mode: Mode::Replay,
+            client: None,
+            state: Mutex::new(State {
+                used: vec![false; interactions.len()],
+                interactions,
+            }),
+        }
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state.lock().unwrap().interactions.clone()
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(
+            &self.state.lock().unwrap().interactions,
+        )?)
+    }
+
+    /// Does nothing in replay mode.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), Error> {
+        if self.mode == Mode::Replay {
+            return Ok(());
+        }
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    /// Same requests are answered in recorded order, the last one repeats.
+    fn find(&self, request: &TwapiRequest) -> Result<HttpResponse, Error> {
+        let mut state = self.state.lock().unwrap();
+        let indexes: Vec<usize> = state
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, it)| it.matches(request))
+            .map(|(index, _)| index)
+            .collect();
+        let index = indexes
+            .iter()
+            .find(|index| !state.used[**index])
+            .or(indexes.last())
+            .copied()
+            .ok_or_else(|| {
+                Error::Other(
+                    format!(
+                        "no interaction recorded for {} {}",
+                        request.method,
+                        request.url_with_query()
+                    ),
+                    None,
+                )
+            })?;
+        state.used[index] = true;
+        Ok(state.interactions[index].response())
+    }
+}
+
+impl<C: HttpClient> HttpClient for Cassette<C> {
+    async fn execute(&self, request: TwapiRequest) -> Result<HttpResponse, Error> {
+        let client = match (&self.mode, &self.client) {
+            (Mode::Record, Some(client)) => client,
+            _ => return self.find(&request),
+        };
+        let response = client.execute(request.clone()).await?;
+        let interaction = Interaction::new(&request, &response);
+        let mut state = self.state.lock().unwrap();
+        state.interactions.push(interaction);
+        state.used.push(true);
+        Ok(response)
+    }
+}
+
+fn endpoint(request: &TwapiRequest) -> String {
+    let path = match request.url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map(|index| &rest[index..]).unwrap_or("/"),
+        None => &request.url,
+    };
+    endpoint_template(&request.method.to_string(), path)
+        .map(|it| it.to_owned())
+        .unwrap_or_else(|| path.to_owned())
+}
+
+/// Fields and expansions are joined from a HashSet, so the items of a value are sorted too.
+fn normalize(query: &[(String, String)]) -> Vec<(&str, String)> {
+    let mut result: Vec<(&str, String)> = query
+        .iter()
+        .map(|(key, value)| {
+            let mut items: Vec<&str> = value.split(',').collect();
+            items.sort();
+            (key.as_str(), items.join(","))
+        })
+        .collect();
+    result.sort();
+    result
+}
+
+fn scrub(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SCRUBBED_KEYS.contains(&key.as_str()) {
+                    *value = Value::String(REDACTED.to_owned());
+                } else {
+                    scrub(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(scrub),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{get_2_users_id, BearerAuthentication},
+        request::Method,
+    };
+
+    struct FakeClient;
+
+    impl HttpClient for FakeClient {
+        async fn execute(&self, request: TwapiRequest) -> Result<HttpResponse, Error> {
+            let id = request
+                .url
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_owned();
+            Ok(HttpResponse {
+                status: 200,
+                headers: vec![
+                    ("x-transaction-id".to_owned(), "abc".to_owned()),
+                    ("x-rate-limit-remaining".to_owned(), "9".to_owned()),
+                ],
+                body: format!(r#"{{"data":{{"id":"{}","name":"a","username":"b"}}}}"#, id)
+                    .into_bytes(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn it_record_replay() {
+        let auth = BearerAuthentication::new("token");
+        let cassette = Cassette::record(FakeClient);
+        for id in ["1", "2"] {
+            get_2_users_id::Api::new(id)
+                .execute_with(&cassette, &auth)
+                .await
+                .unwrap();
+        }
+        let json = cassette.to_json().unwrap();
+        assert!(!json.contains("token"));
+        assert!(!json.contains("abc"));
+
+        let cassette = Cassette::from_json(&json).unwrap();
+        assert_eq!(cassette.mode(), Mode::Replay);
+        assert_eq!(cassette.interactions()[0].endpoint, "/2/users/:id");
+        // Matched by the endpoint template, so the recorded order is replayed.
+        for id in ["1", "2", "2"] {
+            let (response, headers) = get_2_users_id::Api::new("0")
+                .execute_with(&cassette, &auth)
+                .await
+                .unwrap();
+            assert_eq!(response.data.unwrap().id, id);
+            assert_eq!(headers.x_rate_limit_remaining, Some(9));
+            assert_eq!(headers.x_transaction_id, None);
+        }
+    }
+
+    #[test]
+    fn it_scrub() {
+        let request = TwapiRequest::new(Method::Post, "https://api.twitter.com/2/oauth2/token")
+            .basic_auth("key", "secret");
+        let response = HttpResponse {
+            status: 200,
+            headers: vec![],
+            body: br#"{"access_token":"a","refresh_token":"b","expires_in":7200}"#.to_vec(),
+        };
+        let interaction = Interaction::new(&request, &response);
+        assert_eq!(interaction.request_headers[0].1, REDACTED);
+        assert_eq!(interaction.body["access_token"], REDACTED);
+        assert_eq!(interaction.body["expires_in"], 7200);
+    }
+}
@@ -0,0 +1,255 @@
+//! Uniform access to the generated endpoints.
+//! Every `api::*::Api` implements `Endpoint`, generic code can paginate, inspect or send any of them.
+//!
+//! ```no_run
+//! use twapi_v2::{
+//!     api::{get_2_users_id_followers, BearerAuthentication},
+//!     endpoint::{next_page, Endpoint},
+//!     error::Error,
+//! };
+//!
+//! # async fn run(auth: BearerAuthentication, id: &str) -> Result<(), Error> {
+//! let mut api = get_2_users_id_followers::Api::open(id);
+//! loop {
+//!     let (response, _) = Endpoint::execute(api.clone(), &auth).await?;
+//!     match next_page(&api, &response) {
+//!         Some(it) => api = it,
+//!         None => break,
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    api::Authentication,
+    error::Error,
+    headers::Headers,
+    request::{execute_request, Body, HttpClient, Method, TwapiRequest},
+    runtime::{MaybeSend, MaybeSync},
+    validation::AuthContext,
+};
+
+mod any;
+
+pub use any::AnyEndpoint;
+
+/// Requests per 15 minutes window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// App only bearer token, None if not allowed.
+    pub app: Option<u32>,
+    /// User context, None if not allowed.
+    pub user: Option<u32>,
+}
+
+impl RateLimit {
+    /// The lower limit of the two for `AuthContext::Unknown`.
+    pub fn limit(&self, context: AuthContext) -> Option<u32> {
+        match context {
+            AuthContext::App => self.app,
+            AuthContext::User => self.user,
+            AuthContext::Unknown => match (self.app, self.user) {
+                (Some(app), Some(user)) => Some(app.min(user)),
+                (app, user) => app.or(user),
+            },
+        }
+    }
+}
+
+/// Documented rate limit of the endpoint, None if the endpoint is unknown.
+/// Generated from `rate_limit` of maker/api/*.yaml, every endpoint has it.
+pub fn rate_limit(method: Method, template: &str) -> Option<RateLimit> {
+    let method = method.to_string();
+    crate::validation::endpoints::ENDPOINTS
+        .iter()
+        .find(|it| it.method == method && it.template == template)
+        .and_then(|it| it.rate_limit)
+}
+
+/// Implemented by every generated `Api`.
+pub trait Endpoint: Clone + Sized {
+    type Response: DeserializeOwned + Serialize + std::fmt::Debug;
+
+    const METHOD: Method;
+    /// Path with `:name` parameters, e.g. `/2/users/:id/followers`.
+    const TEMPLATE: &'static str;
+    /// Query parameter name of the pagination token.
+    const PAGINATION: Option<&'static str> = None;
+
+    /// Values of `:name` parameters in `TEMPLATE`.
+    fn path_params(&self) -> Vec<(&'static str, String)>;
+
+    fn request(self) -> TwapiRequest;
+
+    /// `TEMPLATE` with the path parameters replaced.
+    fn path(&self) -> String {
+        self.path_params()
+            .iter()
+            .fold(Self::TEMPLATE.to_owned(), |path, (name, value)| {
+                path.replace(&format!(":{}", name), value)
+            })
+    }
+
+    fn query(&self) -> Vec<(String, String)> {
+        self.clone().request().query
+    }
+
+    fn body(&self) -> Option<Body> {
+        self.clone().request().body
+    }
+
+    fn rate_limit() -> Option<RateLimit> {
+        rate_limit(Self::METHOD, Self::TEMPLATE)
+    }
+
+    /// Token of the next page, None if the endpoint is not paginated or on the last page.
+    fn next_token(_response: &Self::Response) -> Option<String> {
+        None
+    }
+
+    /// Sets the pagination token, does nothing if the endpoint is not paginated.
+    fn with_pagination_token(self, _token: &str) -> Self {
+        self
+    }
+
+    /// The future is `Send` on native targets, it can be spawned.
+    #[cfg(feature = "reqwest")]
+    fn execute(
+        self,
+        authentication: &(impl Authentication + MaybeSync),
+    ) -> impl Future<Output = Result<(Self::Response, Headers), Error>> + MaybeSend
+    where
+        Self: MaybeSend,
+    {
+        async move {
+            let request = self.request().validate(authentication)?;
+            crate::api::execute_twitter(request.build(authentication)).await
+        }
+    }
+
+    fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &(impl Authentication + MaybeSync),
+    ) -> impl Future<Output = Result<(Self::Response, Headers), Error>> + MaybeSend
+    where
+        Self: MaybeSend,
+    {
+        async move {
+            let request = self.request().validate(authentication)?;
+            execute_request(client, request.authorize(authentication)).await
+        }
+    }
+}
+
+/// Api for the next page, None on the last page.
+pub fn next_page<E: Endpoint>(api: &E, response: &E::Response) -> Option<E> {
+    E::next_token(response).map(|token| api.clone().with_pagination_token(&token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{get_2_tweets_id, get_2_users_id_followers, post_2_tweets, TwapiOptions},
+        responses::meta::Meta,
+    };
+
+    #[test]
+    fn it_endpoint() {
+        type Followers = get_2_users_id_followers::Api;
+        let api = Followers::new("123").max_results(10);
+        assert_eq!(Followers::TEMPLATE, "/2/users/:id/followers");
+        assert_eq!(Followers::PAGINATION, Some("pagination_token"));
+        assert_eq!(api.path_params(), vec![("id", "123".to_owned())]);
+        assert_eq!(api.path(), "/2/users/123/followers");
+        assert_eq!(
+            Followers::rate_limit().unwrap().limit(AuthContext::App),
+            Some(15)
+        );
+        let tweets = get_2_tweets_id::Api::rate_limit().unwrap();
+        assert_eq!(tweets.limit(AuthContext::Unknown), Some(300));
+        assert_eq!(
+            post_2_tweets::Api::rate_limit()
+                .unwrap()
+                .limit(AuthContext::Unknown),
+            Some(200)
+        );
+        assert!(crate::validation::endpoints::ENDPOINTS
+            .iter()
+            .all(|it| it.rate_limit.is_some()));
+
+        let mut response = get_2_users_id_followers::Response::default();
+        assert!(next_page(&api, &response).is_none());
+        response.meta = Some(Meta {
+            next_token: Some("next".to_owned()),
+            ..Default::default()
+        });
+        let next = next_page(&api, &response).unwrap();
+        assert!(next
+            .query()
+            .contains(&("pagination_token".to_owned(), "next".to_owned())));
+
+        assert_eq!(get_2_tweets_id::Api::PAGINATION, None);
+        assert_eq!(post_2_tweets::Api::METHOD, Method::Post);
+        assert!(post_2_tweets::Api::new(Default::default()).body().is_some());
+    }
+
+    fn assert_send<T: Send>(_: T) {}
+
+    #[test]
+    fn it_send() {
+        let auth = crate::api::BearerAuthentication::new("token");
+        let client = crate::request::ReqwestClient::default();
+        assert_send(Endpoint::execute(get_2_tweets_id::Api::new("1"), &auth));
+        assert_send(Endpoint::execute_with(
+            get_2_tweets_id::Api::new("1"),
+            &client,
+            &auth,
+        ));
+    }
+
+    #[test]
+    fn it_any() {
+        let api = get_2_users_id_followers::Api::new("123")
+            .pagination_token("next")
+            .twapi_options(TwapiOptions::new().prefix_url("http://localhost"));
+        let json = serde_json::to_value(AnyEndpoint::from(api)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "endpoint": "get_2_users_id_followers",
+                "id": "123",
+                "pagination_token": "next",
+                "twapi_options": {"prefix_url": "http://localhost"},
+            })
+        );
+
+        let any: AnyEndpoint = serde_json::from_value(json).unwrap();
+        assert_eq!(any.name(), "get_2_users_id_followers");
+        assert_eq!(any.method(), Method::Get);
+        assert_eq!(any.template(), "/2/users/:id/followers");
+        let request = any.request();
+        assert_eq!(request.url, "http://localhost/2/users/123/followers");
+        assert_eq!(
+            request.query,
+            vec![("pagination_token".to_owned(), "next".to_owned())]
+        );
+
+        let options = TwapiOptions::new()
+            .timeout(std::time::Duration::from_secs(5))
+            .validator(crate::validation::Validator::new().strict(true));
+        let api = get_2_users_id_followers::Api::new("123").twapi_options(options);
+        let json = serde_json::to_string(&AnyEndpoint::from(api)).unwrap();
+        let request = serde_json::from_str::<AnyEndpoint>(&json)
+            .unwrap()
+            .request();
+        assert_eq!(request.timeout, Some(std::time::Duration::from_secs(5)));
+        assert!(request.validator.is_some());
+    }
+}
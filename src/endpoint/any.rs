@@ -0,0 +1,1135 @@
+use super::Endpoint;
+use crate::{
+    api::{self, Authentication},
+    error::Error,
+    headers::Headers,
+    request::{execute_request, HttpClient, Method, TwapiRequest},
+};
+use serde::{Deserialize, Serialize};
+
+/// Every generated `Api`, tagged by the module name.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "endpoint")]
+pub enum AnyEndpoint {
+    #[serde(rename = "delete_2_lists_id")]
+    Delete2ListsId(api::delete_2_lists_id::Api),
+    #[serde(rename = "delete_2_lists_id_members_user_id")]
+    Delete2ListsIdMembersUserId(api::delete_2_lists_id_members_user_id::Api),
+    #[serde(rename = "delete_2_tweets_id")]
+    Delete2TweetsId(api::delete_2_tweets_id::Api),
+    #[serde(rename = "delete_2_users_id_bookmarks_tweet_id")]
+    Delete2UsersIdBookmarksTweetId(api::delete_2_users_id_bookmarks_tweet_id::Api),
+    #[serde(rename = "delete_2_users_id_followed_lists_list_id")]
+    Delete2UsersIdFollowedListsListId(api::delete_2_users_id_followed_lists_list_id::Api),
+    #[serde(rename = "delete_2_users_id_likes_tweet_id")]
+    Delete2UsersIdLikesTweetId(api::delete_2_users_id_likes_tweet_id::Api),
+    #[serde(rename = "delete_2_users_id_pinned_lists")]
+    Delete2UsersIdPinnedLists(api::delete_2_users_id_pinned_lists::Api),
+    #[serde(rename = "delete_2_users_id_retweets_source_tweet_id")]
+    Delete2UsersIdRetweetsSourceTweetId(api::delete_2_users_id_retweets_source_tweet_id::Api),
+    #[serde(rename = "delete_2_users_source_user_id_blocking_target_user_id")]
+    Delete2UsersSourceUserIdBlockingTargetUserId(
+        api::delete_2_users_source_user_id_blocking_target_user_id::Api,
+    ),
+    #[serde(rename = "delete_2_users_source_user_id_following_target_user_id")]
+    Delete2UsersSourceUserIdFollowingTargetUserId(
+        api::delete_2_users_source_user_id_following_target_user_id::Api,
+    ),
+    #[serde(rename = "delete_2_users_source_user_id_muting_target_user_id")]
+    Delete2UsersSourceUserIdMutingTargetUserId(
+        api::delete_2_users_source_user_id_muting_target_user_id::Api,
+    ),
+    #[serde(rename = "get_2_compliance_jobs")]
+    Get2ComplianceJobs(api::get_2_compliance_jobs::Api),
+    #[serde(rename = "get_2_compliance_jobs_id")]
+    Get2ComplianceJobsId(api::get_2_compliance_jobs_id::Api),
+    #[serde(rename = "get_2_dm_conversations_dm_conversation_id_dm_events")]
+    Get2DmConversationsDmConversationIdDmEvents(
+        api::get_2_dm_conversations_dm_conversation_id_dm_events::Api,
+    ),
+    #[serde(rename = "get_2_dm_conversations_with_participant_id_dm_events")]
+    Get2DmConversationsWithParticipantIdDmEvents(
+        api::get_2_dm_conversations_with_participant_id_dm_events::Api,
+    ),
+    #[serde(rename = "get_2_dm_events")]
+    Get2DmEvents(api::get_2_dm_events::Api),
+    #[serde(rename = "get_2_lists_id")]
+    Get2ListsId(api::get_2_lists_id::Api),
+    #[serde(rename = "get_2_lists_id_followers")]
+    Get2ListsIdFollowers(api::get_2_lists_id_followers::Api),
+    #[serde(rename = "get_2_lists_id_members")]
+    Get2ListsIdMembers(api::get_2_lists_id_members::Api),
+    #[serde(rename = "get_2_lists_id_tweets")]
+    Get2ListsIdTweets(api::get_2_lists_id_tweets::Api),
+    #[serde(rename = "get_2_spaces")]
+    Get2Spaces(api::get_2_spaces::Api),
+    #[serde(rename = "get_2_spaces_by_creator_ids")]
+    Get2SpacesByCreatorIds(api::get_2_spaces_by_creator_ids::Api),
+    #[serde(rename = "get_2_spaces_id")]
+    Get2SpacesId(api::get_2_spaces_id::Api),
+    #[serde(rename = "get_2_spaces_id_buyers")]
+    Get2SpacesIdBuyers(api::get_2_spaces_id_buyers::Api),
+    #[serde(rename = "get_2_spaces_id_tweets")]
+    Get2SpacesIdTweets(api::get_2_spaces_id_tweets::Api),
+    #[serde(rename = "get_2_spaces_search")]
+    Get2SpacesSearch(api::get_2_spaces_search::Api),
+    #[serde(rename = "get_2_trends_by_woeid_woeid")]
+    Get2TrendsByWoeidWoeid(api::get_2_trends_by_woeid_woeid::Api),
+    #[serde(rename = "get_2_tweets")]
+    Get2Tweets(api::get_2_tweets::Api),
+    #[serde(rename = "get_2_tweets_compliance_stream")]
+    Get2TweetsComplianceStream(api::get_2_tweets_compliance_stream::Api),
+    #[serde(rename = "get_2_tweets_count_all")]
+    Get2TweetsCountAll(api::get_2_tweets_count_all::Api),
+    #[serde(rename = "get_2_tweets_count_recent")]
+    Get2TweetsCountRecent(api::get_2_tweets_count_recent::Api),
+    #[serde(rename = "get_2_tweets_id")]
+    Get2TweetsId(api::get_2_tweets_id::Api),
+    #[serde(rename = "get_2_tweets_id_liking_users")]
+    Get2TweetsIdLikingUsers(api::get_2_tweets_id_liking_users::Api),
+    #[serde(rename = "get_2_tweets_id_quote_tweets")]
+    Get2TweetsIdQuoteTweets(api::get_2_tweets_id_quote_tweets::Api),
+    #[serde(rename = "get_2_tweets_id_retweeted_by")]
+    Get2TweetsIdRetweetedBy(api::get_2_tweets_id_retweeted_by::Api),
+    #[serde(rename = "get_2_tweets_sample10_stream")]
+    Get2TweetsSample10Stream(api::get_2_tweets_sample10_stream::Api),
+    #[serde(rename = "get_2_tweets_sample_stream")]
+    Get2TweetsSampleStream(api::get_2_tweets_sample_stream::Api),
+    #[serde(rename = "get_2_tweets_search_all")]
+    Get2TweetsSearchAll(api::get_2_tweets_search_all::Api),
+    #[serde(rename = "get_2_tweets_search_recent")]
+    Get2TweetsSearchRecent(api::get_2_tweets_search_recent::Api),
+    #[serde(rename = "get_2_tweets_search_stream")]
+    Get2TweetsSearchStream(api::get_2_tweets_search_stream::Api),
+    #[serde(rename = "get_2_tweets_search_stream_rules")]
+    Get2TweetsSearchStreamRules(api::get_2_tweets_search_stream_rules::Api),
+    #[serde(rename = "get_2_usage_tweets")]
+    Get2UsageTweets(api::get_2_usage_tweets::Api),
+    #[serde(rename = "get_2_users")]
+    Get2Users(api::get_2_users::Api),
+    #[serde(rename = "get_2_users_by")]
+    Get2UsersBy(api::get_2_users_by::Api),
+    #[serde(rename = "get_2_users_by_username_username")]
+    Get2UsersByUsernameUsername(api::get_2_users_by_username_username::Api),
+    #[serde(rename = "get_2_users_compliance_stream")]
+    Get2UsersComplianceStream(api::get_2_users_compliance_stream::Api),
+    #[serde(rename = "get_2_users_id")]
+    Get2UsersId(api::get_2_users_id::Api),
+    #[serde(rename = "get_2_users_id_blocking")]
+    Get2UsersIdBlocking(api::get_2_users_id_blocking::Api),
+    #[serde(rename = "get_2_users_id_bookmarks")]
+    Get2UsersIdBookmarks(api::get_2_users_id_bookmarks::Api),
+    #[serde(rename = "get_2_users_id_followed_lists")]
+    Get2UsersIdFollowedLists(api::get_2_users_id_followed_lists::Api),
+    #[serde(rename = "get_2_users_id_followers")]
+    Get2UsersIdFollowers(api::get_2_users_id_followers::Api),
+    #[serde(rename = "get_2_users_id_following")]
+    Get2UsersIdFollowing(api::get_2_users_id_following::Api),
+    #[serde(rename = "get_2_users_id_liked_tweets")]
+    Get2UsersIdLikedTweets(api::get_2_users_id_liked_tweets::Api),
+    #[serde(rename = "get_2_users_id_list_memberships")]
+    Get2UsersIdListMemberships(api::get_2_users_id_list_memberships::Api),
+    #[serde(rename = "get_2_users_id_mentions")]
+    Get2UsersIdMentions(api::get_2_users_id_mentions::Api),
+    #[serde(rename = "get_2_users_id_muting")]
+    Get2UsersIdMuting(api::get_2_users_id_muting::Api),
+    #[serde(rename = "get_2_users_id_owned_lists")]
+    Get2UsersIdOwnedLists(api::get_2_users_id_owned_lists::Api),
+    #[serde(rename = "get_2_users_id_pinned_lists")]
+    Get2UsersIdPinnedLists(api::get_2_users_id_pinned_lists::Api),
+    #[serde(rename = "get_2_users_id_timelines_reverse_chronological")]
+    Get2UsersIdTimelinesReverseChronological(
+        api::get_2_users_id_timelines_reverse_chronological::Api,
+    ),
+    #[serde(rename = "get_2_users_id_tweets")]
+    Get2UsersIdTweets(api::get_2_users_id_tweets::Api),
+    #[serde(rename = "get_2_users_me")]
+    Get2UsersMe(api::get_2_users_me::Api),
+    #[serde(rename = "get_2_users_search")]
+    Get2UsersSearch(api::get_2_users_search::Api),
+    #[serde(rename = "post_2_compliance_jobs")]
+    Post2ComplianceJobs(api::post_2_compliance_jobs::Api),
+    #[serde(rename = "post_2_dm_conversations")]
+    Post2DmConversations(api::post_2_dm_conversations::Api),
+    #[serde(rename = "post_2_dm_conversations_dm_conversation_id_message")]
+    Post2DmConversationsDmConversationIdMessage(
+        api::post_2_dm_conversations_dm_conversation_id_message::Api,
+    ),
+    #[serde(rename = "post_2_dm_conversations_with_participant_id_message")]
+    Post2DmConversationsWithParticipantIdMessage(
+        api::post_2_dm_conversations_with_participant_id_message::Api,
+    ),
+    #[serde(rename = "post_2_lists")]
+    Post2Lists(api::post_2_lists::Api),
+    #[serde(rename = "post_2_lists_id_members")]
+    Post2ListsIdMembers(api::post_2_lists_id_members::Api),
+    #[serde(rename = "post_2_tweets")]
+    Post2Tweets(api::post_2_tweets::Api),
+    #[serde(rename = "post_2_tweets_search_stream_rules")]
+    Post2TweetsSearchStreamRules(api::post_2_tweets_search_stream_rules::Api),
+    #[serde(rename = "post_2_users_id_blocking")]
+    Post2UsersIdBlocking(api::post_2_users_id_blocking::Api),
+    #[serde(rename = "post_2_users_id_bookmarks")]
+    Post2UsersIdBookmarks(api::post_2_users_id_bookmarks::Api),
+    #[serde(rename = "post_2_users_id_followed_lists")]
+    Post2UsersIdFollowedLists(api::post_2_users_id_followed_lists::Api),
+    #[serde(rename = "post_2_users_id_following")]
+    Post2UsersIdFollowing(api::post_2_users_id_following::Api),
+    #[serde(rename = "post_2_users_id_likes")]
+    Post2UsersIdLikes(api::post_2_users_id_likes::Api),
+    #[serde(rename = "post_2_users_id_muting")]
+    Post2UsersIdMuting(api::post_2_users_id_muting::Api),
+    #[serde(rename = "post_2_users_id_pinned_lists")]
+    Post2UsersIdPinnedLists(api::post_2_users_id_pinned_lists::Api),
+    #[serde(rename = "post_2_users_id_retweets")]
+    Post2UsersIdRetweets(api::post_2_users_id_retweets::Api),
+    #[serde(rename = "put_2_lists_id")]
+    Put2ListsId(api::put_2_lists_id::Api),
+    #[serde(rename = "put_2_tweets_id_hidden")]
+    Put2TweetsIdHidden(api::put_2_tweets_id_hidden::Api),
+}
+
+impl AnyEndpoint {
+    /// Module name, e.g. `get_2_users_id_followers`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Delete2ListsId(_) => "delete_2_lists_id",
+            Self::Delete2ListsIdMembersUserId(_) => "delete_2_lists_id_members_user_id",
+            Self::Delete2TweetsId(_) => "delete_2_tweets_id",
+            Self::Delete2UsersIdBookmarksTweetId(_) => "delete_2_users_id_bookmarks_tweet_id",
+            Self::Delete2UsersIdFollowedListsListId(_) => {
+                "delete_2_users_id_followed_lists_list_id"
+            }
+            Self::Delete2UsersIdLikesTweetId(_) => "delete_2_users_id_likes_tweet_id",
+            Self::Delete2UsersIdPinnedLists(_) => "delete_2_users_id_pinned_lists",
+            Self::Delete2UsersIdRetweetsSourceTweetId(_) => {
+                "delete_2_users_id_retweets_source_tweet_id"
+            }
+            Self::Delete2UsersSourceUserIdBlockingTargetUserId(_) => {
+                "delete_2_users_source_user_id_blocking_target_user_id"
+            }
+            Self::Delete2UsersSourceUserIdFollowingTargetUserId(_) => {
+                "delete_2_users_source_user_id_following_target_user_id"
+            }
+            Self::Delete2UsersSourceUserIdMutingTargetUserId(_) => {
+                "delete_2_users_source_user_id_muting_target_user_id"
+            }
+            Self::Get2ComplianceJobs(_) => "get_2_compliance_jobs",
+            Self::Get2ComplianceJobsId(_) => "get_2_compliance_jobs_id",
+            Self::Get2DmConversationsDmConversationIdDmEvents(_) => {
+                "get_2_dm_conversations_dm_conversation_id_dm_events"
+            }
+            Self::Get2DmConversationsWithParticipantIdDmEvents(_) => {
+                "get_2_dm_conversations_with_participant_id_dm_events"
+            }
+            Self::Get2DmEvents(_) => "get_2_dm_events",
+            Self::Get2ListsId(_) => "get_2_lists_id",
+            Self::Get2ListsIdFollowers(_) => "get_2_lists_id_followers",
+            Self::Get2ListsIdMembers(_) => "get_2_lists_id_members",
+            Self::Get2ListsIdTweets(_) => "get_2_lists_id_tweets",
+            Self::Get2Spaces(_) => "get_2_spaces",
+            Self::Get2SpacesByCreatorIds(_) => "get_2_spaces_by_creator_ids",
+            Self::Get2SpacesId(_) => "get_2_spaces_id",
+            Self::Get2SpacesIdBuyers(_) => "get_2_spaces_id_buyers",
+            Self::Get2SpacesIdTweets(_) => "get_2_spaces_id_tweets",
+            Self::Get2SpacesSearch(_) => "get_2_spaces_search",
+            Self::Get2TrendsByWoeidWoeid(_) => "get_2_trends_by_woeid_woeid",
+            Self::Get2Tweets(_) => "get_2_tweets",
+            Self::Get2TweetsComplianceStream(_) => "get_2_tweets_compliance_stream",
+            Self::Get2TweetsCountAll(_) => "get_2_tweets_count_all",
+            Self::Get2TweetsCountRecent(_) => "get_2_tweets_count_recent",
+            Self::Get2TweetsId(_) => "get_2_tweets_id",
+            Self::Get2TweetsIdLikingUsers(_) => "get_2_tweets_id_liking_users",
+            Self::Get2TweetsIdQuoteTweets(_) => "get_2_tweets_id_quote_tweets",
+            Self::Get2TweetsIdRetweetedBy(_) => "get_2_tweets_id_retweeted_by",
+            Self::Get2TweetsSample10Stream(_) => "get_2_tweets_sample10_stream",
+            Self::Get2TweetsSampleStream(_) => "get_2_tweets_sample_stream",
+            Self::Get2TweetsSearchAll(_) => "get_2_tweets_search_all",
+            Self::Get2TweetsSearchRecent(_) => "get_2_tweets_search_recent",
+            Self::Get2TweetsSearchStream(_) => "get_2_tweets_search_stream",
+            Self::Get2TweetsSearchStreamRules(_) => "get_2_tweets_search_stream_rules",
+            Self::Get2UsageTweets(_) => "get_2_usage_tweets",
+            Self::Get2Users(_) => "get_2_users",
+            Self::Get2UsersBy(_) => "get_2_users_by",
+            Self::Get2UsersByUsernameUsername(_) => "get_2_users_by_username_username",
+            Self::Get2UsersComplianceStream(_) => "get_2_users_compliance_stream",
+            Self::Get2UsersId(_) => "get_2_users_id",
+            Self::Get2UsersIdBlocking(_) => "get_2_users_id_blocking",
+            Self::Get2UsersIdBookmarks(_) => "get_2_users_id_bookmarks",
+            Self::Get2UsersIdFollowedLists(_) => "get_2_users_id_followed_lists",
+            Self::Get2UsersIdFollowers(_) => "get_2_users_id_followers",
+            Self::Get2UsersIdFollowing(_) => "get_2_users_id_following",
+            Self::Get2UsersIdLikedTweets(_) => "get_2_users_id_liked_tweets",
+            Self::Get2UsersIdListMemberships(_) => "get_2_users_id_list_memberships",
+            Self::Get2UsersIdMentions(_) => "get_2_users_id_mentions",
+            Self::Get2UsersIdMuting(_) => "get_2_users_id_muting",
+            Self::Get2UsersIdOwnedLists(_) => "get_2_users_id_owned_lists",
+            Self::Get2UsersIdPinnedLists(_) => "get_2_users_id_pinned_lists",
+            Self::Get2UsersIdTimelinesReverseChronological(_) => {
+                "get_2_users_id_timelines_reverse_chronological"
+            }
+            Self::Get2UsersIdTweets(_) => "get_2_users_id_tweets",
+            Self::Get2UsersMe(_) => "get_2_users_me",
+            Self::Get2UsersSearch(_) => "get_2_users_search",
+            Self::Post2ComplianceJobs(_) => "post_2_compliance_jobs",
+            Self::Post2DmConversations(_) => "post_2_dm_conversations",
+            Self::Post2DmConversationsDmConversationIdMessage(_) => {
+                "post_2_dm_conversations_dm_conversation_id_message"
+            }
+            Self::Post2DmConversationsWithParticipantIdMessage(_) => {
+                "post_2_dm_conversations_with_participant_id_message"
+            }
+            Self::Post2Lists(_) => "post_2_lists",
+            Self::Post2ListsIdMembers(_) => "post_2_lists_id_members",
+            Self::Post2Tweets(_) => "post_2_tweets",
+            Self::Post2TweetsSearchStreamRules(_) => "post_2_tweets_search_stream_rules",
+            Self::Post2UsersIdBlocking(_) => "post_2_users_id_blocking",
+            Self::Post2UsersIdBookmarks(_) => "post_2_users_id_bookmarks",
+            Self::Post2UsersIdFollowedLists(_) => "post_2_users_id_followed_lists",
+            Self::Post2UsersIdFollowing(_) => "post_2_users_id_following",
+            Self::Post2UsersIdLikes(_) => "post_2_users_id_likes",
+            Self::Post2UsersIdMuting(_) => "post_2_users_id_muting",
+            Self::Post2UsersIdPinnedLists(_) => "post_2_users_id_pinned_lists",
+            Self::Post2UsersIdRetweets(_) => "post_2_users_id_retweets",
+            Self::Put2ListsId(_) => "put_2_lists_id",
+            Self::Put2TweetsIdHidden(_) => "put_2_tweets_id_hidden",
+        }
+    }
+
+    pub fn method(&self) -> Method {
+        match self {
+            Self::Delete2ListsId(_) => api::delete_2_lists_id::Api::METHOD,
+            Self::Delete2ListsIdMembersUserId(_) => {
+                api::delete_2_lists_id_members_user_id::Api::METHOD
+            }
+            Self::Delete2TweetsId(_) => api::delete_2_tweets_id::Api::METHOD,
+            Self::Delete2UsersIdBookmarksTweetId(_) => {
+                api::delete_2_users_id_bookmarks_tweet_id::Api::METHOD
+            }
+            Self::Delete2UsersIdFollowedListsListId(_) => {
+                api::delete_2_users_id_followed_lists_list_id::Api::METHOD
+            }
+            Self::Delete2UsersIdLikesTweetId(_) => {
+                api::delete_2_users_id_likes_tweet_id::Api::METHOD
+            }
+            Self::Delete2UsersIdPinnedLists(_) => api::delete_2_users_id_pinned_lists::Api::METHOD,
+            Self::Delete2UsersIdRetweetsSourceTweetId(_) => {
+                api::delete_2_users_id_retweets_source_tweet_id::Api::METHOD
+            }
+            Self::Delete2UsersSourceUserIdBlockingTargetUserId(_) => {
+                api::delete_2_users_source_user_id_blocking_target_user_id::Api::METHOD
+            }
+            Self::Delete2UsersSourceUserIdFollowingTargetUserId(_) => {
+                api::delete_2_users_source_user_id_following_target_user_id::Api::METHOD
+            }
+            Self::Delete2UsersSourceUserIdMutingTargetUserId(_) => {
+                api::delete_2_users_source_user_id_muting_target_user_id::Api::METHOD
+            }
+            Self::Get2ComplianceJobs(_) => api::get_2_compliance_jobs::Api::METHOD,
+            Self::Get2ComplianceJobsId(_) => api::get_2_compliance_jobs_id::Api::METHOD,
+            Self::Get2DmConversationsDmConversationIdDmEvents(_) => {
+                api::get_2_dm_conversations_dm_conversation_id_dm_events::Api::METHOD
+            }
+            Self::Get2DmConversationsWithParticipantIdDmEvents(_) => {
+                api::get_2_dm_conversations_with_participant_id_dm_events::Api::METHOD
+            }
+            Self::Get2DmEvents(_) => api::get_2_dm_events::Api::METHOD,
+            Self::Get2ListsId(_) => api::get_2_lists_id::Api::METHOD,
+            Self::Get2ListsIdFollowers(_) => api::get_2_lists_id_followers::Api::METHOD,
+            Self::Get2ListsIdMembers(_) => api::get_2_lists_id_members::Api::METHOD,
+            Self::Get2ListsIdTweets(_) => api::get_2_lists_id_tweets::Api::METHOD,
+            Self::Get2Spaces(_) => api::get_2_spaces::Api::METHOD,
+            Self::Get2SpacesByCreatorIds(_) => api::get_2_spaces_by_creator_ids::Api::METHOD,
+            Self::Get2SpacesId(_) => api::get_2_spaces_id::Api::METHOD,
+            Self::Get2SpacesIdBuyers(_) => api::get_2_spaces_id_buyers::Api::METHOD,
+            Self::Get2SpacesIdTweets(_) => api::get_2_spaces_id_tweets::Api::METHOD,
+            Self::Get2SpacesSearch(_) => api::get_2_spaces_search::Api::METHOD,
+            Self::Get2TrendsByWoeidWoeid(_) => api::get_2_trends_by_woeid_woeid::Api::METHOD,
+            Self::Get2Tweets(_) => api::get_2_tweets::Api::METHOD,
+            Self::Get2TweetsComplianceStream(_) => api::get_2_tweets_compliance_stream::Api::METHOD,
+            Self::Get2TweetsCountAll(_) => api::get_2_tweets_count_all::Api::METHOD,
+            Self::Get2TweetsCountRecent(_) => api::get_2_tweets_count_recent::Api::METHOD,
+            Self::Get2TweetsId(_) => api::get_2_tweets_id::Api::METHOD,
+            Self::Get2TweetsIdLikingUsers(_) => api::get_2_tweets_id_liking_users::Api::METHOD,
+            Self::Get2TweetsIdQuoteTweets(_) => api::get_2_tweets_id_quote_tweets::Api::METHOD,
+            Self::Get2TweetsIdRetweetedBy(_) => api::get_2_tweets_id_retweeted_by::Api::METHOD,
+            Self::Get2TweetsSample10Stream(_) => api::get_2_tweets_sample10_stream::Api::METHOD,
+            Self::Get2TweetsSampleStream(_) => api::get_2_tweets_sample_stream::Api::METHOD,
+            Self::Get2TweetsSearchAll(_) => api::get_2_tweets_search_all::Api::METHOD,
+            Self::Get2TweetsSearchRecent(_) => api::get_2_tweets_search_recent::Api::METHOD,
+            Self::Get2TweetsSearchStream(_) => api::get_2_tweets_search_stream::Api::METHOD,
+            Self::Get2TweetsSearchStreamRules(_) => {
+                api::get_2_tweets_search_stream_rules::Api::METHOD
+            }
+            Self::Get2UsageTweets(_) => api::get_2_usage_tweets::Api::METHOD,
+            Self::Get2Users(_) => api::get_2_users::Api::METHOD,
+            Self::Get2UsersBy(_) => api::get_2_users_by::Api::METHOD,
+            Self::Get2UsersByUsernameUsername(_) => {
+                api::get_2_users_by_username_username::Api::METHOD
+            }
+            Self::Get2UsersComplianceStream(_) => api::get_2_users_compliance_stream::Api::METHOD,
+            Self::Get2UsersId(_) => api::get_2_users_id::Api::METHOD,
+            Self::Get2UsersIdBlocking(_) => api::get_2_users_id_blocking::Api::METHOD,
+            Self::Get2UsersIdBookmarks(_) => api::get_2_users_id_bookmarks::Api::METHOD,
+            Self::Get2UsersIdFollowedLists(_) => api::get_2_users_id_followed_lists::Api::METHOD,
+            Self::Get2UsersIdFollowers(_) => api::get_2_users_id_followers::Api::METHOD,
+            Self::Get2UsersIdFollowing(_) => api::get_2_users_id_following::Api::METHOD,
+            Self::Get2UsersIdLikedTweets(_) => api::get_2_users_id_liked_tweets::Api::METHOD,
+            Self::Get2UsersIdListMemberships(_) => {
+                api::get_2_users_id_list_memberships::Api::METHOD
+            }
+            Self::Get2UsersIdMentions(_) => api::get_2_users_id_mentions::Api::METHOD,
+            Self::Get2UsersIdMuting(_) => api::get_2_users_id_muting::Api::METHOD,
+            Self::Get2UsersIdOwnedLists(_) => api::get_2_users_id_owned_lists::Api::METHOD,
+            Self::Get2UsersIdPinnedLists(_) => api::get_2_users_id_pinned_lists::Api::METHOD,
+            Self::Get2UsersIdTimelinesReverseChronological(_) => {
+                api::get_2_users_id_timelines_reverse_chronological::Api::METHOD
+            }
+            Self::Get2UsersIdTweets(_) => api::get_2_users_id_tweets::Api::METHOD,
+            Self::Get2UsersMe(_) => api::get_2_users_me::Api::METHOD,
+            Self::Get2UsersSearch(_) => api::get_2_users_search::Api::METHOD,
+            Self::Post2ComplianceJobs(_) => api::post_2_compliance_jobs::Api::METHOD,
+            Self::Post2DmConversations(_) => api::post_2_dm_conversations::Api::METHOD,
+            Self::Post2DmConversationsDmConversationIdMessage(_) => {
+                api::post_2_dm_conversations_dm_conversation_id_message::Api::METHOD
+            }
+            Self::Post2DmConversationsWithParticipantIdMessage(_) => {
+                api::post_2_dm_conversations_with_participant_id_message::Api::METHOD
+            }
+            Self::Post2Lists(_) => api::post_2_lists::Api::METHOD,
+            Self::Post2ListsIdMembers(_) => api::post_2_lists_id_members::Api::METHOD,
+            Self::Post2Tweets(_) => api::post_2_tweets::Api::METHOD,
+            Self::Post2TweetsSearchStreamRules(_) => {
+                api::post_2_tweets_search_stream_rules::Api::METHOD
+            }
+            Self::Post2UsersIdBlocking(_) => api::post_2_users_id_blocking::Api::METHOD,
+            Self::Post2UsersIdBookmarks(_) => api::post_2_users_id_bookmarks::Api::METHOD,
+            Self::Post2UsersIdFollowedLists(_) => api::post_2_users_id_followed_lists::Api::METHOD,
+            Self::Post2UsersIdFollowing(_) => api::post_2_users_id_following::Api::METHOD,
+            Self::Post2UsersIdLikes(_) => api::post_2_users_id_likes::Api::METHOD,
+            Self::Post2UsersIdMuting(_) => api::post_2_users_id_muting::Api::METHOD,
+            Self::Post2UsersIdPinnedLists(_) => api::post_2_users_id_pinned_lists::Api::METHOD,
+            Self::Post2UsersIdRetweets(_) => api::post_2_users_id_retweets::Api::METHOD,
+            Self::Put2ListsId(_) => api::put_2_lists_id::Api::METHOD,
+            Self::Put2TweetsIdHidden(_) => api::put_2_tweets_id_hidden::Api::METHOD,
+        }
+    }
+
+    pub fn template(&self) -> &'static str {
+        match self {
+            Self::Delete2ListsId(_) => api::delete_2_lists_id::Api::TEMPLATE,
+            Self::Delete2ListsIdMembersUserId(_) => {
+                api::delete_2_lists_id_members_user_id::Api::TEMPLATE
+            }
+            Self::Delete2TweetsId(_) => api::delete_2_tweets_id::Api::TEMPLATE,
+            Self::Delete2UsersIdBookmarksTweetId(_) => {
+                api::delete_2_users_id_bookmarks_tweet_id::Api::TEMPLATE
+            }
+            Self::Delete2UsersIdFollowedListsListId(_) => {
+                api::delete_2_users_id_followed_lists_list_id::Api::TEMPLATE
+            }
+            Self::Delete2UsersIdLikesTweetId(_) => {
+                api::delete_2_users_id_likes_tweet_id::Api::TEMPLATE
+            }
+            Self::Delete2UsersIdPinnedLists(_) => {
+                api::delete_2_users_id_pinned_lists::Api::TEMPLATE
+            }
+            Self::Delete2UsersIdRetweetsSourceTweetId(_) => {
+                api::delete_2_users_id_retweets_source_tweet_id::Api::TEMPLATE
+            }
+            Self::Delete2UsersSourceUserIdBlockingTargetUserId(_) => {
+                api::delete_2_users_source_user_id_blocking_target_user_id::Api::TEMPLATE
+            }
+            Self::Delete2UsersSourceUserIdFollowingTargetUserId(_) => {
+                api::delete_2_users_source_user_id_following_target_user_id::Api::TEMPLATE
+            }
+            Self::Delete2UsersSourceUserIdMutingTargetUserId(_) => {
+                api::delete_2_users_source_user_id_muting_target_user_id::Api::TEMPLATE
+            }
+            Self::Get2ComplianceJobs(_) => api::get_2_compliance_jobs::Api::TEMPLATE,
+            Self::Get2ComplianceJobsId(_) => api::get_2_compliance_jobs_id::Api::TEMPLATE,
+            Self::Get2DmConversationsDmConversationIdDmEvents(_) => {
+                api::get_2_dm_conversations_dm_conversation_id_dm_events::Api::TEMPLATE
+            }
+            Self::Get2DmConversationsWithParticipantIdDmEvents(_) => {
+                api::get_2_dm_conversations_with_participant_id_dm_events::Api::TEMPLATE
+            }
+            Self::Get2DmEvents(_) => api::get_2_dm_events::Api::TEMPLATE,
+            Self::Get2ListsId(_) => api::get_2_lists_id::Api::TEMPLATE,
+            Self::Get2ListsIdFollowers(_) => api::get_2_lists_id_followers::Api::TEMPLATE,
+            Self::Get2ListsIdMembers(_) => api::get_2_lists_id_members::Api::TEMPLATE,
+            Self::Get2ListsIdTweets(_) => api::get_2_lists_id_tweets::Api::TEMPLATE,
+            Self::Get2Spaces(_) => api::get_2_spaces::Api::TEMPLATE,
+            Self::Get2SpacesByCreatorIds(_) => api::get_2_spaces_by_creator_ids::Api::TEMPLATE,
+            Self::Get2SpacesId(_) => api::get_2_spaces_id::Api::TEMPLATE,
+            Self::Get2SpacesIdBuyers(_) => api::get_2_spaces_id_buyers::Api::TEMPLATE,
+            Self::Get2SpacesIdTweets(_) => api::get_2_spaces_id_tweets::Api::TEMPLATE,
+            Self::Get2SpacesSearch(_) => api::get_2_spaces_search::Api::TEMPLATE,
+            Self::Get2TrendsByWoeidWoeid(_) => api::get_2_trends_by_woeid_woeid::Api::TEMPLATE,
+            Self::Get2Tweets(_) => api::get_2_tweets::Api::TEMPLATE,
+            Self::Get2TweetsComplianceStream(_) => {
+                api::get_2_tweets_compliance_stream::Api::TEMPLATE
+            }
+            Self::Get2TweetsCountAll(_) => api::get_2_tweets_count_all::Api::TEMPLATE,
+            Self::Get2TweetsCountRecent(_) => api::get_2_tweets_count_recent::Api::TEMPLATE,
+            Self::Get2TweetsId(_) => api::get_2_tweets_id::Api::TEMPLATE,
+            Self::Get2TweetsIdLikingUsers(_) => api::get_2_tweets_id_liking_users::Api::TEMPLATE,
+            Self::Get2TweetsIdQuoteTweets(_) => api::get_2_tweets_id_quote_tweets::Api::TEMPLATE,
+            Self::Get2TweetsIdRetweetedBy(_) => api::get_2_tweets_id_retweeted_by::Api::TEMPLATE,
+            Self::Get2TweetsSample10Stream(_) => api::get_2_tweets_sample10_stream::Api::TEMPLATE,
+            Self::Get2TweetsSampleStream(_) => api::get_2_tweets_sample_stream::Api::TEMPLATE,
+            Self::Get2TweetsSearchAll(_) => api::get_2_tweets_search_all::Api::TEMPLATE,
+            Self::Get2TweetsSearchRecent(_) => api::get_2_tweets_search_recent::Api::TEMPLATE,
+            Self::Get2TweetsSearchStream(_) => api::get_2_tweets_search_stream::Api::TEMPLATE,
+            Self::Get2TweetsSearchStreamRules(_) => {
+                api::get_2_tweets_search_stream_rules::Api::TEMPLATE
+            }
+            Self::Get2UsageTweets(_) => api::get_2_usage_tweets::Api::TEMPLATE,
+            Self::Get2Users(_) => api::get_2_users::Api::TEMPLATE,
+            Self::Get2UsersBy(_) => api::get_2_users_by::Api::TEMPLATE,
+            Self::Get2UsersByUsernameUsername(_) => {
+                api::get_2_users_by_username_username::Api::TEMPLATE
+            }
+            Self::Get2UsersComplianceStream(_) => api::get_2_users_compliance_stream::Api::TEMPLATE,
+            Self::Get2UsersId(_) => api::get_2_users_id::Api::TEMPLATE,
+            Self::Get2UsersIdBlocking(_) => api::get_2_users_id_blocking::Api::TEMPLATE,
+            Self::Get2UsersIdBookmarks(_) => api::get_2_users_id_bookmarks::Api::TEMPLATE,
+            Self::Get2UsersIdFollowedLists(_) => api::get_2_users_id_followed_lists::Api::TEMPLATE,
+            Self::Get2UsersIdFollowers(_) => api::get_2_users_id_followers::Api::TEMPLATE,
+            Self::Get2UsersIdFollowing(_) => api::get_2_users_id_following::Api::TEMPLATE,
+            Self::Get2UsersIdLikedTweets(_) => api::get_2_users_id_liked_tweets::Api::TEMPLATE,
+            Self::Get2UsersIdListMemberships(_) => {
+                api::get_2_users_id_list_memberships::Api::TEMPLATE
+            }
+            Self::Get2UsersIdMentions(_) => api::get_2_users_id_mentions::Api::TEMPLATE,
+            Self::Get2UsersIdMuting(_) => api::get_2_users_id_muting::Api::TEMPLATE,
+            Self::Get2UsersIdOwnedLists(_) => api::get_2_users_id_owned_lists::Api::TEMPLATE,
+            Self::Get2UsersIdPinnedLists(_) => api::get_2_users_id_pinned_lists::Api::TEMPLATE,
+            Self::Get2UsersIdTimelinesReverseChronological(_) => {
+                api::get_2_users_id_timelines_reverse_chronological::Api::TEMPLATE
+            }
+            Self::Get2UsersIdTweets(_) => api::get_2_users_id_tweets::Api::TEMPLATE,
+            Self::Get2UsersMe(_) => api::get_2_users_me::Api::TEMPLATE,
+            Self::Get2UsersSearch(_) => api::get_2_users_search::Api::TEMPLATE,
+            Self::Post2ComplianceJobs(_) => api::post_2_compliance_jobs::Api::TEMPLATE,
+            Self::Post2DmConversations(_) => api::post_2_dm_conversations::Api::TEMPLATE,
+            Self::Post2DmConversationsDmConversationIdMessage(_) => {
+                api::post_2_dm_conversations_dm_conversation_id_message::Api::TEMPLATE
+            }
+            Self::Post2DmConversationsWithParticipantIdMessage(_) => {
+                api::post_2_dm_conversations_with_participant_id_message::Api::TEMPLATE
+            }
+            Self::Post2Lists(_) => api::post_2_lists::Api::TEMPLATE,
+            Self::Post2ListsIdMembers(_) => api::post_2_lists_id_members::Api::TEMPLATE,
+            Self::Post2Tweets(_) => api::post_2_tweets::Api::TEMPLATE,
+            Self::Post2TweetsSearchStreamRules(_) => {
+                api::post_2_tweets_search_stream_rules::Api::TEMPLATE
+            }
+            Self::Post2UsersIdBlocking(_) => api::post_2_users_id_blocking::Api::TEMPLATE,
+            Self::Post2UsersIdBookmarks(_) => api::post_2_users_id_bookmarks::Api::TEMPLATE,
+            Self::Post2UsersIdFollowedLists(_) => {
+                api::post_2_users_id_followed_lists::Api::TEMPLATE
+            }
+            Self::Post2UsersIdFollowing(_) => api::post_2_users_id_following::Api::TEMPLATE,
+            Self::Post2UsersIdLikes(_) => api::post_2_users_id_likes::Api::TEMPLATE,
+            Self::Post2UsersIdMuting(_) => api::post_2_users_id_muting::Api::TEMPLATE,
+            Self::Post2UsersIdPinnedLists(_) => api::post_2_users_id_pinned_lists::Api::TEMPLATE,
+            Self::Post2UsersIdRetweets(_) => api::post_2_users_id_retweets::Api::TEMPLATE,
+            Self::Put2ListsId(_) => api::put_2_lists_id::Api::TEMPLATE,
+            Self::Put2TweetsIdHidden(_) => api::put_2_tweets_id_hidden::Api::TEMPLATE,
+        }
+    }
+
+    pub fn request(self) -> TwapiRequest {
+        match self {
+            Self::Delete2ListsId(it) => it.request(),
+            Self::Delete2ListsIdMembersUserId(it) => it.request(),
+            Self::Delete2TweetsId(it) => it.request(),
+            Self::Delete2UsersIdBookmarksTweetId(it) => it.request(),
+            Self::Delete2UsersIdFollowedListsListId(it) => it.request(),
+            Self::Delete2UsersIdLikesTweetId(it) => it.request(),
+            Self::Delete2UsersIdPinnedLists(it) => it.request(),
+            Self::Delete2UsersIdRetweetsSourceTweetId(it) => it.request(),
+            Self::Delete2UsersSourceUserIdBlockingTargetUserId(it) => it.request(),
+            Self::Delete2UsersSourceUserIdFollowingTargetUserId(it) => it.request(),
+            Self::Delete2UsersSourceUserIdMutingTargetUserId(it) => it.request(),
+            Self::Get2ComplianceJobs(it) => it.request(),
+            Self::Get2ComplianceJobsId(it) => it.request(),
+            Self::Get2DmConversationsDmConversationIdDmEvents(it) => it.request(),
+            Self::Get2DmConversationsWithParticipantIdDmEvents(it) => it.request(),
+            Self::Get2DmEvents(it) => it.request(),
+            Self::Get2ListsId(it) => it.request(),
+            Self::Get2ListsIdFollowers(it) => it.request(),
+            Self::Get2ListsIdMembers(it) => it.request(),
+            Self::Get2ListsIdTweets(it) => it.request(),
+            Self::Get2Spaces(it) => it.request(),
+            Self::Get2SpacesByCreatorIds(it) => it.request(),
+            Self::Get2SpacesId(it) => it.request(),
+            Self::Get2SpacesIdBuyers(it) => it.request(),
+            Self::Get2SpacesIdTweets(it) => it.request(),
+            Self::Get2SpacesSearch(it) => it.request(),
+            Self::Get2TrendsByWoeidWoeid(it) => it.request(),
+            Self::Get2Tweets(it) => it.request(),
+            Self::Get2TweetsComplianceStream(it) => it.request(),
+            Self::Get2TweetsCountAll(it) => it.request(),
+            Self::Get2TweetsCountRecent(it) => it.request(),
+            Self::Get2TweetsId(it) => it.request(),
+            Self::Get2TweetsIdLikingUsers(it) => it.request(),
+            Self::Get2TweetsIdQuoteTweets(it) => it.request(),
+            Self::Get2TweetsIdRetweetedBy(it) => it.request(),
+            Self::Get2TweetsSample10Stream(it) => it.request(),
+            Self::Get2TweetsSampleStream(it) => it.request(),
+            Self::Get2TweetsSearchAll(it) => it.request(),
+            Self::Get2TweetsSearchRecent(it) => it.request(),
+            Self::Get2TweetsSearchStream(it) => it.request(),
+            Self::Get2TweetsSearchStreamRules(it) => it.request(),
+            Self::Get2UsageTweets(it) => it.request(),
+            Self::Get2Users(it) => it.request(),
+            Self::Get2UsersBy(it) => it.request(),
+            Self::Get2UsersByUsernameUsername(it) => it.request(),
+            Self::Get2UsersComplianceStream(it) => it.request(),
+            Self::Get2UsersId(it) => it.request(),
+            Self::Get2UsersIdBlocking(it) => it.request(),
+            Self::Get2UsersIdBookmarks(it) => it.request(),
+            Self::Get2UsersIdFollowedLists(it) => it.request(),
+            Self::Get2UsersIdFollowers(it) => it.request(),
+            Self::Get2UsersIdFollowing(it) => it.request(),
+            Self::Get2UsersIdLikedTweets(it) => it.request(),
+            Self::Get2UsersIdListMemberships(it) => it.request(),
+            Self::Get2UsersIdMentions(it) => it.request(),
+            Self::Get2UsersIdMuting(it) => it.request(),
+            Self::Get2UsersIdOwnedLists(it) => it.request(),
+            Self::Get2UsersIdPinnedLists(it) => it.request(),
+            Self::Get2UsersIdTimelinesReverseChronological(it) => it.request(),
+            Self::Get2UsersIdTweets(it) => it.request(),
+            Self::Get2UsersMe(it) => it.request(),
+            Self::Get2UsersSearch(it) => it.request(),
+            Self::Post2ComplianceJobs(it) => it.request(),
+            Self::Post2DmConversations(it) => it.request(),
+            Self::Post2DmConversationsDmConversationIdMessage(it) => it.request(),
+            Self::Post2DmConversationsWithParticipantIdMessage(it) => it.request(),
+            Self::Post2Lists(it) => it.request(),
+            Self::Post2ListsIdMembers(it) => it.request(),
+            Self::Post2Tweets(it) => it.request(),
+            Self::Post2TweetsSearchStreamRules(it) => it.request(),
+            Self::Post2UsersIdBlocking(it) => it.request(),
+            Self::Post2UsersIdBookmarks(it) => it.request(),
+            Self::Post2UsersIdFollowedLists(it) => it.request(),
+            Self::Post2UsersIdFollowing(it) => it.request(),
+            Self::Post2UsersIdLikes(it) => it.request(),
+            Self::Post2UsersIdMuting(it) => it.request(),
+            Self::Post2UsersIdPinnedLists(it) => it.request(),
+            Self::Post2UsersIdRetweets(it) => it.request(),
+            Self::Put2ListsId(it) => it.request(),
+            Self::Put2TweetsIdHidden(it) => it.request(),
+        }
+    }
+
+    /// Deserialize the value by `Endpoint::Response` of the Api.
+    #[cfg(feature = "reqwest")]
+    pub async fn execute(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(serde_json::Value, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        api::execute_twitter(request.build(authentication)).await
+    }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<(serde_json::Value, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        execute_request(client, request.authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(serde_json::Value, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        crate::blocking::execute_twitter(request.build(authentication))
+    }
+}
+
+impl From<api::delete_2_lists_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_lists_id::Api) -> Self {
+        Self::Delete2ListsId(value)
+    }
+}
+
+impl From<api::delete_2_lists_id_members_user_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_lists_id_members_user_id::Api) -> Self {
+        Self::Delete2ListsIdMembersUserId(value)
+    }
+}
+
+impl From<api::delete_2_tweets_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_tweets_id::Api) -> Self {
+        Self::Delete2TweetsId(value)
+    }
+}
+
+impl From<api::delete_2_users_id_bookmarks_tweet_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_users_id_bookmarks_tweet_id::Api) -> Self {
+        Self::Delete2UsersIdBookmarksTweetId(value)
+    }
+}
+
+impl From<api::delete_2_users_id_followed_lists_list_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_users_id_followed_lists_list_id::Api) -> Self {
+        Self::Delete2UsersIdFollowedListsListId(value)
+    }
+}
+
+impl From<api::delete_2_users_id_likes_tweet_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_users_id_likes_tweet_id::Api) -> Self {
+        Self::Delete2UsersIdLikesTweetId(value)
+    }
+}
+
+impl From<api::delete_2_users_id_pinned_lists::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_users_id_pinned_lists::Api) -> Self {
+        Self::Delete2UsersIdPinnedLists(value)
+    }
+}
+
+impl From<api::delete_2_users_id_retweets_source_tweet_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_users_id_retweets_source_tweet_id::Api) -> Self {
+        Self::Delete2UsersIdRetweetsSourceTweetId(value)
+    }
+}
+
+impl From<api::delete_2_users_source_user_id_blocking_target_user_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_users_source_user_id_blocking_target_user_id::Api) -> Self {
+        Self::Delete2UsersSourceUserIdBlockingTargetUserId(value)
+    }
+}
+
+impl From<api::delete_2_users_source_user_id_following_target_user_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_users_source_user_id_following_target_user_id::Api) -> Self {
+        Self::Delete2UsersSourceUserIdFollowingTargetUserId(value)
+    }
+}
+
+impl From<api::delete_2_users_source_user_id_muting_target_user_id::Api> for AnyEndpoint {
+    fn from(value: api::delete_2_users_source_user_id_muting_target_user_id::Api) -> Self {
+        Self::Delete2UsersSourceUserIdMutingTargetUserId(value)
+    }
+}
+
+impl From<api::get_2_compliance_jobs::Api> for AnyEndpoint {
+    fn from(value: api::get_2_compliance_jobs::Api) -> Self {
+        Self::Get2ComplianceJobs(value)
+    }
+}
+
+impl From<api::get_2_compliance_jobs_id::Api> for AnyEndpoint {
+    fn from(value: api::get_2_compliance_jobs_id::Api) -> Self {
+        Self::Get2ComplianceJobsId(value)
+    }
+}
+
+impl From<api::get_2_dm_conversations_dm_conversation_id_dm_events::Api> for AnyEndpoint {
+    fn from(value: api::get_2_dm_conversations_dm_conversation_id_dm_events::Api) -> Self {
+        Self::Get2DmConversationsDmConversationIdDmEvents(value)
+    }
+}
+
+impl From<api::get_2_dm_conversations_with_participant_id_dm_events::Api> for AnyEndpoint {
+    fn from(value: api::get_2_dm_conversations_with_participant_id_dm_events::Api) -> Self {
+        Self::Get2DmConversationsWithParticipantIdDmEvents(value)
+    }
+}
+
+impl From<api::get_2_dm_events::Api> for AnyEndpoint {
+    fn from(value: api::get_2_dm_events::Api) -> Self {
+        Self::Get2DmEvents(value)
+    }
+}
+
+impl From<api::get_2_lists_id::Api> for AnyEndpoint {
+    fn from(value: api::get_2_lists_id::Api) -> Self {
+        Self::Get2ListsId(value)
+    }
+}
+
+impl From<api::get_2_lists_id_followers::Api> for AnyEndpoint {
+    fn from(value: api::get_2_lists_id_followers::Api) -> Self {
+        Self::Get2ListsIdFollowers(value)
+    }
+}
+
+impl From<api::get_2_lists_id_members::Api> for AnyEndpoint {
+    fn from(value: api::get_2_lists_id_members::Api) -> Self {
+        Self::Get2ListsIdMembers(value)
+    }
+}
+
+impl From<api::get_2_lists_id_tweets::Api> for AnyEndpoint {
+    fn from(value: api::get_2_lists_id_tweets::Api) -> Self {
+        Self::Get2ListsIdTweets(value)
+    }
+}
+
+impl From<api::get_2_spaces::Api> for AnyEndpoint {
+    fn from(value: api::get_2_spaces::Api) -> Self {
+        Self::Get2Spaces(value)
+    }
+}
+
+impl From<api::get_2_spaces_by_creator_ids::Api> for AnyEndpoint {
+    fn from(value: api::get_2_spaces_by_creator_ids::Api) -> Self {
+        Self::Get2SpacesByCreatorIds(value)
+    }
+}
+
+impl From<api::get_2_spaces_id::Api> for AnyEndpoint {
+    fn from(value: api::get_2_spaces_id::Api) -> Self {
+        Self::Get2SpacesId(value)
+    }
+}
+
+impl From<api::get_2_spaces_id_buyers::Api> for AnyEndpoint {
+    fn from(value: api::get_2_spaces_id_buyers::Api) -> Self {
+        Self::Get2SpacesIdBuyers(value)
+    }
+}
+
+impl From<api::get_2_spaces_id_tweets::Api> for AnyEndpoint {
+    fn from(value: api::get_2_spaces_id_tweets::Api) -> Self {
+        Self::Get2SpacesIdTweets(value)
+    }
+}
+
+impl From<api::get_2_spaces_search::Api> for AnyEndpoint {
+    fn from(value: api::get_2_spaces_search::Api) -> Self {
+        Self::Get2SpacesSearch(value)
+    }
+}
+
+impl From<api::get_2_trends_by_woeid_woeid::Api> for AnyEndpoint {
+    fn from(value: api::get_2_trends_by_woeid_woeid::Api) -> Self {
+        Self::Get2TrendsByWoeidWoeid(value)
+    }
+}
+
+impl From<api::get_2_tweets::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets::Api) -> Self {
+        Self::Get2Tweets(value)
+    }
+}
+
+impl From<api::get_2_tweets_compliance_stream::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_compliance_stream::Api) -> Self {
+        Self::Get2TweetsComplianceStream(value)
+    }
+}
+
+impl From<api::get_2_tweets_count_all::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_count_all::Api) -> Self {
+        Self::Get2TweetsCountAll(value)
+    }
+}
+
+impl From<api::get_2_tweets_count_recent::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_count_recent::Api) -> Self {
+        Self::Get2TweetsCountRecent(value)
+    }
+}
+
+impl From<api::get_2_tweets_id::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_id::Api) -> Self {
+        Self::Get2TweetsId(value)
+    }
+}
+
+impl From<api::get_2_tweets_id_liking_users::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_id_liking_users::Api) -> Self {
+        Self::Get2TweetsIdLikingUsers(value)
+    }
+}
+
+impl From<api::get_2_tweets_id_quote_tweets::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_id_quote_tweets::Api) -> Self {
+        Self::Get2TweetsIdQuoteTweets(value)
+    }
+}
+
+impl From<api::get_2_tweets_id_retweeted_by::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_id_retweeted_by::Api) -> Self {
+        Self::Get2TweetsIdRetweetedBy(value)
+    }
+}
+
+impl From<api::get_2_tweets_sample10_stream::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_sample10_stream::Api) -> Self {
+        Self::Get2TweetsSample10Stream(value)
+    }
+}
+
+impl From<api::get_2_tweets_sample_stream::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_sample_stream::Api) -> Self {
+        Self::Get2TweetsSampleStream(value)
+    }
+}
+
+impl From<api::get_2_tweets_search_all::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_search_all::Api) -> Self {
+        Self::Get2TweetsSearchAll(value)
+    }
+}
+
+impl From<api::get_2_tweets_search_recent::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_search_recent::Api) -> Self {
+        Self::Get2TweetsSearchRecent(value)
+    }
+}
+
+impl From<api::get_2_tweets_search_stream::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_search_stream::Api) -> Self {
+        Self::Get2TweetsSearchStream(value)
+    }
+}
+
+impl From<api::get_2_tweets_search_stream_rules::Api> for AnyEndpoint {
+    fn from(value: api::get_2_tweets_search_stream_rules::Api) -> Self {
+        Self::Get2TweetsSearchStreamRules(value)
+    }
+}
+
+impl From<api::get_2_usage_tweets::Api> for AnyEndpoint {
+    fn from(value: api::get_2_usage_tweets::Api) -> Self {
+        Self::Get2UsageTweets(value)
+    }
+}
+
+impl From<api::get_2_users::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users::Api) -> Self {
+        Self::Get2Users(value)
+    }
+}
+
+impl From<api::get_2_users_by::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_by::Api) -> Self {
+        Self::Get2UsersBy(value)
+    }
+}
+
+impl From<api::get_2_users_by_username_username::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_by_username_username::Api) -> Self {
+        Self::Get2UsersByUsernameUsername(value)
+    }
+}
+
+impl From<api::get_2_users_compliance_stream::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_compliance_stream::Api) -> Self {
+        Self::Get2UsersComplianceStream(value)
+    }
+}
+
+impl From<api::get_2_users_id::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id::Api) -> Self {
+        Self::Get2UsersId(value)
+    }
+}
+
+impl From<api::get_2_users_id_blocking::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_blocking::Api) -> Self {
+        Self::Get2UsersIdBlocking(value)
+    }
+}
+
+impl From<api::get_2_users_id_bookmarks::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_bookmarks::Api) -> Self {
+        Self::Get2UsersIdBookmarks(value)
+    }
+}
+
+impl From<api::get_2_users_id_followed_lists::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_followed_lists::Api) -> Self {
+        Self::Get2UsersIdFollowedLists(value)
+    }
+}
+
+impl From<api::get_2_users_id_followers::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_followers::Api) -> Self {
+        Self::Get2UsersIdFollowers(value)
+    }
+}
+
+impl From<api::get_2_users_id_following::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_following::Api) -> Self {
+        Self::Get2UsersIdFollowing(value)
+    }
+}
+
+impl From<api::get_2_users_id_liked_tweets::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_liked_tweets::Api) -> Self {
+        Self::Get2UsersIdLikedTweets(value)
+    }
+}
+
+impl From<api::get_2_users_id_list_memberships::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_list_memberships::Api) -> Self {
+        Self::Get2UsersIdListMemberships(value)
+    }
+}
+
+impl From<api::get_2_users_id_mentions::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_mentions::Api) -> Self {
+        Self::Get2UsersIdMentions(value)
+    }
+}
+
+impl From<api::get_2_users_id_muting::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_muting::Api) -> Self {
+        Self::Get2UsersIdMuting(value)
+    }
+}
+
+impl From<api::get_2_users_id_owned_lists::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_owned_lists::Api) -> Self {
+        Self::Get2UsersIdOwnedLists(value)
+    }
+}
+
+impl From<api::get_2_users_id_pinned_lists::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_pinned_lists::Api) -> Self {
+        Self::Get2UsersIdPinnedLists(value)
+    }
+}
+
+impl From<api::get_2_users_id_timelines_reverse_chronological::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_timelines_reverse_chronological::Api) -> Self {
+        Self::Get2UsersIdTimelinesReverseChronological(value)
+    }
+}
+
+impl From<api::get_2_users_id_tweets::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_id_tweets::Api) -> Self {
+        Self::Get2UsersIdTweets(value)
+    }
+}
+
+impl From<api::get_2_users_me::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_me::Api) -> Self {
+        Self::Get2UsersMe(value)
+    }
+}
+
+impl From<api::get_2_users_search::Api> for AnyEndpoint {
+    fn from(value: api::get_2_users_search::Api) -> Self {
+        Self::Get2UsersSearch(value)
+    }
+}
+
+impl From<api::post_2_compliance_jobs::Api> for AnyEndpoint {
+    fn from(value: api::post_2_compliance_jobs::Api) -> Self {
+        Self::Post2ComplianceJobs(value)
+    }
+}
+
+impl From<api::post_2_dm_conversations::Api> for AnyEndpoint {
+    fn from(value: api::post_2_dm_conversations::Api) -> Self {
+        Self::Post2DmConversations(value)
+    }
+}
+
+impl From<api::post_2_dm_conversations_dm_conversation_id_message::Api> for AnyEndpoint {
+    fn from(value: api::post_2_dm_conversations_dm_conversation_id_message::Api) -> Self {
+        Self::Post2DmConversationsDmConversationIdMessage(value)
+    }
+}
+
+impl From<api::post_2_dm_conversations_with_participant_id_message::Api> for AnyEndpoint {
+    fn from(value: api::post_2_dm_conversations_with_participant_id_message::Api) -> Self {
+        Self::Post2DmConversationsWithParticipantIdMessage(value)
+    }
+}
+
+impl From<api::post_2_lists::Api> for AnyEndpoint {
+    fn from(value: api::post_2_lists::Api) -> Self {
+        Self::Post2Lists(value)
+    }
+}
+
+impl From<api::post_2_lists_id_members::Api> for AnyEndpoint {
+    fn from(value: api::post_2_lists_id_members::Api) -> Self {
+        Self::Post2ListsIdMembers(value)
+    }
+}
+
+impl From<api::post_2_tweets::Api> for AnyEndpoint {
+    fn from(value: api::post_2_tweets::Api) -> Self {
+        Self::Post2Tweets(value)
+    }
+}
+
+impl From<api::post_2_tweets_search_stream_rules::Api> for AnyEndpoint {
+    fn from(value: api::post_2_tweets_search_stream_rules::Api) -> Self {
+        Self::Post2TweetsSearchStreamRules(value)
+    }
+}
+
+impl From<api::post_2_users_id_blocking::Api> for AnyEndpoint {
+    fn from(value: api::post_2_users_id_blocking::Api) -> Self {
+        Self::Post2UsersIdBlocking(value)
+    }
+}
+
+impl From<api::post_2_users_id_bookmarks::Api> for AnyEndpoint {
+    fn from(value: api::post_2_users_id_bookmarks::Api) -> Self {
+        Self::Post2UsersIdBookmarks(value)
+    }
+}
+
+impl From<api::post_2_users_id_followed_lists::Api> for AnyEndpoint {
+    fn from(value: api::post_2_users_id_followed_lists::Api) -> Self {
+        Self::Post2UsersIdFollowedLists(value)
+    }
+}
+
+impl From<api::post_2_users_id_following::Api> for AnyEndpoint {
+    fn from(value: api::post_2_users_id_following::Api) -> Self {
+        Self::Post2UsersIdFollowing(value)
+    }
+}
+
+impl From<api::post_2_users_id_likes::Api> for AnyEndpoint {
+    fn from(value: api::post_2_users_id_likes::Api) -> Self {
+        Self::Post2UsersIdLikes(value)
+    }
+}
+
+impl From<api::post_2_users_id_muting::Api> for AnyEndpoint {
+    fn from(value: api::post_2_users_id_muting::Api) -> Self {
+        Self::Post2UsersIdMuting(value)
+    }
+}
+
+impl From<api::post_2_users_id_pinned_lists::Api> for AnyEndpoint {
+    fn from(value: api::post_2_users_id_pinned_lists::Api) -> Self {
+        Self::Post2UsersIdPinnedLists(value)
+    }
+}
+
+impl From<api::post_2_users_id_retweets::Api> for AnyEndpoint {
+    fn from(value: api::post_2_users_id_retweets::Api) -> Self {
+        Self::Post2UsersIdRetweets(value)
+    }
+}
+
+impl From<api::put_2_lists_id::Api> for AnyEndpoint {
+    fn from(value: api::put_2_lists_id::Api) -> Self {
+        Self::Put2ListsId(value)
+    }
+}
+
+impl From<api::put_2_tweets_id_hidden::Api> for AnyEndpoint {
+    fn from(value: api::put_2_tweets_id_hidden::Api) -> Self {
+        Self::Put2TweetsIdHidden(value)
+    }
+}
@@ -1,4 +1,4 @@
-use reqwest::StatusCode;
+use http::StatusCode;
 use thiserror::Error;
 
 use crate::headers::Headers;
@@ -17,14 +17,20 @@ pub enum Error {
     #[error("Other {0}")]
     Other(String, Option<StatusCode>),
 
+    #[cfg(feature = "reqwest")]
     #[error("reqwest {0}")]
     Reqwest(#[from] reqwest::Error),
 
     #[error("serde json {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("Validation {0:?}")]
+    Validation(Vec<crate::validation::Issue>),
+
     #[error("TwitterError {0:?}, {1:?}, {1:?}")]
-    Twitter(TwitterError, serde_json::Value, Box<Headers>), // https://rust-lang.github.io/rust-clippy/master/index.html#large_enum_variant
+    // Boxed to keep Result<T, Error> small
+    // https://rust-lang.github.io/rust-clippy/master/index.html#result_large_err
+    Twitter(Box<TwitterError>, serde_json::Value, Box<Headers>),
 }
 
 #[derive(Debug, Clone)]
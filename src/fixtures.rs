@@ -0,0 +1,88 @@
+//! Random response values for tests with the arbitrary crate.
+//!
+//! Values are chosen so that they survive a JSON round trip: dates have millisecond precision and floats
+//! are finite with an exact decimal representation. `extra` is always empty.
+
+pub use arbitrary::{Arbitrary, Unstructured};
+
+use arbitrary::Result;
+use chrono::{DateTime, TimeZone, Utc};
+
+/// 2006-03-21, the first tweet.
+const MIN_MILLIS: i64 = 1_142_899_200_000;
+/// 2100-01-01
+const MAX_MILLIS: i64 = 4_102_444_800_000;
+
+/// Builds a value from random bytes. Missing bytes are filled with defaults.
+pub fn generate<'a, T: Arbitrary<'a>>(bytes: &'a [u8]) -> Result<T> {
+    T::arbitrary(&mut Unstructured::new(bytes))
+}
+
+pub fn date_time(u: &mut Unstructured) -> Result<DateTime<Utc>> {
+    let millis = u.int_in_range(MIN_MILLIS..=MAX_MILLIS)?;
+    Ok(Utc
+        .timestamp_millis_opt(millis)
+        .single()
+        .unwrap_or_default())
+}
+
+pub fn option_date_time(u: &mut Unstructured) -> Result<Option<DateTime<Utc>>> {
+    option(u, date_time)
+}
+
+/// Quarters, exact in decimal.
+pub fn f64(u: &mut Unstructured) -> Result<f64> {
+    Ok(f64::from(i32::arbitrary(u)?) / 4.0)
+}
+
+pub fn option_f64(u: &mut Unstructured) -> Result<Option<f64>> {
+    option(u, f64)
+}
+
+pub fn vec_f64(u: &mut Unstructured) -> Result<Vec<f64>> {
+    let len = u.arbitrary_len::<i32>()?;
+    (0..len).map(|_| f64(u)).collect()
+}
+
+pub fn option_vec_f64(u: &mut Unstructured) -> Result<Option<Vec<f64>>> {
+    option(u, vec_f64)
+}
+
+/// An object of strings, never null.
+pub fn json(u: &mut Unstructured) -> Result<serde_json::Value> {
+    let map: std::collections::BTreeMap<String, String> = u.arbitrary()?;
+    Ok(serde_json::json!(map))
+}
+
+pub fn option_json(u: &mut Unstructured) -> Result<Option<serde_json::Value>> {
+    option(u, json)
+}
+
+fn option<T>(
+    u: &mut Unstructured,
+    f: impl FnOnce(&mut Unstructured) -> Result<T>,
+) -> Result<Option<T>> {
+    if u.arbitrary()? {
+        Ok(Some(f(u)?))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::tweets::Tweets;
+
+    #[test]
+    fn it_generate() {
+        let bytes: Vec<u8> = (0..1024).map(|it| (it * 31 % 251) as u8).collect();
+        let tweets: Tweets = generate(&bytes).unwrap();
+        assert!(tweets.extra.is_empty());
+        if let Some(created_at) = tweets.created_at {
+            assert!(created_at.timestamp_millis() >= MIN_MILLIS);
+        }
+        let tweets: Tweets = generate(&[]).unwrap();
+        assert!(tweets.id.is_empty());
+    }
+}
@@ -59,32 +59,47 @@ pub struct Headers {
 }
 
 impl Headers {
-    pub fn new(header: &reqwest::header::HeaderMap) -> Self {
-        let mut extra = HashMap::new();
-        for (name, value) in header.iter() {
-            if !KEYS.contains(&name.as_str()) {
-                if let Ok(value) = value.to_str() {
-                    extra.insert(name.as_str().to_string(), value.to_string());
-                }
-            }
+    pub fn new(header: &http::HeaderMap) -> Self {
+        Self::from_pairs(
+            header
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?))),
+        )
+    }
+
+    /// For responses of other http clients. Names are case insensitive.
+    pub fn from_pairs<'a>(pairs: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut header = HashMap::new();
+        for (name, value) in pairs {
+            header
+                .entry(name.to_lowercase())
+                .or_insert(value.to_owned());
         }
+        let extra = header
+            .iter()
+            .filter(|(name, _)| !KEYS.contains(&name.as_str()))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
         Self {
-            x_access_level: get_string_value(header, X_ACCESS_LEVEL),
-            x_app_limit_24hour_limit: get_integer_value(header, X_APP_LIMIT_24HOUR_LIMIT),
-            x_app_limit_24hour_reset: get_unixtimestamp_value(header, X_APP_LIMIT_24HOUR_RESET),
-            x_app_limit_24hour_remaining: get_integer_value(header, X_APP_LIMIT_24HOUR_REMAINING),
-            x_connection_hash: get_string_value(header, X_CONNECTION_HASH),
-            x_content_type_options: get_string_value(header, X_CONTENT_TYPE_OPTIONS),
-            x_frame_options: get_string_value(header, X_FRAME_OPTIONS),
-            x_rate_limit_limit: get_integer_value(header, X_RATE_LIMIT_LIMIT),
-            x_rate_limit_reset: get_unixtimestamp_value(header, X_RATE_LIMIT_RESET),
-            x_rate_limit_remaining: get_integer_value(header, X_RATE_LIMIT_REMAINING),
-            x_response_time: get_integer_value(header, X_RESPONSE_TIME),
-            x_transaction_id: get_string_value(header, X_TRANSACTION_ID),
-            x_user_limit_24hour_limit: get_integer_value(header, X_USER_LIMIT_24HOUR_LIMIT),
-            x_user_limit_24hour_reset: get_unixtimestamp_value(header, X_USER_LIMIT_24HOUR_RESET),
-            x_user_limit_24hour_remaining: get_integer_value(header, X_USER_LIMIT_24HOUR_REMAINING),
-            x_xss_protection: get_integer_value(header, X_XSS_PROTECTION),
+            x_access_level: get_string_value(&header, X_ACCESS_LEVEL),
+            x_app_limit_24hour_limit: get_integer_value(&header, X_APP_LIMIT_24HOUR_LIMIT),
+            x_app_limit_24hour_reset: get_unixtimestamp_value(&header, X_APP_LIMIT_24HOUR_RESET),
+            x_app_limit_24hour_remaining: get_integer_value(&header, X_APP_LIMIT_24HOUR_REMAINING),
+            x_connection_hash: get_string_value(&header, X_CONNECTION_HASH),
+            x_content_type_options: get_string_value(&header, X_CONTENT_TYPE_OPTIONS),
+            x_frame_options: get_string_value(&header, X_FRAME_OPTIONS),
+            x_rate_limit_limit: get_integer_value(&header, X_RATE_LIMIT_LIMIT),
+            x_rate_limit_reset: get_unixtimestamp_value(&header, X_RATE_LIMIT_RESET),
+            x_rate_limit_remaining: get_integer_value(&header, X_RATE_LIMIT_REMAINING),
+            x_response_time: get_integer_value(&header, X_RESPONSE_TIME),
+            x_transaction_id: get_string_value(&header, X_TRANSACTION_ID),
+            x_user_limit_24hour_limit: get_integer_value(&header, X_USER_LIMIT_24HOUR_LIMIT),
+            x_user_limit_24hour_reset: get_unixtimestamp_value(&header, X_USER_LIMIT_24HOUR_RESET),
+            x_user_limit_24hour_remaining: get_integer_value(
+                &header,
+                X_USER_LIMIT_24HOUR_REMAINING,
+            ),
+            x_xss_protection: get_integer_value(&header, X_XSS_PROTECTION),
             extra,
         }
     }
@@ -98,18 +113,15 @@ impl std::fmt::Display for Headers {
     }
 }
 
-fn get_integer_value(header: &reqwest::header::HeaderMap, key: &str) -> Option<u64> {
-    header.get(key)?.to_str().ok()?.parse().ok()
+fn get_integer_value(header: &HashMap<String, String>, key: &str) -> Option<u64> {
+    header.get(key)?.parse().ok()
 }
 
-fn get_string_value(header: &reqwest::header::HeaderMap, key: &str) -> Option<String> {
-    header.get(key)?.to_str().ok().map(|it| it.to_string())
+fn get_string_value(header: &HashMap<String, String>, key: &str) -> Option<String> {
+    header.get(key).cloned()
 }
 
-fn get_unixtimestamp_value(
-    header: &reqwest::header::HeaderMap,
-    key: &str,
-) -> Option<DateTime<Utc>> {
+fn get_unixtimestamp_value(header: &HashMap<String, String>, key: &str) -> Option<DateTime<Utc>> {
     let res = get_integer_value(header, key)?;
     Utc.timestamp_opt(res as i64, 0).single()
 }
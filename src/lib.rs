@@ -1,12 +1,30 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 pub mod api;
+pub mod endpoint;
 pub mod error;
 pub mod fields;
 pub mod headers;
+pub mod permalink;
+pub mod request;
 pub mod responses;
+pub mod runtime;
+pub mod secret;
+pub mod select;
+pub mod snowflake;
+pub mod telemetry;
+pub mod thread;
+pub mod twitter_text;
+pub mod validation;
 
+#[cfg(feature = "reqwest")]
 pub use reqwest;
 
+#[cfg(feature = "reqwest")]
+pub mod middleware;
+
+#[cfg(all(target_arch = "wasm32", any(feature = "blocking", feature = "loader", feature = "testing")))]
+compile_error!("blocking, loader and testing features are not supported on wasm32");
+
 #[cfg(feature = "retry")]
 pub mod retry;
 
@@ -16,8 +34,26 @@ pub mod oauth;
 #[cfg(feature = "oauth10a")]
 pub mod oauth10a;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+#[cfg(feature = "cache")]
+pub mod cache;
+
+#[cfg(feature = "loader")]
+pub mod loader;
+
 #[cfg(feature = "models")]
 pub mod models;
 
 #[cfg(feature = "upload")]
 pub mod upload;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "cassette")]
+pub mod cassette;
+
+#[cfg(feature = "arbitrary")]
+pub mod fixtures;
@@ -0,0 +1,355 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+use tokio::sync::oneshot;
+
+use crate::{
+    api::{get_2_tweets, get_2_users, Authentication},
+    error::Error,
+    responses::{errors::Errors, tweets::Tweets, users::Users},
+};
+
+const MAX_IDS: usize = 100;
+const DEFAULT_WINDOW: Duration = Duration::from_millis(10);
+
+#[derive(Error, Debug, Clone)]
+pub enum LoadError {
+    #[error("Errors {0:?}")]
+    Errors(Box<Errors>),
+
+    #[error("NotFound")]
+    NotFound,
+
+    #[error("Request {0}")]
+    Request(Arc<Error>),
+
+    #[error("Canceled")]
+    Canceled,
+}
+
+/// Fetches up to 100 ids in one request.
+pub trait Fetcher: Send + Sync + 'static {
+    type Item: Clone + Send + 'static;
+
+    fn id(item: &Self::Item) -> &str;
+
+    fn fetch(
+        &self,
+        ids: &[String],
+    ) -> impl Future<Output = Result<(Vec<Self::Item>, Vec<Errors>), Error>> + Send;
+}
+
+type Builder<T> = Box<dyn Fn(&str) -> T + Send + Sync>;
+
+pub struct UsersFetcher<A> {
+    authentication: A,
+    builder: Builder<get_2_users::Api>,
+}
+
+impl<A: Authentication + Send + Sync + 'static> UsersFetcher<A> {
+    pub fn new(authentication: A) -> Self {
+        Self {
+            authentication,
+            builder: Box::new(get_2_users::Api::open),
+        }
+    }
+
+    /// Customize fields or options. The argument is comma separated ids.
+    pub fn builder(
+        mut self,
+        value: impl Fn(&str) -> get_2_users::Api + Send + Sync + 'static,
+    ) -> Self {
+        self.builder = Box::new(value);
+        self
+    }
+}
+
+impl<A: Authentication + Send + Sync + 'static> Fetcher for UsersFetcher<A> {
+    type Item = Users;
+
+    fn id(item: &Self::Item) -> &str {
+        &item.id
+    }
+
+    async fn fetch(&self, ids: &[String]) -> Result<(Vec<Self::Item>, Vec<Errors>), Error> {
+        let (response, _) = (self.builder)(&ids.join(","))
+            .execute(&self.authentication)
+            .await?;
+        Ok((
+            response.data.unwrap_or_default(),
+            response.errors.unwrap_or_default(),
+        ))
+    }
+}
+
+pub struct TweetsFetcher<A> {
+    authentication: A,
+    builder: Builder<get_2_tweets::Api>,
+}
+
+impl<A: Authentication + Send + Sync + 'static> TweetsFetcher<A> {
+    pub fn new(authentication: A) -> Self {
+        Self {
+            authentication,
+            builder: Box::new(get_2_tweets::Api::open),
+        }
+    }
+
+    /// Customize fields or options. The argument is comma separated ids.
+    pub fn builder(
+        mut self,
+        value: impl Fn(&str) -> get_2_tweets::Api + Send + Sync + 'static,
+    ) -> Self {
+        self.builder = Box::new(value);
+        self
+    }
+}
+
+impl<A: Authentication + Send + Sync + 'static> Fetcher for TweetsFetcher<A> {
+    type Item = Tweets;
+
+    fn id(item: &Self::Item) -> &str {
+        &item.id
+    }
+
+    async fn fetch(&self, ids: &[String]) -> Result<(Vec<Self::Item>, Vec<Errors>), Error> {
+        let (response, _) = (self.builder)(&ids.join(","))
+            .execute(&self.authentication)
+            .await?;
+        Ok((
+            response.data.unwrap_or_default(),
+            response.errors.unwrap_or_default(),
+        ))
+    }
+}
+
+type Waiter<T> = oneshot::Sender<Result<T, LoadError>>;
+
+struct State<T> {
+    pending: HashMap<String, Vec<Waiter<T>>>,
+    scheduled: bool,
+    cache: HashMap<String, (Instant, Result<T, LoadError>)>,
+}
+
+struct Inner<F: Fetcher> {
+    fetcher: F,
+    window: Duration,
+    ttl: Option<Duration>,
+    state: Mutex<State<F::Item>>,
+}
+
+/// Collects `load` calls made within a short window and resolves them with batch requests.
+pub struct Loader<F: Fetcher> {
+    inner: Arc<Inner<F>>,
+}
+
+impl<F: Fetcher> Clone for Loader<F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<F: Fetcher> Loader<F> {
+    pub fn new(fetcher: F) -> Self {
+        Self::with_options(fetcher, DEFAULT_WINDOW, None)
+    }
+
+    /// `ttl` enables caching of found items and per-id errors.
+    pub fn with_options(fetcher: F, window: Duration, ttl: Option<Duration>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                fetcher,
+                window,
+                ttl,
+                state: Mutex::new(State {
+                    pending: HashMap::new(),
+                    scheduled: false,
+                    cache: HashMap::new(),
+                }),
+            }),
+        }
+    }
+
+    pub async fn load(&self, id: &str) -> Result<F::Item, LoadError> {
+        match self.enqueue(id) {
+            Ok(receiver) => receiver.await.unwrap_or(Err(LoadError::Canceled)),
+            Err(cached) => cached,
+        }
+    }
+
+    pub async fn load_many(&self, ids: &[&str]) -> Vec<Result<F::Item, LoadError>> {
+        let queued: Vec<_> = ids.iter().map(|id| self.enqueue(id)).collect();
+        let mut result = Vec::with_capacity(ids.len());
+        for it in queued {
+            result.push(match it {
+                Ok(receiver) => receiver.await.unwrap_or(Err(LoadError::Canceled)),
+                Err(cached) => cached,
+            });
+        }
+        result
+    }
+
+    // Err is a cache hit.
+    #[allow(clippy::type_complexity)]
+    fn enqueue(
+        &self,
+        id: &str,
+    ) -> Result<oneshot::Receiver<Result<F::Item, LoadError>>, Result<F::Item, LoadError>> {
+        let mut state = self.inner.state.lock().unwrap();
+        if let (Some(ttl), Some((cached_at, value))) = (self.inner.ttl, state.cache.get(id)) {
+            if cached_at.elapsed() < ttl {
+                return Err(value.clone());
+            }
+        }
+        let (sender, receiver) = oneshot::channel();
+        state.pending.entry(id.to_owned()).or_default().push(sender);
+        if !state.scheduled {
+            state.scheduled = true;
+            tokio::spawn(dispatch(self.inner.clone()));
+        }
+        Ok(receiver)
+    }
+
+    pub fn clear_cache(&self) {
+        self.inner.state.lock().unwrap().cache.clear();
+    }
+}
+
+async fn dispatch<F: Fetcher>(inner: Arc<Inner<F>>) {
+    tokio::time::sleep(inner.window).await;
+    let pending = {
+        let mut state = inner.state.lock().unwrap();
+        state.scheduled = false;
+        std::mem::take(&mut state.pending)
+    };
+    let ids: Vec<String> = pending.keys().cloned().collect();
+    let mut results: HashMap<String, Result<F::Item, LoadError>> = HashMap::new();
+    for chunk in ids.chunks(MAX_IDS) {
+        match inner.fetcher.fetch(chunk).await {
+            Ok((items, errors)) => {
+                for item in items {
+                    results.insert(F::id(&item).to_owned(), Ok(item));
+                }
+                for error in errors {
+                    let Some(id) = error.resource_id.clone().or(error.value.clone()) else {
+                        continue;
+                    };
+                    results
+                        .entry(id)
+                        .or_insert(Err(LoadError::Errors(Box::new(error))));
+                }
+            }
+            Err(error) => {
+                let error = Arc::new(error);
+                for id in chunk {
+                    results.insert(id.clone(), Err(LoadError::Request(error.clone())));
+                }
+            }
+        }
+    }
+
+    let mut state = inner.state.lock().unwrap();
+    // Expired entries are evicted once per batch, the cache does not grow with every id ever loaded.
+    if let Some(ttl) = inner.ttl {
+        state
+            .cache
+            .retain(|_, (cached_at, _)| cached_at.elapsed() < ttl);
+    }
+    for (id, waiters) in pending {
+        let value = results.remove(&id).unwrap_or(Err(LoadError::NotFound));
+        if inner.ttl.is_some() && !matches!(value, Err(LoadError::Request(_))) {
+            state.cache.insert(id, (Instant::now(), value.clone()));
+        }
+        for waiter in waiters {
+            let _ = waiter.send(value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use super::{Fetcher, LoadError, Loader};
+    use crate::{error::Error, responses::errors::Errors};
+
+    struct Echo {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Fetcher for Echo {
+        type Item = String;
+
+        fn id(item: &Self::Item) -> &str {
+            item
+        }
+
+        async fn fetch(&self, ids: &[String]) -> Result<(Vec<Self::Item>, Vec<Errors>), Error> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let errors = ids
+                .iter()
+                .filter(|it| it.starts_with('x'))
+                .map(|it| Errors {
+                    resource_id: Some(it.clone()),
+                    title: Some("Not Found Error".to_owned()),
+                    ..Default::default()
+                })
+                .collect();
+            let items = ids
+                .iter()
+                .filter(|it| !it.starts_with('x'))
+                .cloned()
+                .collect();
+            Ok((items, errors))
+        }
+    }
+
+    #[tokio::test]
+    async fn it_batch() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let loader = Loader::new(Echo {
+            calls: calls.clone(),
+        });
+        let ids: Vec<String> = (0..150).map(|it| it.to_string()).collect();
+        let mut ids: Vec<&str> = ids.iter().map(|it| it.as_str()).collect();
+        ids.push("1");
+        ids.push("x1");
+        let results = loader.load_many(&ids).await;
+        assert_eq!(results[0].as_deref().unwrap(), "0");
+        assert_eq!(results[150].as_deref().unwrap(), "1");
+        assert!(matches!(results[151], Err(LoadError::Errors(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn it_evict() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let loader = Loader::with_options(
+            Echo {
+                calls: calls.clone(),
+            },
+            Duration::from_millis(1),
+            Some(Duration::from_millis(20)),
+        );
+        loader.load("a").await.unwrap();
+        loader.load("a").await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        loader.load("b").await.unwrap();
+        let state = loader.inner.state.lock().unwrap();
+        assert_eq!(state.cache.keys().collect::<Vec<_>>(), vec!["b"]);
+    }
+}
@@ -0,0 +1,314 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use reqwest::{
+    header::{HeaderName, HeaderValue},
+    Client, Request, RequestBuilder, Response,
+};
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "retry")]
+use chrono::prelude::*;
+#[cfg(feature = "retry")]
+use reqwest::StatusCode;
+
+#[cfg(feature = "retry")]
+use crate::request::auth_identity;
+use crate::{api::parse_twitter, error::Error, headers::Headers, telemetry};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+// fetch futures are not Send
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// Called for every request sent through a `Chain`. Call `next.run` to pass the request on.
+pub trait Middleware: Send + Sync {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, Error>>;
+}
+
+/// The rest of the chain.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    client: &'a Client,
+    middlewares: &'a [Arc<dyn Middleware>],
+    attempt: usize,
+}
+
+impl<'a> Next<'a> {
+    pub fn run(self, request: Request) -> BoxFuture<'a, Result<Response, Error>> {
+        match self.middlewares.split_first() {
+            Some((middleware, middlewares)) => middleware.handle(
+                request,
+                Next {
+                    middlewares,
+                    ..self
+                },
+            ),
+            None => Box::pin(async move {
+                Ok(telemetry::execute(self.client, request, self.attempt).await?)
+            }),
+        }
+    }
+}
+
+/// Middlewares run in the order they were added.
+#[derive(Clone, Default)]
+pub struct Chain {
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl Chain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    pub async fn send(&self, builder: RequestBuilder) -> Result<Response, Error> {
+        let (client, request) = crate::runtime::build_split(builder);
+        Next {
+            client: &client,
+            middlewares: &self.middlewares,
+            attempt: 0,
+        }
+        .run(request?)
+        .await
+    }
+
+    /// Same as `execute_twitter` but through the chain.
+    pub async fn execute<T>(&self, builder: RequestBuilder) -> Result<(T, Headers), Error>
+    where
+        T: DeserializeOwned,
+    {
+        parse_twitter(self.send(builder).await?).await
+    }
+}
+
+/// Adds headers to every request, existing values are replaced.
+#[derive(Debug, Clone, Default)]
+pub struct InjectHeaders {
+    headers: Vec<(HeaderName, HeaderValue)>,
+}
+
+impl InjectHeaders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+}
+
+impl Middleware for InjectHeaders {
+    fn handle<'a>(
+        &'a self,
+        mut request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, Error>> {
+        for (name, value) in self.headers.iter() {
+            request.headers_mut().insert(name.clone(), value.clone());
+        }
+        next.run(request)
+    }
+}
+
+/// Retries on errors and the given status codes, waiting 2^n seconds unless `delay` is set.
+#[cfg(feature = "retry")]
+#[derive(Debug, Clone)]
+pub struct Retry {
+    retry_count: usize,
+    retryable_status_codes: Vec<reqwest::StatusCode>,
+    delay: Option<std::time::Duration>,
+}
+
+#[cfg(feature = "retry")]
+impl Retry {
+    pub fn new(retry_count: usize, retryable_status_codes: &[reqwest::StatusCode]) -> Self {
+        Self {
+            retry_count,
+            retryable_status_codes: retryable_status_codes.to_vec(),
+            delay: None,
+        }
+    }
+
+    pub fn delay(mut self, value: std::time::Duration) -> Self {
+        self.delay = Some(value);
+        self
+    }
+}
+
+#[cfg(feature = "retry")]
+impl Middleware for Retry {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, Error>> {
+        Box::pin(async move {
+            let mut count = 0;
+            loop {
+                // streaming bodies can not be sent twice
+                let Some(target) = request.try_clone() else {
+                    return next.run(request).await;
+                };
+                let result = Next {
+                    attempt: count,
+                    ..next
+                }
+                .run(target)
+                .await;
+                // transport errors such as a reset connection are retried too
+                let retryable = match &result {
+                    Ok(response) => self.retryable_status_codes.contains(&response.status()),
+                    Err(_) => true,
+                };
+                if count >= self.retry_count || !retryable {
+                    return result;
+                }
+                count += 1;
+                let delay = self
+                    .delay
+                    .unwrap_or(std::time::Duration::from_secs(2_u64.pow(count as u32)));
+                crate::runtime::sleep(delay).await;
+            }
+        })
+    }
+}
+
+/// Waits until `x-rate-limit-reset` when the last response of the same endpoint and user had no requests remaining.
+/// Fails without sending if the wait would be longer than `max_wait`.
+#[cfg(feature = "retry")]
+#[derive(Debug, Default)]
+pub struct WaitRateLimit {
+    max_wait: Option<std::time::Duration>,
+    // (method, endpoint, authorization) -> reset of the exhausted window
+    resets: std::sync::Mutex<std::collections::HashMap<(String, String, String), DateTime<Utc>>>,
+}
+
+#[cfg(feature = "retry")]
+impl WaitRateLimit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_wait(mut self, value: std::time::Duration) -> Self {
+        self.max_wait = Some(value);
+        self
+    }
+
+    fn key(request: &Request) -> (String, String, String) {
+        let method = request.method().as_str();
+        let path = request.url().path();
+        (
+            method.to_owned(),
+            telemetry::endpoint_template(method, path)
+                .unwrap_or(path)
+                .to_owned(),
+            auth_identity(request.headers()),
+        )
+    }
+}
+
+#[cfg(feature = "retry")]
+impl Middleware for WaitRateLimit {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, Error>> {
+        Box::pin(async move {
+            let key = Self::key(&request);
+            let reset = self.resets.lock().unwrap().get(&key).copied();
+            if let Some(reset) = reset {
+                let wait = (reset - Utc::now()).to_std().unwrap_or_default();
+                if self.max_wait.is_some_and(|max_wait| wait > max_wait) {
+                    return Err(Error::Other(
+                        format!("rate limit exceeded until {}", reset),
+                        Some(StatusCode::TOO_MANY_REQUESTS),
+                    ));
+                }
+                crate::runtime::sleep(wait).await;
+            }
+            let response = next.run(request).await?;
+            let headers = Headers::new(response.headers());
+            let mut resets = self.resets.lock().unwrap();
+            match (headers.x_rate_limit_remaining, headers.x_rate_limit_reset) {
+                (Some(0), Some(reset)) => {
+                    resets.insert(key, reset);
+                }
+                _ => {
+                    resets.remove(&key);
+                }
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// Serves successful GET responses from `cache` until the ttl of `options` expires, the rest of the chain is skipped on a hit.
+#[cfg(all(feature = "cache", not(target_arch = "wasm32")))]
+pub struct CacheResponses<C> {
+    cache: C,
+    options: crate::cache::CacheOptions,
+}
+
+#[cfg(all(feature = "cache", not(target_arch = "wasm32")))]
+impl<C> CacheResponses<C> {
+    pub fn new(cache: C, options: crate::cache::CacheOptions) -> Self {
+        Self { cache, options }
+    }
+}
+
+#[cfg(all(feature = "cache", not(target_arch = "wasm32")))]
+impl<C: crate::cache::Cache + Send + Sync> Middleware for CacheResponses<C> {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, Error>> {
+        Box::pin(async move {
+            let Some((key, path)) = crate::cache::request_cache_key(&request) else {
+                return next.run(request).await;
+            };
+            let ttl = self.options.ttl(&path);
+            if ttl.is_zero() {
+                return next.run(request).await;
+            }
+            if let Some(entry) = self.cache.get(&key) {
+                if !entry.is_expired() {
+                    return Ok(entry.response());
+                }
+                self.cache.remove(&key);
+            }
+            let response = next.run(request).await?;
+            if !response.status().is_success() {
+                return Ok(response);
+            }
+            let status = response.status();
+            let header_map = response.headers().clone();
+            let body = response.bytes().await?;
+            if let Ok(text) = std::str::from_utf8(&body) {
+                self.cache.put(crate::cache::CacheEntry::new(
+                    key,
+                    &header_map,
+                    text.to_owned(),
+                    ttl,
+                ));
+            }
+            let mut result = http::Response::new(body);
+            *result.status_mut() = status;
+            *result.headers_mut() = header_map;
+            Ok(Response::from(result))
+        })
+    }
+}
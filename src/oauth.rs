@@ -5,6 +5,8 @@ use oauth2::{
 use std::time::Duration;
 use thiserror::Error;
 
+use crate::{api::TwapiOptions, secret::Secret};
+
 pub enum TwitterScope {
     TweetRead,
     TweetWrite,
@@ -84,6 +86,7 @@ impl std::fmt::Display for TwitterScope {
 
 const AUTH_URL: &str = "https://twitter.com/i/oauth2/authorize";
 const TOKEN_URL: &str = "https://api.twitter.com/2/oauth2/token";
+const TOKEN_PATH: &str = "/2/oauth2/token";
 
 #[derive(Error, Debug)]
 pub enum OAuthError {
@@ -97,13 +100,13 @@ pub enum OAuthError {
 #[derive(Debug, Clone)]
 pub struct OAuthUrlResult {
     pub oauth_url: String,
-    pub pkce_verifier: String,
+    pub pkce_verifier: Secret,
 }
 
 #[derive(Debug, Clone)]
 pub struct TokenResult {
-    pub access_token: String,
-    pub refresh_token: Option<String>,
+    pub access_token: Secret,
+    pub refresh_token: Option<Secret>,
     pub expires_in: Option<Duration>,
 }
 
@@ -120,11 +123,37 @@ impl TwitterOauth {
         callback_url: &str,
         scopes: Vec<TwitterScope>,
     ) -> Result<Self, OAuthError> {
+        Self::with_options(
+            api_key_code,
+            api_secret_code,
+            callback_url,
+            scopes,
+            &TwapiOptions::default(),
+        )
+    }
+
+    /// Authorize and token urls are taken from `twapi_options`.
+    pub fn with_options(
+        api_key_code: &str,
+        api_secret_code: &str,
+        callback_url: &str,
+        scopes: Vec<TwitterScope>,
+        twapi_options: &TwapiOptions,
+    ) -> Result<Self, OAuthError> {
+        let auth_url = twapi_options
+            .oauth_authorize_url
+            .clone()
+            .unwrap_or(AUTH_URL.to_owned());
+        let token_url = match (&twapi_options.oauth_token_url, &twapi_options.prefix_url) {
+            (Some(token_url), _) => token_url.clone(),
+            (None, Some(prefix_url)) => format!("{}{}", prefix_url, TOKEN_PATH),
+            (None, None) => TOKEN_URL.to_owned(),
+        };
         let basic_client = BasicClient::new(
             ClientId::new(api_key_code.to_owned()),
             Some(ClientSecret::new(api_secret_code.to_owned())),
-            AuthUrl::new(AUTH_URL.to_owned())?,
-            Some(TokenUrl::new(TOKEN_URL.to_owned())?),
+            AuthUrl::new(auth_url)?,
+            Some(TokenUrl::new(token_url)?),
         );
         let redirect_url = RedirectUrl::new(callback_url.to_string())?;
         let scopes: Vec<Scope> = scopes
@@ -159,7 +188,7 @@ impl TwitterOauth {
 
         OAuthUrlResult {
             oauth_url: auth_url.to_string(),
-            pkce_verifier: pkce_verifier.secret().to_string(),
+            pkce_verifier: pkce_verifier.secret().into(),
         }
     }
 
@@ -180,8 +209,8 @@ impl TwitterOauth {
             .await
             .map_err(|e| OAuthError::Token(format!("{:?}", e)))?;
         Ok(TokenResult {
-            access_token: token.access_token().secret().to_string(),
-            refresh_token: token.refresh_token().map(|it| it.secret().to_string()),
+            access_token: token.access_token().secret().into(),
+            refresh_token: token.refresh_token().map(|it| it.secret().into()),
             expires_in: token.expires_in(),
         })
     }
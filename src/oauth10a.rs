@@ -1,22 +1,22 @@
-use crate::api::Authentication;
+use crate::{api::Authentication, secret::Secret, validation::AuthContext};
 use base64::{engine::general_purpose, Engine as _};
 use chrono::prelude::*;
 use hmac::{Hmac, Mac};
 use rand::distributions::{Alphanumeric, DistString};
-use reqwest::RequestBuilder;
 use sha1::Sha1;
 
 type HmacSha1 = Hmac<Sha1>;
 
+#[derive(Debug, Clone)]
 pub struct OAuthAuthentication {
-    consumer_key: String,
-    consumer_secret: String,
-    access_key: String,
-    access_secret: String,
+    consumer_key: Secret,
+    consumer_secret: Secret,
+    access_key: Secret,
+    access_secret: Secret,
 }
 
 impl OAuthAuthentication {
-    pub fn new<T: Into<String>>(
+    pub fn new<T: Into<Secret>>(
         consumer_key: T,
         consumer_secret: T,
         access_key: T,
@@ -32,23 +32,20 @@ impl OAuthAuthentication {
 }
 
 impl Authentication for OAuthAuthentication {
-    fn execute(
-        &self,
-        builder: RequestBuilder,
-        method: &str,
-        uri: &str,
-        options: &[(&str, &str)],
-    ) -> RequestBuilder {
-        let auth = oauth1_authorization_header(
-            &self.consumer_key,
-            &self.consumer_secret,
-            &self.access_key,
-            &self.access_secret,
+    fn auth_context(&self) -> AuthContext {
+        AuthContext::User
+    }
+
+    fn authorization(&self, method: &str, uri: &str, options: &[(&str, &str)]) -> Option<String> {
+        Some(oauth1_authorization_header(
+            self.consumer_key.expose(),
+            self.consumer_secret.expose(),
+            self.access_key.expose(),
+            self.access_secret.expose(),
             method,
             uri,
             &options.to_vec(),
-        );
-        builder.header(reqwest::header::AUTHORIZATION, auth)
+        ))
     }
 }
 
@@ -112,12 +109,7 @@ fn timestamp() -> String {
 }
 
 pub fn encode(s: &str) -> String {
-    // Twitter API URL encode space is %20 not +
-    form_urlencoded::byte_serialize(s.as_bytes())
-        .collect::<String>()
-        .replace('+', "%20")
-        .replace('*', "%2A")
-        .replace("%7E", "~")
+    crate::request::encode(s)
 }
 
 fn sign(base: &str, key: &str) -> String {
@@ -0,0 +1,226 @@
+use url::Url;
+
+const PREFIX_URL: &str = "https://x.com";
+const HOSTS: [&str; 8] = [
+    "twitter.com",
+    "www.twitter.com",
+    "mobile.twitter.com",
+    "m.twitter.com",
+    "x.com",
+    "www.x.com",
+    "mobile.x.com",
+    "m.x.com",
+];
+const SHORT_HOSTS: [&str; 1] = ["t.co"];
+const RESERVED_PATHS: [&str; 18] = [
+    "compose",
+    "explore",
+    "hashtag",
+    "home",
+    "i",
+    "intent",
+    "jobs",
+    "login",
+    "logout",
+    "messages",
+    "notifications",
+    "privacy",
+    "search",
+    "settings",
+    "share",
+    "signup",
+    "tos",
+    "who_to_follow",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Permalink {
+    /// Use with `get_2_tweets_id::Api::new`.
+    Tweet {
+        id: String,
+        username: Option<String>,
+    },
+    /// Use with `get_2_users_by_username_username::Api::new`.
+    User { username: String },
+    /// Use with `get_2_users_id::Api::new`.
+    UserId { id: String },
+    /// Use with `get_2_lists_id::Api::new`.
+    List { id: String },
+    /// Use with `get_2_spaces_id::Api::new`.
+    Space { id: String },
+    /// t.co links must be resolved by following the redirect.
+    ShortLink { url: String },
+}
+
+impl Permalink {
+    pub fn parse(src: &str) -> Option<Self> {
+        let src = src.trim();
+        let url = if src.contains("://") {
+            Url::parse(src).ok()?
+        } else {
+            Url::parse(&format!("https://{}", src)).ok()?
+        };
+        let host = url.host_str()?.to_lowercase();
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|it| it.filter(|segment| !segment.is_empty()).collect())
+            .unwrap_or_default();
+
+        if SHORT_HOSTS.contains(&host.as_str()) {
+            return match segments.as_slice() {
+                [code] => Some(Self::ShortLink {
+                    url: format!("https://{}/{}", host, code),
+                }),
+                _ => None,
+            };
+        }
+        if !HOSTS.contains(&host.as_str()) {
+            return None;
+        }
+
+        match segments.as_slice() {
+            ["i", "web", "status", id, ..] | ["i", "status", id, ..] => Self::tweet(id, None),
+            ["i", "lists", id, ..] if is_numeric(id) => Some(Self::List { id: id.to_string() }),
+            ["i", "spaces", id, ..] if is_space_id(id) => Some(Self::Space { id: id.to_string() }),
+            ["i", "user", id, ..] if is_numeric(id) => Some(Self::UserId { id: id.to_string() }),
+            ["intent", "user"] | ["intent", "follow"] => {
+                let query = |key: &str| {
+                    url.query_pairs()
+                        .find(|(name, _)| name == key)
+                        .map(|(_, value)| value.to_string())
+                };
+                if let Some(id) = query("user_id").filter(|it| is_numeric(it)) {
+                    Some(Self::UserId { id })
+                } else {
+                    query("screen_name")
+                        .filter(|it| is_username(it))
+                        .map(|username| Self::User { username })
+                }
+            }
+            [username, "status", id, ..] | [username, "statuses", id, ..]
+                if is_username(username) =>
+            {
+                Self::tweet(id, Some(username))
+            }
+            [username, ..] if is_username(username) => Some(Self::User {
+                username: username.to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn tweet(id: &str, username: Option<&str>) -> Option<Self> {
+        if !is_numeric(id) {
+            return None;
+        }
+        Some(Self::Tweet {
+            id: id.to_owned(),
+            username: username.map(|it| it.to_owned()),
+        })
+    }
+
+    /// Canonical x.com permalink.
+    pub fn to_url(&self) -> String {
+        match self {
+            Self::Tweet {
+                id,
+                username: Some(username),
+            } => format!("{}/{}/status/{}", PREFIX_URL, username, id),
+            Self::Tweet { id, username: None } => format!("{}/i/web/status/{}", PREFIX_URL, id),
+            Self::User { username } => format!("{}/{}", PREFIX_URL, username),
+            Self::UserId { id } => format!("{}/i/user/{}", PREFIX_URL, id),
+            Self::List { id } => format!("{}/i/lists/{}", PREFIX_URL, id),
+            Self::Space { id } => format!("{}/i/spaces/{}", PREFIX_URL, id),
+            Self::ShortLink { url } => url.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for Permalink {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_url())
+    }
+}
+
+impl std::str::FromStr for Permalink {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or(format!("unsupported url {}", s))
+    }
+}
+
+fn is_numeric(src: &str) -> bool {
+    !src.is_empty() && src.len() <= 20 && src.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_space_id(src: &str) -> bool {
+    !src.is_empty() && src.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_username(src: &str) -> bool {
+    (1..=15).contains(&src.len())
+        && src.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !RESERVED_PATHS.contains(&src.to_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Permalink;
+
+    #[test]
+    fn it_parse() {
+        assert_eq!(
+            Permalink::parse("https://x.com/user/status/123?s=20"),
+            Some(Permalink::Tweet {
+                id: "123".to_owned(),
+                username: Some("user".to_owned())
+            })
+        );
+        assert_eq!(
+            Permalink::parse("mobile.twitter.com/i/web/status/123/photo/1"),
+            Some(Permalink::Tweet {
+                id: "123".to_owned(),
+                username: None
+            })
+        );
+        assert_eq!(
+            Permalink::parse("twitter.com/i/lists/456"),
+            Some(Permalink::List {
+                id: "456".to_owned()
+            })
+        );
+        assert_eq!(
+            Permalink::parse("https://twitter.com/i/spaces/1abcDEF"),
+            Some(Permalink::Space {
+                id: "1abcDEF".to_owned()
+            })
+        );
+        assert_eq!(
+            Permalink::parse("https://twitter.com/intent/user?user_id=789"),
+            Some(Permalink::UserId {
+                id: "789".to_owned()
+            })
+        );
+        assert_eq!(
+            Permalink::parse("https://www.x.com/rustlang/"),
+            Some(Permalink::User {
+                username: "rustlang".to_owned()
+            })
+        );
+        assert_eq!(
+            Permalink::parse("https://t.co/AbCdE"),
+            Some(Permalink::ShortLink {
+                url: "https://t.co/AbCdE".to_owned()
+            })
+        );
+        assert_eq!(Permalink::parse("https://x.com/home"), None);
+        assert_eq!(Permalink::parse("https://example.com/user/status/1"), None);
+    }
+
+    #[test]
+    fn it_to_url() {
+        let permalink: Permalink = "twitter.com/user/statuses/123".parse().unwrap();
+        assert_eq!(permalink.to_string(), "https://x.com/user/status/123");
+    }
+}
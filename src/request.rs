@@ -0,0 +1,482 @@
+use std::{future::Future, time::Duration};
+
+use base64::{engine::general_purpose, Engine as _};
+use http::StatusCode;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    api::{make_twitter_error, Authentication, TwapiOptions},
+    error::Error,
+    headers::Headers,
+    runtime::{MaybeSend, MaybeSync},
+    validation::Validator,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl std::fmt::Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Get => write!(f, "GET"),
+            Self::Post => write!(f, "POST"),
+            Self::Put => write!(f, "PUT"),
+            Self::Delete => write!(f, "DELETE"),
+        }
+    }
+}
+
+#[cfg(feature = "upload")]
+#[derive(Debug, Clone)]
+pub enum Part {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+#[cfg(feature = "upload")]
+impl Part {
+    pub fn text<T: Into<String>>(value: T) -> Self {
+        Self::Text(value.into())
+    }
+
+    pub fn bytes<T: Into<Vec<u8>>>(value: T) -> Self {
+        Self::Bytes(value.into())
+    }
+}
+
+/// multipart/form-data
+#[cfg(feature = "upload")]
+#[derive(Debug, Clone, Default)]
+pub struct Form {
+    pub parts: Vec<(String, Part)>,
+}
+
+#[cfg(feature = "upload")]
+impl Form {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text<T: Into<String>, U: Into<String>>(self, name: T, value: U) -> Self {
+        self.part(name, Part::text(value))
+    }
+
+    pub fn part<T: Into<String>>(mut self, name: T, part: Part) -> Self {
+        self.parts.push((name.into(), part));
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Body {
+    Json(serde_json::Value),
+    Form(Vec<(String, String)>),
+    #[cfg(feature = "upload")]
+    Multipart(Form),
+}
+
+/// HTTP request independent of the http client.
+#[derive(Debug, Clone)]
+pub struct TwapiRequest {
+    pub method: Method,
+    /// Without query.
+    pub url: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Body>,
+    pub timeout: Option<Duration>,
+    /// From `TwapiOptions`, used by `validate`.
+    pub validator: Option<Validator>,
+    /// Serialization error of `json`, the request fails instead of sending it.
+    pub error: Option<String>,
+}
+
+impl TwapiRequest {
+    pub fn new(method: Method, url: &str) -> Self {
+        Self {
+            method,
+            url: url.to_owned(),
+            query: vec![],
+            headers: vec![],
+            body: None,
+            timeout: None,
+            validator: None,
+            error: None,
+        }
+    }
+
+    pub fn query(mut self, value: Vec<(&str, String)>) -> Self {
+        self.query.extend(
+            value
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value)),
+        );
+        self
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub fn json<T: Serialize>(mut self, value: &T) -> Self {
+        match serde_json::to_value(value) {
+            Ok(value) => self.body = Some(Body::Json(value)),
+            Err(err) => self.error = Some(err.to_string()),
+        }
+        self
+    }
+
+    pub fn form(mut self, value: Vec<(&str, String)>) -> Self {
+        self.body = Some(Body::Form(
+            value
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "upload")]
+    pub fn multipart(mut self, value: Form) -> Self {
+        self.body = Some(Body::Multipart(value));
+        self
+    }
+
+    pub fn basic_auth(self, username: &str, password: &str) -> Self {
+        let value = format!(
+            "Basic {}",
+            general_purpose::STANDARD.encode(format!("{}:{}", username, password))
+        );
+        self.header("authorization", &value)
+    }
+
+    pub fn twapi_options(mut self, value: &Option<TwapiOptions>) -> Self {
+        if let Some(timeout) = value.as_ref().and_then(|it| it.timeout) {
+            self.timeout = Some(timeout);
+        }
+        if let Some(validator) = value.as_ref().and_then(|it| it.validator.as_ref()) {
+            self.validator = Some(validator.clone());
+        }
+        self
+    }
+
+    /// Checks fields and expansions by the validator, Ok without it.
+    pub fn validate(self, authentication: &impl Authentication) -> Result<Self, Error> {
+        self.check_error()?;
+        if let Some(validator) = &self.validator {
+            validator.check(&self, authentication.auth_context())?;
+        }
+        Ok(self)
+    }
+
+    /// Add the Authorization header by `Authentication::sign`.
+    pub fn authorize(self, authentication: &impl Authentication) -> Self {
+        authentication.sign(self)
+    }
+
+    pub fn url_with_query(&self) -> String {
+        if self.query.is_empty() {
+            return self.url.clone();
+        }
+        let query: Vec<String> = self
+            .query
+            .iter()
+            .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+            .collect();
+        format!("{}?{}", self.url, query.join("&"))
+    }
+
+    /// Err if `json` failed to serialize the body.
+    pub fn check_error(&self) -> Result<(), Error> {
+        match &self.error {
+            Some(err) => Err(Error::Json(serde::ser::Error::custom(err))),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn query_refs(&self) -> Vec<(&str, &str)> {
+        self.query
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect()
+    }
+
+    /// reqwest builder without authentication.
+    #[cfg(feature = "reqwest")]
+    pub fn into_reqwest(self) -> reqwest::RequestBuilder {
+        self.into_reqwest_with(&reqwest::Client::new())
+    }
+
+    #[cfg(feature = "reqwest")]
+    pub fn into_reqwest_with(self, client: &reqwest::Client) -> reqwest::RequestBuilder {
+        let method = match self.method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+            Method::Put => reqwest::Method::PUT,
+            Method::Delete => reqwest::Method::DELETE,
+        };
+        let mut builder = client.request(method, &self.url);
+        if !self.query.is_empty() {
+            builder = builder.query(&self.query);
+        }
+        for (name, value) in self.headers.iter() {
+            builder = builder.header(name, value);
+        }
+        if let Some(err) = &self.error {
+            builder = builder.json(&JsonError(err));
+        }
+        builder = match self.body {
+            Some(Body::Json(value)) => builder.json(&value),
+            Some(Body::Form(value)) => builder.form(&value),
+            #[cfg(feature = "upload")]
+            Some(Body::Multipart(value)) => {
+                let mut form = reqwest::multipart::Form::new();
+                for (name, part) in value.parts {
+                    form = match part {
+                        Part::Text(text) => form.text(name, text),
+                        Part::Bytes(bytes) => {
+                            form.part(name, reqwest::multipart::Part::bytes(bytes))
+                        }
+                    };
+                }
+                builder.multipart(form)
+            }
+            None => builder,
+        };
+        crate::runtime::with_timeout(builder, self.timeout)
+    }
+
+    /// reqwest builder through `Authentication::execute`.
+    #[cfg(feature = "reqwest")]
+    pub fn build(self, authentication: &impl Authentication) -> reqwest::RequestBuilder {
+        let method = self.method.to_string();
+        let url = self.url.clone();
+        let query = self.query.clone();
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        authentication.execute(self.into_reqwest(), &method, &url, &query)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Sends a `TwapiRequest`. Implement this to use an http client other than reqwest.
+/// The client and its futures are `Sync` and `Send` on native targets, so requests can be spawned.
+pub trait HttpClient: MaybeSync {
+    fn execute(
+        &self,
+        request: TwapiRequest,
+    ) -> impl Future<Output = Result<HttpResponse, Error>> + MaybeSend;
+}
+
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestClient {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestClient {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpClient for ReqwestClient {
+    async fn execute(&self, request: TwapiRequest) -> Result<HttpResponse, Error> {
+        let request = request.into_reqwest_with(&self.client).build()?;
+        let response = crate::telemetry::execute(&self.client, request, 0).await?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+            .collect();
+        let body = response.bytes().await?.to_vec();
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+pub async fn execute_request<T>(
+    client: &impl HttpClient,
+    request: TwapiRequest,
+) -> Result<(T, Headers), Error>
+where
+    T: DeserializeOwned,
+{
+    request.check_error()?;
+    parse_response(client.execute(request).await?)
+}
+
+pub async fn execute_request_no_response(
+    client: &impl HttpClient,
+    request: TwapiRequest,
+) -> Result<Headers, Error> {
+    request.check_error()?;
+    let response = client.execute(request).await?;
+    let (status_code, headers) = status_and_headers(&response)?;
+    if status_code.is_success() {
+        Ok(headers)
+    } else {
+        let body = String::from_utf8_lossy(&response.body).to_string();
+        Err(Error::Other(body, Some(status_code)))
+    }
+}
+
+pub fn parse_response<T>(response: HttpResponse) -> Result<(T, Headers), Error>
+where
+    T: DeserializeOwned,
+{
+    let (status_code, headers) = status_and_headers(&response)?;
+    if status_code.is_success() {
+        Ok((serde_json::from_slice(&response.body)?, headers))
+    } else {
+        let text = String::from_utf8_lossy(&response.body);
+        Err(make_twitter_error(&text, status_code, headers))
+    }
+}
+
+fn status_and_headers(response: &HttpResponse) -> Result<(StatusCode, Headers), Error> {
+    let status_code = StatusCode::from_u16(response.status)
+        .map_err(|_| Error::Other(format!("invalid status {}", response.status), None))?;
+    let headers = Headers::from_pairs(
+        response
+            .headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str())),
+    );
+    Ok((status_code, headers))
+}
+
+#[cfg(any(feature = "cache", feature = "retry"))]
+pub(crate) fn fnv1a(src: &str) -> u64 {
+    src.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+// Bearer token, or consumer key and access token for OAuth1.0a. Nonce and signature are ignored.
+#[cfg(any(feature = "cache", feature = "retry"))]
+pub(crate) fn auth_identity(headers: &reqwest::header::HeaderMap) -> String {
+    let Some(value) = headers
+        .get(reqwest::header::AUTHORIZATION)
+        .and_then(|it| it.to_str().ok())
+    else {
+        return "anonymous".to_owned();
+    };
+    if let Some(params) = value.strip_prefix("OAuth ") {
+        let mut keys: Vec<&str> = params
+            .split(',')
+            .map(|it| it.trim())
+            .filter(|it| it.starts_with("oauth_consumer_key=") || it.starts_with("oauth_token="))
+            .collect();
+        keys.sort();
+        format!("oauth:{:016x}", fnv1a(&keys.join(",")))
+    } else {
+        format!("auth:{:016x}", fnv1a(value))
+    }
+}
+
+/// RFC 3986 percent encoding, only unreserved characters are kept.
+pub(crate) fn encode(src: &str) -> String {
+    // Twitter API URL encode space is %20 not +
+    form_urlencoded::byte_serialize(src.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+        .replace('*', "%2A")
+        .replace("%7E", "~")
+}
+
+// Fails serialization with the error of `TwapiRequest::json`, reqwest reports it on build.
+#[cfg(feature = "reqwest")]
+struct JsonError<'a>(&'a str);
+
+#[cfg(feature = "reqwest")]
+impl Serialize for JsonError<'_> {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{get_2_users_id, post_2_tweets, BearerAuthentication};
+
+    #[test]
+    fn it_request() {
+        let auth = BearerAuthentication::new("token");
+        let request = get_2_users_id::Api::new("1").request().authorize(&auth);
+        assert_eq!(request.method, Method::Get);
+        assert!(request.url.ends_with("/2/users/1"));
+        assert!(request
+            .headers
+            .contains(&("authorization".to_owned(), "Bearer token".to_owned())));
+
+        let body = post_2_tweets::Body {
+            text: Some("hello".to_owned()),
+            ..Default::default()
+        };
+        let request = post_2_tweets::Api::new(body).request();
+        assert!(matches!(request.body, Some(Body::Json(_))));
+
+        let request = TwapiRequest::new(Method::Get, "https://example.com/a")
+            .query(vec![("q", "a b&c".to_owned())])
+            .basic_auth("user", "pass");
+        assert_eq!(
+            request.url_with_query(),
+            "https://example.com/a?q=a%20b%26c"
+        );
+        assert_eq!(request.headers[0].1, "Basic dXNlcjpwYXNz");
+
+        // map keys must be strings in JSON
+        let value = std::collections::HashMap::from([((1, 2), 3)]);
+        let request = TwapiRequest::new(Method::Post, "https://example.com/a").json(&value);
+        assert!(request.body.is_none());
+        assert!(matches!(request.validate(&auth), Err(Error::Json(_))));
+        let request = TwapiRequest::new(Method::Post, "https://example.com/a").json(&value);
+        assert!(request.into_reqwest().build().is_err());
+    }
+
+    struct FakeClient;
+
+    impl HttpClient for FakeClient {
+        async fn execute(&self, request: TwapiRequest) -> Result<HttpResponse, Error> {
+            assert_eq!(request.method, Method::Get);
+            Ok(HttpResponse {
+                status: 200,
+                headers: vec![("X-Rate-Limit-Remaining".to_owned(), "9".to_owned())],
+                body: br#"{"data":{"id":"1","name":"a","username":"b"}}"#.to_vec(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn it_execute_with() {
+        let auth = BearerAuthentication::new("token");
+        let (response, headers) = get_2_users_id::Api::new("1")
+            .execute_with(&FakeClient, &auth)
+            .await
+            .unwrap();
+        assert_eq!(response.data.unwrap().id, "1");
+        assert_eq!(headers.x_rate_limit_remaining, Some(9));
+    }
+}
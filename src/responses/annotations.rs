@@ -1,17 +1,20 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Annotations {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<i64>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_f64))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub probability: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub normalized_text: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
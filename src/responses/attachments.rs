@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Attachments {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub media_keys: Option<Vec<String>>,
@@ -10,6 +11,7 @@ pub struct Attachments {
     pub poll_ids: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub media_source_tweet_id: Option<Vec<String>>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
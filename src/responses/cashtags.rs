@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Cashtags {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start: Option<i64>,
@@ -10,6 +11,7 @@ pub struct Cashtags {
     pub cashtag: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
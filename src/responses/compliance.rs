@@ -3,13 +3,16 @@ use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Compliance {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tweet: Option<ComplianceTweet>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<ComplianceUser>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_date_time))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_at: Option<DateTime<Utc>>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ComplianceTweet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author_id: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -2,6 +2,7 @@ use crate::responses::usage::Usage;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DailyClientAppUsage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_app_id: Option<String>,
@@ -9,6 +10,7 @@ pub struct DailyClientAppUsage {
     pub usage_result_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage: Option<Vec<Usage>>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
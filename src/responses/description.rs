@@ -2,6 +2,7 @@ use crate::responses::{hashtags::Hashtags, mentions::Mentions, urls::Urls};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Description {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub urls: Option<Vec<Urls>>,
@@ -9,6 +10,7 @@ pub struct Description {
     pub hashtags: Option<Vec<Hashtags>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mentions: Option<Vec<Mentions>>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
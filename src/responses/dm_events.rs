@@ -3,6 +3,7 @@ use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DmEvents {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
@@ -10,6 +11,7 @@ pub struct DmEvents {
     pub text: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_type: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_date_time))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,6 +22,7 @@ pub struct DmEvents {
     pub attachments: Option<Attachments>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub referenced_tweets: Option<Vec<ReferencedTweets>>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -2,13 +2,16 @@ use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EditControls {
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_date_time))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub editable_until: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub edits_remaining: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_edit_eligible: Option<bool>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -5,6 +5,7 @@ use crate::responses::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Entities {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub annotations: Option<Vec<Annotations>>,
@@ -16,6 +17,7 @@ pub struct Entities {
     pub mentions: Option<Vec<Mentions>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub urls: Option<Vec<Urls>>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
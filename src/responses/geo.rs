@@ -2,9 +2,11 @@ use crate::responses::coordinates::Coordinates;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Geo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#type: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_vec_f64))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Vec<f64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -13,8 +15,10 @@ pub struct Geo {
     pub coordinates: Option<Coordinates>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub place_id: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_json))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -2,6 +2,7 @@ use crate::responses::{media::Media, places::Places, polls::Polls, tweets::Tweet
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Includes {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub media: Option<Vec<Media>>,
@@ -13,6 +14,7 @@ pub struct Includes {
     pub tweets: Option<Vec<Tweets>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<Vec<Users>>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
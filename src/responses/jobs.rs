@@ -2,9 +2,11 @@ use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Jobs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_date_time))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -13,10 +15,12 @@ pub struct Jobs {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub upload_url: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_date_time))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub upload_expires_at: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub download_url: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_date_time))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub download_expires_at: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,6 +31,7 @@ pub struct Jobs {
     pub error: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resumable: Option<bool>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -42,6 +47,7 @@ impl Jobs {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Type {
     #[serde(rename = "tweets")]
     Tweets,
@@ -65,6 +71,7 @@ impl Default for Type {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Status {
     #[serde(rename = "created")]
     Created,
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Meta {
     pub result_count: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -11,6 +12,7 @@ pub struct Meta {
     pub next_token: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_token: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Options {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<i64>,
@@ -8,6 +9,7 @@ pub struct Options {
     pub label: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub votes: Option<i64>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -2,6 +2,7 @@ use crate::responses::geo::Geo;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Places {
     pub full_name: String,
     pub id: String,
@@ -17,6 +18,7 @@ pub struct Places {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub place_type: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PromotedMetrics {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub impression_count: Option<i64>,
@@ -16,6 +17,7 @@ pub struct PromotedMetrics {
     pub url_link_clicks: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_profile_clicks: Option<i64>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
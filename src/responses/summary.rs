@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Summary {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i64>,
@@ -14,6 +15,7 @@ pub struct Summary {
     pub valid: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invalid: Option<i64>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -8,6 +8,7 @@ use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Tweets {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attachments: Option<Attachments>,
@@ -17,6 +18,7 @@ pub struct Tweets {
     pub context_annotations: Option<Vec<ContextAnnotations>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conversation_id: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fixtures::option_date_time))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -52,6 +54,7 @@ pub struct Tweets {
     pub withheld: Option<Withheld>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -2,6 +2,7 @@ use crate::responses::images::Images;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Urls {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_url: Option<String>,
@@ -25,6 +26,7 @@ pub struct Urls {
     pub url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unwound_url: Option<String>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
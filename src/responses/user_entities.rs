@@ -2,11 +2,13 @@ use crate::responses::{description::Description, user_url::UserUrl};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UserEntities {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<UserUrl>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<Description>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -2,9 +2,11 @@ use crate::responses::urls::Urls;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UserUrl {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub urls: Option<Vec<Urls>>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -1,9 +1,13 @@
 use reqwest::{RequestBuilder, StatusCode};
 use serde::de::DeserializeOwned;
 use std::time::Duration;
-use tokio::time::{sleep, timeout};
 
-use crate::{api::execute_twitter, error::Error, headers::Headers};
+use crate::{
+    api::execute_twitter_attempt,
+    error::Error,
+    headers::Headers,
+    runtime::{sleep, timeout},
+};
 
 pub trait RetryLogger {
     fn log(&self, builder: &RequestBuilder);
@@ -31,8 +35,8 @@ where
         }
 
         let error = if let Some(timeout_duration) = timeout_duration {
-            match timeout(timeout_duration, execute_twitter(target)).await {
-                Ok(res) => match res {
+            match timeout(timeout_duration, execute_twitter_attempt(target, count)).await {
+                Some(res) => match res {
                     Ok(res) => return Ok(res),
                     Err(err) => match &err {
                         Error::Twitter(twitter_error, _, _) => {
@@ -44,10 +48,10 @@ where
                         _ => return Err(err),
                     },
                 },
-                Err(_) => Error::Timeout,
+                None => Error::Timeout,
             }
         } else {
-            match execute_twitter(target).await {
+            match execute_twitter_attempt(target, count).await {
                 Ok(res) => return Ok(res),
                 Err(err) => match &err {
                     Error::Twitter(twitter_error, _, _) => {
@@ -85,6 +89,7 @@ mod tests {
             BearerAuthentication,
         },
         retry::execute_retry,
+        secret::redact_request,
     };
 
     use super::RetryLogger;
@@ -92,7 +97,7 @@ mod tests {
     struct Logger;
     impl RetryLogger for Logger {
         fn log(&self, builder: &RequestBuilder) {
-            println!("{:?}", builder);
+            println!("{}", redact_request(builder));
         }
     }
 
@@ -0,0 +1,151 @@
+//! Platform dependent parts. tokio on native targets, gloo-timers and fetch(reqwest) on wasm32.
+
+#[cfg(any(feature = "reqwest", feature = "tokio", feature = "wasm"))]
+use std::time::Duration;
+
+// Only `timeout` polls futures by hand.
+#[cfg(any(
+    all(not(target_arch = "wasm32"), feature = "tokio"),
+    all(target_arch = "wasm32", feature = "wasm")
+))]
+use std::{future::Future, pin::pin, task::Poll};
+
+#[cfg(feature = "reqwest")]
+use reqwest::{Client, Request, RequestBuilder};
+
+// Used by telemetry.
+#[cfg(all(not(target_arch = "wasm32"), feature = "reqwest"))]
+pub(crate) use std::time::Instant;
+
+/// `Send` on native targets. Futures of the fetch client are not `Send` on wasm32.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+/// `Sync` on native targets, see `MaybeSend`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSync: Sync {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Sync> MaybeSync for T {}
+
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSync {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSync for T {}
+
+// std::time::Instant panics on wasm32-unknown-unknown
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub(crate) use web_time::Instant;
+
+#[cfg(all(target_arch = "wasm32", feature = "reqwest", not(feature = "wasm")))]
+pub(crate) use std::time::Instant;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
+pub async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// `None` when the duration elapsed before the future completed.
+#[cfg(any(
+    all(not(target_arch = "wasm32"), feature = "tokio"),
+    all(target_arch = "wasm32", feature = "wasm")
+))]
+pub async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let mut timer = pin!(sleep(duration));
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        match timer.as_mut().poll(cx) {
+            Poll::Ready(()) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    })
+    .await
+}
+
+/// `RequestBuilder::build_split` is not available on wasm32.
+#[cfg(all(not(target_arch = "wasm32"), feature = "reqwest"))]
+pub(crate) fn build_split(builder: RequestBuilder) -> (Client, reqwest::Result<Request>) {
+    builder.build_split()
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "reqwest"))]
+pub(crate) fn build_split(builder: RequestBuilder) -> (Client, reqwest::Result<Request>) {
+    (Client::new(), builder.build())
+}
+
+/// Request timeout is not supported by the fetch client.
+#[cfg(all(not(target_arch = "wasm32"), feature = "reqwest"))]
+pub(crate) fn with_timeout(builder: RequestBuilder, timeout: Option<Duration>) -> RequestBuilder {
+    match timeout {
+        Some(timeout) => builder.timeout(timeout),
+        None => builder,
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "reqwest"))]
+pub(crate) fn with_timeout(builder: RequestBuilder, _timeout: Option<Duration>) -> RequestBuilder {
+    builder
+}
+
+/// Process wide settings such as prefix urls. Environment variables are not available on wasm32.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn get_var(key: &str) -> Option<String> {
+    std::env::var(key).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn set_var(key: &str, value: &str) {
+    std::env::set_var(key, value);
+}
+
+#[cfg(target_arch = "wasm32")]
+static VARS: std::sync::Mutex<Vec<(String, String)>> = std::sync::Mutex::new(Vec::new());
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn get_var(key: &str) -> Option<String> {
+    let vars = VARS.lock().ok()?;
+    vars.iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.clone())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn set_var(key: &str, value: &str) {
+    if let Ok(mut vars) = VARS.lock() {
+        vars.retain(|(name, _)| name != key);
+        vars.push((key.to_owned(), value.to_owned()));
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::{sleep, timeout};
+
+    #[tokio::test]
+    async fn it_timeout() {
+        let res = timeout(Duration::from_millis(100), async { 1 }).await;
+        assert_eq!(res, Some(1));
+        let res = timeout(Duration::from_millis(10), sleep(Duration::from_secs(10))).await;
+        assert_eq!(res, None);
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroize;
+
+pub(crate) const REDACTED: &str = "[REDACTED]";
+#[cfg(feature = "reqwest")]
+pub(crate) const SENSITIVE_HEADERS: [&str; 4] = [
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+];
+
+/// Credential string. Debug and Display are redacted and memory is zeroized on drop.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new<T: Into<String>>(value: T) -> Self {
+        Self(value.into())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", REDACTED)
+    }
+}
+
+impl std::fmt::Display for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", REDACTED)
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl From<&String> for Secret {
+    fn from(value: &String) -> Self {
+        Self(value.clone())
+    }
+}
+
+// The raw value is written so that tokens can be stored.
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Method, url and headers of the request with credentials masked. The body is not included.
+#[cfg(feature = "reqwest")]
+pub fn redact_request(builder: &reqwest::RequestBuilder) -> String {
+    let Some(request) = builder.try_clone().and_then(|it| it.build().ok()) else {
+        return format!("RequestBuilder {{ {} }}", REDACTED);
+    };
+    let headers: Vec<String> = request
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            let value = if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                REDACTED
+            } else {
+                value.to_str().unwrap_or_default()
+            };
+            format!("{:?}: {:?}", name.as_str(), value)
+        })
+        .collect();
+    format!(
+        "Request {{ method: {}, url: {}, headers: {{{}}} }}",
+        request.method(),
+        request.url(),
+        headers.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{redact_request, Secret};
+    use crate::api::{get_2_users_me, BearerAuthentication};
+
+    #[test]
+    fn it_redact() {
+        let secret = Secret::new("token");
+        assert_eq!(format!("{:?} {}", secret, secret), "[REDACTED] [REDACTED]");
+        assert_eq!(secret.expose(), "token");
+
+        let auth = BearerAuthentication::new("token");
+        let text = redact_request(&get_2_users_me::Api::new().build(&auth));
+        assert!(text.contains("/2/users/me"));
+        assert!(!text.contains("token"));
+        assert!(!format!("{:?}", auth).contains("token"));
+    }
+}
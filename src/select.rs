@@ -0,0 +1,141 @@
+//! Typed field selection.
+//! `select!` defines a struct which has only the requested fields, and they are not optional.
+//! Fields the API omits when they have no value, such as `attachments` of a tweet, are `Option`.
+//! Accessing a field which is not requested does not compile.
+//!
+//! ```no_run
+//! use twapi_v2::{api::{get_2_tweets_id, BearerAuthentication}, error::Error, select::Select};
+//!
+//! twapi_v2::select! {
+//!     #[derive(Debug, Clone)]
+//!     pub struct MyTweet: tweet { id, text, created_at, public_metrics }
+//! }
+//!
+//! # async fn run(auth: BearerAuthentication, id: &str) -> Result<(), Error> {
+//! let api = get_2_tweets_id::Api::new(id).tweet_fields(MyTweet::fields());
+//! let (response, _) = api.execute(&auth).await?;
+//! let tweet = MyTweet::select(response.data.unwrap_or_default())?;
+//! println!("{} {}", tweet.created_at, tweet.public_metrics.like_count.unwrap_or_default());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::HashSet, hash::Hash};
+
+use crate::error::Error;
+
+pub mod dm_event;
+pub mod list;
+pub mod media;
+pub mod place;
+pub mod poll;
+pub mod space;
+pub mod topic;
+pub mod tweet;
+pub mod user;
+
+/// Marker of a field, generated in `select::tweet`, `select::user`, ...
+pub trait Field {
+    type Source;
+    type Fields;
+    type Value;
+    const FIELD: Self::Fields;
+    const NAME: &'static str;
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value>;
+}
+
+/// Implemented by `select!`.
+pub trait Select: Sized {
+    type Source;
+    type Fields: Eq + Hash;
+
+    fn fields() -> HashSet<Self::Fields>;
+
+    fn select(source: Self::Source) -> Result<Self, Error>;
+
+    fn select_vec(sources: Vec<Self::Source>) -> Result<Vec<Self>, Error> {
+        sources.into_iter().map(Self::select).collect()
+    }
+}
+
+/// Fails when the response does not have the field.
+pub fn take<F: Field>(source: &mut F::Source) -> Result<F::Value, Error> {
+    F::take(source).ok_or_else(|| Error::Other(format!("{} is not in the response", F::NAME), None))
+}
+
+#[macro_export]
+macro_rules! select {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident: $module:ident { $($field:ident),* $(,)? }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(pub $field: <$crate::select::$module::$field as $crate::select::Field>::Value,)*
+        }
+
+        impl $crate::select::Select for $name {
+            type Source = $crate::select::$module::Source;
+            type Fields = $crate::select::$module::Fields;
+
+            fn fields() -> std::collections::HashSet<Self::Fields> {
+                [$(<$crate::select::$module::$field as $crate::select::Field>::FIELD),*]
+                    .into_iter()
+                    .collect()
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn select(mut source: Self::Source) -> Result<Self, $crate::error::Error> {
+                Ok(Self {
+                    $($field: $crate::select::take::<$crate::select::$module::$field>(&mut source)?,)*
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Select;
+    use crate::fields::tweet_fields::TweetFields;
+
+    crate::select! {
+        #[derive(Debug)]
+        struct Tweet: tweet { id, created_at, public_metrics, attachments }
+    }
+
+    #[test]
+    fn it_select() {
+        assert_eq!(
+            Tweet::fields(),
+            [
+                TweetFields::Id,
+                TweetFields::CreatedAt,
+                TweetFields::PublicMetrics,
+                TweetFields::Attachments
+            ]
+            .into_iter()
+            .collect()
+        );
+        let source = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "text": "hello",
+            "edit_history_tweet_ids": ["1"],
+            "created_at": "2023-01-01T00:00:00.000Z",
+            "public_metrics": { "like_count": 3 }
+        }))
+        .unwrap();
+        let tweet = Tweet::select(source).unwrap();
+        assert_eq!(tweet.id, "1");
+        assert_eq!(tweet.created_at.to_rfc3339(), "2023-01-01T00:00:00+00:00");
+        assert_eq!(tweet.public_metrics.like_count, Some(3));
+        assert!(tweet.attachments.is_none());
+
+        let source = serde_json::from_value(
+            serde_json::json!({ "id": "1", "text": "hello", "edit_history_tweet_ids": ["1"] }),
+        )
+        .unwrap();
+        assert!(Tweet::select(source).is_err());
+    }
+}
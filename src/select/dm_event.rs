@@ -0,0 +1,122 @@
+pub type Source = crate::responses::dm_events::DmEvents;
+pub type Fields = crate::fields::dm_event_fields::DmEventFields;
+
+#[allow(non_camel_case_types)]
+pub struct id;
+
+impl super::Field for id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Id;
+    const NAME: &'static str = "id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.id.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct text;
+
+impl super::Field for text {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::Text;
+    const NAME: &'static str = "text";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.text.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct event_type;
+
+impl super::Field for event_type {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::EventType;
+    const NAME: &'static str = "event_type";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.event_type.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct created_at;
+
+impl super::Field for created_at {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = chrono::DateTime<chrono::Utc>;
+    const FIELD: Self::Fields = Self::Fields::CreatedAt;
+    const NAME: &'static str = "created_at";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.created_at.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct dm_conversation_id;
+
+impl super::Field for dm_conversation_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::DmConversationId;
+    const NAME: &'static str = "dm_conversation_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.dm_conversation_id.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct sender_id;
+
+impl super::Field for sender_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::SenderId;
+    const NAME: &'static str = "sender_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.sender_id.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct referenced_tweets;
+
+impl super::Field for referenced_tweets {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<crate::responses::referenced_tweets::ReferencedTweets>>;
+    const FIELD: Self::Fields = Self::Fields::ReferencedTweets;
+    const NAME: &'static str = "referenced_tweets";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.referenced_tweets.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct attachments;
+
+impl super::Field for attachments {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::attachments::Attachments>;
+    const FIELD: Self::Fields = Self::Fields::Attachments;
+    const NAME: &'static str = "attachments";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.attachments.take())
+    }
+}
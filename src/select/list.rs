@@ -0,0 +1,92 @@
+pub type Source = crate::responses::lists::Lists;
+pub type Fields = crate::fields::list_fields::ListFields;
+
+#[allow(non_camel_case_types)]
+pub struct created_at;
+
+impl super::Field for created_at {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = chrono::DateTime<chrono::Utc>;
+    const FIELD: Self::Fields = Self::Fields::CreatedAt;
+    const NAME: &'static str = "created_at";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.created_at.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct follower_count;
+
+impl super::Field for follower_count {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = i64;
+    const FIELD: Self::Fields = Self::Fields::FollowerCount;
+    const NAME: &'static str = "follower_count";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.follower_count.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct member_count;
+
+impl super::Field for member_count {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = i64;
+    const FIELD: Self::Fields = Self::Fields::MemberCount;
+    const NAME: &'static str = "member_count";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.member_count.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct private;
+
+impl super::Field for private {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = bool;
+    const FIELD: Self::Fields = Self::Fields::Private;
+    const NAME: &'static str = "private";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.private.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct description;
+
+impl super::Field for description {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::Description;
+    const NAME: &'static str = "description";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.description.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct owner_id;
+
+impl super::Field for owner_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::OwnerId;
+    const NAME: &'static str = "owner_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.owner_id.take()
+    }
+}
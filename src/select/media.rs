@@ -0,0 +1,152 @@
+pub type Source = crate::responses::media::Media;
+pub type Fields = crate::fields::media_fields::MediaFields;
+
+#[allow(non_camel_case_types)]
+pub struct duration_ms;
+
+impl super::Field for duration_ms {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<i64>;
+    const FIELD: Self::Fields = Self::Fields::DurationMs;
+    const NAME: &'static str = "duration_ms";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.duration_ms.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct height;
+
+impl super::Field for height {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = i64;
+    const FIELD: Self::Fields = Self::Fields::Height;
+    const NAME: &'static str = "height";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.height.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct media_key;
+
+impl super::Field for media_key {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::MediaKey;
+    const NAME: &'static str = "media_key";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.media_key.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct preview_image_url;
+
+impl super::Field for preview_image_url {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::PreviewImageUrl;
+    const NAME: &'static str = "preview_image_url";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.preview_image_url.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct r#type;
+
+impl super::Field for r#type {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Type;
+    const NAME: &'static str = "type";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.r#type.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct url;
+
+impl super::Field for url {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::Url;
+    const NAME: &'static str = "url";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.url.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct width;
+
+impl super::Field for width {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = i64;
+    const FIELD: Self::Fields = Self::Fields::Width;
+    const NAME: &'static str = "width";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.width.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct public_metrics;
+
+impl super::Field for public_metrics {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::media_public_metrics::MediaPublicMetrics>;
+    const FIELD: Self::Fields = Self::Fields::PublicMetrics;
+    const NAME: &'static str = "public_metrics";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.public_metrics.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct alt_text;
+
+impl super::Field for alt_text {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::AltText;
+    const NAME: &'static str = "alt_text";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.alt_text.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct variants;
+
+impl super::Field for variants {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<crate::responses::variants::Variants>>;
+    const FIELD: Self::Fields = Self::Fields::Variants;
+    const NAME: &'static str = "variants";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.variants.take())
+    }
+}
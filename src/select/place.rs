@@ -0,0 +1,122 @@
+pub type Source = crate::responses::places::Places;
+pub type Fields = crate::fields::place_fields::PlaceFields;
+
+#[allow(non_camel_case_types)]
+pub struct contained_within;
+
+impl super::Field for contained_within {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<String>>;
+    const FIELD: Self::Fields = Self::Fields::ContainedWithin;
+    const NAME: &'static str = "contained_within";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.contained_within.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct country;
+
+impl super::Field for country {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Country;
+    const NAME: &'static str = "country";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.country.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct country_code;
+
+impl super::Field for country_code {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::CountryCode;
+    const NAME: &'static str = "country_code";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.country_code.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct full_name;
+
+impl super::Field for full_name {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::FullName;
+    const NAME: &'static str = "full_name";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.full_name))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct geo;
+
+impl super::Field for geo {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::geo::Geo>;
+    const FIELD: Self::Fields = Self::Fields::Geo;
+    const NAME: &'static str = "geo";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.geo.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct id;
+
+impl super::Field for id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Id;
+    const NAME: &'static str = "id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.id))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct name;
+
+impl super::Field for name {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Name;
+    const NAME: &'static str = "name";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.name.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct place_type;
+
+impl super::Field for place_type {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::PlaceType;
+    const NAME: &'static str = "place_type";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.place_type.take()
+    }
+}
@@ -0,0 +1,77 @@
+pub type Source = crate::responses::polls::Polls;
+pub type Fields = crate::fields::poll_fields::PollFields;
+
+#[allow(non_camel_case_types)]
+pub struct duration_minutes;
+
+impl super::Field for duration_minutes {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = i64;
+    const FIELD: Self::Fields = Self::Fields::DurationMinutes;
+    const NAME: &'static str = "duration_minutes";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.duration_minutes.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct end_datetime;
+
+impl super::Field for end_datetime {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = chrono::DateTime<chrono::Utc>;
+    const FIELD: Self::Fields = Self::Fields::EndDatetime;
+    const NAME: &'static str = "end_datetime";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.end_datetime.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct id;
+
+impl super::Field for id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Id;
+    const NAME: &'static str = "id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.id))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct options;
+
+impl super::Field for options {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Vec<crate::responses::options::Options>;
+    const FIELD: Self::Fields = Self::Fields::Options;
+    const NAME: &'static str = "options";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.options))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct voting_status;
+
+impl super::Field for voting_status {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::VotingStatus;
+    const NAME: &'static str = "voting_status";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.voting_status.take()
+    }
+}
@@ -0,0 +1,257 @@
+pub type Source = crate::responses::spaces::Spaces;
+pub type Fields = crate::fields::space_fields::SpaceFields;
+
+#[allow(non_camel_case_types)]
+pub struct host_ids;
+
+impl super::Field for host_ids {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Vec<String>;
+    const FIELD: Self::Fields = Self::Fields::HostIds;
+    const NAME: &'static str = "host_ids";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.host_ids.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct created_at;
+
+impl super::Field for created_at {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = chrono::DateTime<chrono::Utc>;
+    const FIELD: Self::Fields = Self::Fields::CreatedAt;
+    const NAME: &'static str = "created_at";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.created_at.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct creator_id;
+
+impl super::Field for creator_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::CreatorId;
+    const NAME: &'static str = "creator_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.creator_id.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct id;
+
+impl super::Field for id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Id;
+    const NAME: &'static str = "id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.id))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct lang;
+
+impl super::Field for lang {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::Lang;
+    const NAME: &'static str = "lang";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.lang.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct invited_user_ids;
+
+impl super::Field for invited_user_ids {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<String>>;
+    const FIELD: Self::Fields = Self::Fields::InvitedUserIds;
+    const NAME: &'static str = "invited_user_ids";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.invited_user_ids.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct participant_count;
+
+impl super::Field for participant_count {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<i64>;
+    const FIELD: Self::Fields = Self::Fields::ParticipantCount;
+    const NAME: &'static str = "participant_count";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.participant_count.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct speaker_ids;
+
+impl super::Field for speaker_ids {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<String>>;
+    const FIELD: Self::Fields = Self::Fields::SpeakerIds;
+    const NAME: &'static str = "speaker_ids";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.speaker_ids.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct started_at;
+
+impl super::Field for started_at {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<chrono::DateTime<chrono::Utc>>;
+    const FIELD: Self::Fields = Self::Fields::StartedAt;
+    const NAME: &'static str = "started_at";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.started_at.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct ended_at;
+
+impl super::Field for ended_at {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<chrono::DateTime<chrono::Utc>>;
+    const FIELD: Self::Fields = Self::Fields::EndedAt;
+    const NAME: &'static str = "ended_at";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.ended_at.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct subscriber_count;
+
+impl super::Field for subscriber_count {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<i64>;
+    const FIELD: Self::Fields = Self::Fields::SubscriberCount;
+    const NAME: &'static str = "subscriber_count";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.subscriber_count.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct topic_ids;
+
+impl super::Field for topic_ids {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<String>>;
+    const FIELD: Self::Fields = Self::Fields::TopicIds;
+    const NAME: &'static str = "topic_ids";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.topic_ids.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct state;
+
+impl super::Field for state {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = crate::responses::spaces::State;
+    const FIELD: Self::Fields = Self::Fields::State;
+    const NAME: &'static str = "state";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.state))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct title;
+
+impl super::Field for title {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Title;
+    const NAME: &'static str = "title";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.title.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct updated_at;
+
+impl super::Field for updated_at {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = chrono::DateTime<chrono::Utc>;
+    const FIELD: Self::Fields = Self::Fields::UpdatedAt;
+    const NAME: &'static str = "updated_at";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.updated_at.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct scheduled_start;
+
+impl super::Field for scheduled_start {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<chrono::DateTime<chrono::Utc>>;
+    const FIELD: Self::Fields = Self::Fields::ScheduledStart;
+    const NAME: &'static str = "scheduled_start";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.scheduled_start.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct is_ticketed;
+
+impl super::Field for is_ticketed {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = bool;
+    const FIELD: Self::Fields = Self::Fields::IsTicketed;
+    const NAME: &'static str = "is_ticketed";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.is_ticketed.take()
+    }
+}
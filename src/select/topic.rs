@@ -0,0 +1,32 @@
+pub type Source = crate::responses::topics::Topics;
+pub type Fields = crate::fields::topic_fields::TopicFields;
+
+#[allow(non_camel_case_types)]
+pub struct id;
+
+impl super::Field for id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Id;
+    const NAME: &'static str = "id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.id.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct name;
+
+impl super::Field for name {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Name;
+    const NAME: &'static str = "name";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.name.take()
+    }
+}
@@ -0,0 +1,332 @@
+pub type Source = crate::responses::tweets::Tweets;
+pub type Fields = crate::fields::tweet_fields::TweetFields;
+
+#[allow(non_camel_case_types)]
+pub struct attachments;
+
+impl super::Field for attachments {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::attachments::Attachments>;
+    const FIELD: Self::Fields = Self::Fields::Attachments;
+    const NAME: &'static str = "attachments";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.attachments.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct author_id;
+
+impl super::Field for author_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::AuthorId;
+    const NAME: &'static str = "author_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.author_id.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct context_annotations;
+
+impl super::Field for context_annotations {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<crate::responses::context_annotations::ContextAnnotations>>;
+    const FIELD: Self::Fields = Self::Fields::ContextAnnotations;
+    const NAME: &'static str = "context_annotations";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.context_annotations.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct conversation_id;
+
+impl super::Field for conversation_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::ConversationId;
+    const NAME: &'static str = "conversation_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.conversation_id.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct created_at;
+
+impl super::Field for created_at {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = chrono::DateTime<chrono::Utc>;
+    const FIELD: Self::Fields = Self::Fields::CreatedAt;
+    const NAME: &'static str = "created_at";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.created_at.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct edit_controls;
+
+impl super::Field for edit_controls {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::edit_controls::EditControls>;
+    const FIELD: Self::Fields = Self::Fields::EditControls;
+    const NAME: &'static str = "edit_controls";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.edit_controls.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct entities;
+
+impl super::Field for entities {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::entities::Entities>;
+    const FIELD: Self::Fields = Self::Fields::Entities;
+    const NAME: &'static str = "entities";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.entities.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct geo;
+
+impl super::Field for geo {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::geo::Geo>;
+    const FIELD: Self::Fields = Self::Fields::Geo;
+    const NAME: &'static str = "geo";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.geo.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct id;
+
+impl super::Field for id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Id;
+    const NAME: &'static str = "id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.id))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct in_reply_to_user_id;
+
+impl super::Field for in_reply_to_user_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::InReplyToUserId;
+    const NAME: &'static str = "in_reply_to_user_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.in_reply_to_user_id.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct lang;
+
+impl super::Field for lang {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Lang;
+    const NAME: &'static str = "lang";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.lang.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct non_public_metrics;
+
+impl super::Field for non_public_metrics {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::non_public_metrics::NonPublicMetrics>;
+    const FIELD: Self::Fields = Self::Fields::NonPublicMetrics;
+    const NAME: &'static str = "non_public_metrics";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.non_public_metrics.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct public_metrics;
+
+impl super::Field for public_metrics {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = crate::responses::public_metrics::PublicMetrics;
+    const FIELD: Self::Fields = Self::Fields::PublicMetrics;
+    const NAME: &'static str = "public_metrics";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.public_metrics.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct organic_metrics;
+
+impl super::Field for organic_metrics {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::organic_metrics::OrganicMetrics>;
+    const FIELD: Self::Fields = Self::Fields::OrganicMetrics;
+    const NAME: &'static str = "organic_metrics";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.organic_metrics.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct promoted_metrics;
+
+impl super::Field for promoted_metrics {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::promoted_metrics::PromotedMetrics>;
+    const FIELD: Self::Fields = Self::Fields::PromotedMetrics;
+    const NAME: &'static str = "promoted_metrics";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.promoted_metrics.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct possibly_sensitive;
+
+impl super::Field for possibly_sensitive {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = bool;
+    const FIELD: Self::Fields = Self::Fields::PossiblySensitive;
+    const NAME: &'static str = "possibly_sensitive";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.possibly_sensitive.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct referenced_tweets;
+
+impl super::Field for referenced_tweets {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<crate::responses::referenced_tweets::ReferencedTweets>>;
+    const FIELD: Self::Fields = Self::Fields::ReferencedTweets;
+    const NAME: &'static str = "referenced_tweets";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.referenced_tweets.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct reply_settings;
+
+impl super::Field for reply_settings {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::ReplySettings;
+    const NAME: &'static str = "reply_settings";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.reply_settings.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct source;
+
+impl super::Field for source {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::Source;
+    const NAME: &'static str = "source";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.source.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct text;
+
+impl super::Field for text {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Text;
+    const NAME: &'static str = "text";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.text))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct withheld;
+
+impl super::Field for withheld {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::withheld::Withheld>;
+    const FIELD: Self::Fields = Self::Fields::Withheld;
+    const NAME: &'static str = "withheld";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.withheld.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct note_tweet;
+
+impl super::Field for note_tweet {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::note_tweet::NoteTweet>;
+    const FIELD: Self::Fields = Self::Fields::NoteTweet;
+    const NAME: &'static str = "note_tweet";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.note_tweet.take())
+    }
+}
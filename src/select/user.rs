@@ -0,0 +1,257 @@
+pub type Source = crate::responses::users::Users;
+pub type Fields = crate::fields::user_fields::UserFields;
+
+#[allow(non_camel_case_types)]
+pub struct connection_status;
+
+impl super::Field for connection_status {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<Vec<String>>;
+    const FIELD: Self::Fields = Self::Fields::ConnectionStatus;
+    const NAME: &'static str = "connection_status";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.connection_status.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct created_at;
+
+impl super::Field for created_at {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = chrono::DateTime<chrono::Utc>;
+    const FIELD: Self::Fields = Self::Fields::CreatedAt;
+    const NAME: &'static str = "created_at";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.created_at.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct description;
+
+impl super::Field for description {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Description;
+    const NAME: &'static str = "description";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.description.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct entities;
+
+impl super::Field for entities {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::user_entities::UserEntities>;
+    const FIELD: Self::Fields = Self::Fields::Entities;
+    const NAME: &'static str = "entities";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.entities.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct id;
+
+impl super::Field for id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Id;
+    const NAME: &'static str = "id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.id))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct location;
+
+impl super::Field for location {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::Location;
+    const NAME: &'static str = "location";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.location.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct most_recent_tweet_id;
+
+impl super::Field for most_recent_tweet_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::MostRecentTweetId;
+    const NAME: &'static str = "most_recent_tweet_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.most_recent_tweet_id.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct name;
+
+impl super::Field for name {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Name;
+    const NAME: &'static str = "name";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.name))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct pinned_tweet_id;
+
+impl super::Field for pinned_tweet_id {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::PinnedTweetId;
+    const NAME: &'static str = "pinned_tweet_id";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.pinned_tweet_id.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct profile_image_url;
+
+impl super::Field for profile_image_url {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::ProfileImageUrl;
+    const NAME: &'static str = "profile_image_url";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.profile_image_url.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct protected;
+
+impl super::Field for protected {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = bool;
+    const FIELD: Self::Fields = Self::Fields::Protected;
+    const NAME: &'static str = "protected";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.protected.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct public_metrics;
+
+impl super::Field for public_metrics {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = crate::responses::users::PublicMetrics;
+    const FIELD: Self::Fields = Self::Fields::PublicMetrics;
+    const NAME: &'static str = "public_metrics";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.public_metrics.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct url;
+
+impl super::Field for url {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<String>;
+    const FIELD: Self::Fields = Self::Fields::Url;
+    const NAME: &'static str = "url";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.url.take())
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct username;
+
+impl super::Field for username {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::Username;
+    const NAME: &'static str = "username";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(std::mem::take(&mut it.username))
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct verified;
+
+impl super::Field for verified {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = bool;
+    const FIELD: Self::Fields = Self::Fields::Verified;
+    const NAME: &'static str = "verified";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.verified.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct verified_type;
+
+impl super::Field for verified_type {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = String;
+    const FIELD: Self::Fields = Self::Fields::VerifiedType;
+    const NAME: &'static str = "verified_type";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        it.verified_type.take()
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub struct withheld;
+
+impl super::Field for withheld {
+    type Source = Source;
+    type Fields = Fields;
+    type Value = Option<crate::responses::withheld::Withheld>;
+    const FIELD: Self::Fields = Self::Fields::Withheld;
+    const NAME: &'static str = "withheld";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {
+        Some(it.withheld.take())
+    }
+}
@@ -0,0 +1,176 @@
+use chrono::prelude::*;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+// https://github.com/twitter-archive/snowflake/blob/snowflake-2010/src/main/scala/com/twitter/service/snowflake/IdWorker.scala
+pub const TWEPOCH: i64 = 1288834974657;
+const SEQUENCE_BITS: u64 = 12;
+const WORKER_ID_BITS: u64 = 5;
+const DATACENTER_ID_BITS: u64 = 5;
+const WORKER_ID_SHIFT: u64 = SEQUENCE_BITS;
+const DATACENTER_ID_SHIFT: u64 = SEQUENCE_BITS + WORKER_ID_BITS;
+const TIMESTAMP_SHIFT: u64 = SEQUENCE_BITS + WORKER_ID_BITS + DATACENTER_ID_BITS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Snowflake(u64);
+
+impl Snowflake {
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// The smallest id that can be generated at `datetime`.
+    pub fn from_datetime(datetime: DateTime<Utc>) -> Self {
+        let millis = (datetime.timestamp_millis() - TWEPOCH).max(0) as u64;
+        Self(millis << TIMESTAMP_SHIFT)
+    }
+
+    /// `since_id` that includes everything created at or after `datetime`.
+    pub fn since(datetime: DateTime<Utc>) -> Self {
+        Self(Self::from_datetime(datetime).0.saturating_sub(1))
+    }
+
+    /// `until_id` that includes everything created before `datetime`.
+    pub fn until(datetime: DateTime<Utc>) -> Self {
+        Self::from_datetime(datetime)
+    }
+
+    pub fn timestamp_millis(&self) -> i64 {
+        (self.0 >> TIMESTAMP_SHIFT) as i64 + TWEPOCH
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.timestamp_millis())
+            .single()
+            .unwrap_or_default()
+    }
+
+    pub fn datacenter_id(&self) -> u64 {
+        (self.0 >> DATACENTER_ID_SHIFT) & ((1 << DATACENTER_ID_BITS) - 1)
+    }
+
+    pub fn worker_id(&self) -> u64 {
+        (self.0 >> WORKER_ID_SHIFT) & ((1 << WORKER_ID_BITS) - 1)
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.0 & ((1 << SEQUENCE_BITS) - 1)
+    }
+}
+
+impl std::fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for Snowflake {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Snowflake> for u64 {
+    fn from(value: Snowflake) -> Self {
+        value.0
+    }
+}
+
+impl From<Snowflake> for String {
+    fn from(value: Snowflake) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<&str> for Snowflake {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for Snowflake {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Serialize for Snowflake {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+// Accepts both "123" and 123, v1 payloads carry numeric ids.
+impl<'de> Deserialize<'de> for Snowflake {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = Snowflake;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a snowflake id string or integer")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(Snowflake(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value).map(Snowflake).map_err(E::custom)
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::prelude::*;
+
+    use super::Snowflake;
+
+    #[test]
+    fn it_snowflake() {
+        let id: Snowflake = "1445078208190291968".parse().unwrap();
+        assert_eq!(
+            id.created_at(),
+            Utc.timestamp_millis_opt(1633368467744).unwrap()
+        );
+        assert_eq!(id.datacenter_id(), 11);
+        assert_eq!(id.worker_id(), 18);
+        assert_eq!(id.sequence(), 0);
+        assert_eq!(
+            serde_json::to_string(&id).unwrap(),
+            "\"1445078208190291968\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Snowflake>("1445078208190291968").unwrap(),
+            id
+        );
+
+        let since = Snowflake::since(id.created_at());
+        let until = Snowflake::until(Utc.timestamp_millis_opt(id.timestamp_millis() + 1).unwrap());
+        assert!(since < id && id < until);
+    }
+}
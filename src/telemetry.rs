@@ -0,0 +1,173 @@
+// Every request is wrapped in a `twapi_v2.request` span.
+// With the metrics feature, these are also recorded.
+// - twapi_v2_requests_total(counter): endpoint, method, status
+// - twapi_v2_request_duration_seconds(histogram): endpoint, method
+// - twapi_v2_rate_limit_remaining(gauge): endpoint, method
+
+#[cfg(feature = "reqwest")]
+use reqwest::{
+    header::HeaderMap, Client, Method, Request, RequestBuilder, Response, StatusCode, Url,
+};
+#[cfg(feature = "reqwest")]
+use tracing::{field::Empty, Instrument, Span};
+
+use crate::{api::matches_template, validation::endpoints::ENDPOINTS};
+#[cfg(feature = "reqwest")]
+use crate::{error::Error, runtime::Instant};
+
+// Endpoints written by hand, the ones generated from maker/api are in `validation::endpoints`.
+const HAND_WRITTEN: [(&str, &str); 9] = [
+    ("GET", "/1.1/media/upload.json"),
+    ("GET", "/2/media/upload"),
+    ("POST", "/1.1/media/metadata/create.json"),
+    ("POST", "/1.1/media/subtitles/create.json"),
+    ("POST", "/1.1/media/subtitles/delete.json"),
+    ("POST", "/1.1/media/upload.json"),
+    ("POST", "/2/media/metadata"),
+    ("POST", "/2/media/upload"),
+    ("POST", "/2/oauth2/token"),
+];
+
+/// Endpoint template such as `/2/users/:id/followers` for a request path.
+pub fn endpoint_template(method: &str, path: &str) -> Option<&'static str> {
+    ENDPOINTS
+        .iter()
+        .map(|it| (it.method, it.template))
+        .chain(HAND_WRITTEN)
+        .filter(|(it, template)| *it == method && matches_template(template, path))
+        .max_by_key(|(_, template)| {
+            let segments = template.split('/').filter(|it| !it.is_empty());
+            (
+                segments.clone().count(),
+                segments.filter(|it| !it.starts_with(':')).count(),
+            )
+        })
+        .map(|(_, template)| template)
+}
+
+#[cfg(feature = "reqwest")]
+pub(crate) async fn send(builder: RequestBuilder, attempt: usize) -> Result<Response, Error> {
+    let (client, request) = crate::runtime::build_split(builder);
+    Ok(execute(&client, request?, attempt).await?)
+}
+
+#[cfg(feature = "reqwest")]
+pub(crate) async fn execute(
+    client: &Client,
+    request: Request,
+    attempt: usize,
+) -> Result<Response, reqwest::Error> {
+    let span = RequestSpan::new(request.method(), request.url(), attempt);
+    let result = client.execute(request).instrument(span.span.clone()).await;
+    match &result {
+        Ok(response) => span.finish(Ok((response.status(), response.headers()))),
+        Err(err) => span.finish(Err(err)),
+    }
+    result
+}
+
+#[cfg(feature = "reqwest")]
+pub(crate) struct RequestSpan {
+    pub(crate) span: Span,
+    endpoint: &'static str,
+    method: String,
+    start: Instant,
+}
+
+#[cfg(feature = "reqwest")]
+impl RequestSpan {
+    pub(crate) fn new(method: &Method, url: &Url, attempt: usize) -> Self {
+        let method = method.to_string();
+        let endpoint = endpoint_template(&method, url.path()).unwrap_or("unknown");
+        let span = tracing::info_span!(
+            "twapi_v2.request",
+            endpoint,
+            method = method.as_str(),
+            retry_attempt = attempt,
+            status = Empty,
+            latency_ms = Empty,
+            x_transaction_id = Empty,
+            rate_limit_remaining = Empty,
+        );
+        Self {
+            span,
+            endpoint,
+            method,
+            start: Instant::now(),
+        }
+    }
+
+    pub(crate) fn finish(self, result: Result<(StatusCode, &HeaderMap), &reqwest::Error>) {
+        let Self {
+            span,
+            endpoint,
+            method,
+            start,
+        } = self;
+        let latency = start.elapsed();
+        span.record("latency_ms", latency.as_millis() as u64);
+
+        let status = match result {
+            Ok((status_code, headers)) => {
+                let header = |key: &str| headers.get(key).and_then(|it| it.to_str().ok());
+                span.record("status", status_code.as_u16());
+                if let Some(value) = header("x-transaction-id") {
+                    span.record("x_transaction_id", value);
+                }
+                let remaining =
+                    header("x-rate-limit-remaining").and_then(|it| it.parse::<u64>().ok());
+                if let Some(value) = remaining {
+                    span.record("rate_limit_remaining", value);
+                }
+                #[cfg(feature = "metrics")]
+                if let Some(value) = remaining {
+                    metrics::gauge!("twapi_v2_rate_limit_remaining", "endpoint" => endpoint, "method" => method.clone())
+                        .set(value as f64);
+                }
+                tracing::debug!(parent: &span, status = status_code.as_u16(), "response");
+                status_code.as_u16().to_string()
+            }
+            Err(err) => {
+                tracing::warn!(parent: &span, error = %err, "request failed");
+                "error".to_owned()
+            }
+        };
+
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter!("twapi_v2_requests_total", "endpoint" => endpoint, "method" => method.clone(), "status" => status)
+                .increment(1);
+            metrics::histogram!("twapi_v2_request_duration_seconds", "endpoint" => endpoint, "method" => method)
+                .record(latency.as_secs_f64());
+        }
+        #[cfg(not(feature = "metrics"))]
+        let _ = (status, endpoint, method);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::endpoint_template;
+
+    #[test]
+    fn it_endpoint_template() {
+        assert_eq!(endpoint_template("GET", "/2/users/me"), Some("/2/users/me"));
+        assert_eq!(
+            endpoint_template("GET", "/2/users/123"),
+            Some("/2/users/:id")
+        );
+        assert_eq!(
+            endpoint_template("GET", "/prefix/2/users/123/followers"),
+            Some("/2/users/:id/followers")
+        );
+        assert_eq!(
+            endpoint_template("GET", "/2/users/by/username/abc"),
+            Some("/2/users/by/username/:username")
+        );
+        assert_eq!(
+            endpoint_template("DELETE", "/2/users/1/following/2"),
+            Some("/2/users/:source_user_id/following/:target_user_id")
+        );
+        assert_eq!(endpoint_template("GET", "/2/unknown"), None);
+    }
+}
@@ -0,0 +1,1187 @@
+//! In-process fake of the Twitter API for offline tests.
+//!
+//! Use `FakeTwitter` directly as an `HttpClient` with `execute_with`, or `serve` it on a local port
+//! and point `TwapiOptions::prefix_url` at it to use `execute`.
+//! Fields and expansions parameters are ignored, every known value is returned.
+//! JSON and `application/x-www-form-urlencoded` bodies are accepted.
+//!
+//! Simulated endpoints:
+//! - users: me, lookup by id and username, followers, following
+//! - tweets: create, delete, lookup, user tweets, recent search (`from:` and plain terms)
+//! - likes, blocking, muting and bookmarks of the authenticated user
+//! - lists: create, get, update, delete, members, owned lists
+//! - DMs: send with a participant, dm events
+//! - filtered stream rules
+//!
+//! Other endpoints of the API (spaces, compliance, counts, streams, ...) answer 501 Not Implemented,
+//! unknown paths answer 404. Media upload is not simulated.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use chrono::{SecondsFormat, Utc};
+use http::StatusCode;
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+use crate::{
+    api::TwapiOptions,
+    error::Error,
+    request::{Body, HttpClient, HttpResponse, Method, TwapiRequest},
+    telemetry::endpoint_template,
+};
+
+const DEFAULT_RATE_LIMIT: u64 = 900;
+const RATE_LIMIT_WINDOW_SECS: i64 = 15 * 60;
+
+#[derive(Debug, Clone)]
+struct User {
+    id: String,
+    username: String,
+    name: String,
+    created_at: String,
+}
+
+#[derive(Debug, Clone)]
+struct Tweet {
+    id: String,
+    author_id: String,
+    text: String,
+    created_at: String,
+}
+
+#[derive(Debug, Clone)]
+struct List {
+    id: String,
+    owner_id: String,
+    name: String,
+    description: Option<String>,
+    private: bool,
+    created_at: String,
+}
+
+#[derive(Debug, Clone)]
+struct DmEvent {
+    id: String,
+    dm_conversation_id: String,
+    participants: Vec<String>,
+    sender_id: String,
+    text: String,
+    created_at: String,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    id: String,
+    value: String,
+    tag: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct RateLimit {
+    limit: u64,
+    remaining: u64,
+    reset: i64,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    next_id: u64,
+    me: Option<String>,
+    users: Vec<User>,
+    tweets: Vec<Tweet>,
+    // (source_user_id, target_user_id)
+    follows: HashSet<(String, String)>,
+    // (user_id, tweet_id)
+    likes: HashSet<(String, String)>,
+    // (source_user_id, target_user_id)
+    blocks: HashSet<(String, String)>,
+    // (source_user_id, target_user_id)
+    mutes: HashSet<(String, String)>,
+    // (user_id, tweet_id)
+    bookmarks: HashSet<(String, String)>,
+    lists: Vec<List>,
+    // (list_id, user_id)
+    members: HashSet<(String, String)>,
+    dm_events: Vec<DmEvent>,
+    rules: Vec<Rule>,
+    rate_limits: HashMap<(Method, &'static str), RateLimit>,
+    limits: HashMap<(Method, &'static str), u64>,
+    errors: Vec<(Method, &'static str, StatusCode)>,
+}
+
+type Reply = (StatusCode, Value);
+
+#[derive(Debug, Clone, Copy)]
+enum Relation {
+    Blocking,
+    Muting,
+    Bookmark,
+}
+
+/// Fake Twitter API with an in-memory state.
+#[derive(Debug, Clone, Default)]
+pub struct FakeTwitter {
+    state: Arc<Mutex<State>>,
+}
+
+impl FakeTwitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The first user becomes the authenticated user.
+    pub fn add_user(&self, username: &str, name: &str) -> String {
+        let mut state = self.state.lock().unwrap();
+        let id = state.make_id();
+        state.users.push(User {
+            id: id.clone(),
+            username: username.to_owned(),
+            name: name.to_owned(),
+            created_at: now(),
+        });
+        if state.me.is_none() {
+            state.me = Some(id.clone());
+        }
+        id
+    }
+
+    pub fn add_tweet(&self, author_id: &str, text: &str) -> String {
+        let mut state = self.state.lock().unwrap();
+        state.add_tweet(author_id, text)
+    }
+
+    /// Requests are handled as this user.
+    pub fn login(&self, user_id: &str) {
+        self.state.lock().unwrap().me = Some(user_id.to_owned());
+    }
+
+    /// Rate limit per 15 minutes of an endpoint such as `/2/users/:id`. Default is 900.
+    /// Panics if the endpoint does not exist.
+    pub fn rate_limit(&self, method: Method, template: &str, limit: u64) {
+        let template = known_template(method, template);
+        let mut state = self.state.lock().unwrap();
+        state.limits.insert((method, template), limit);
+        state.rate_limits.remove(&(method, template));
+    }
+
+    /// The next request to the endpoint fails with the status.
+    /// Panics if the endpoint does not exist.
+    pub fn inject_error(&self, method: Method, template: &str, status: StatusCode) {
+        let template = known_template(method, template);
+        self.state
+            .lock()
+            .unwrap()
+            .errors
+            .push((method, template, status));
+    }
+
+    pub fn handle(&self, request: &TwapiRequest) -> HttpResponse {
+        let mut state = self.state.lock().unwrap();
+        state.next_id += 1;
+        let transaction_id = format!("{:016x}", state.next_id);
+        let path = url_path(&request.url);
+        let Some(template) = endpoint_template(&request.method.to_string(), path) else {
+            return response(
+                problem(StatusCode::NOT_FOUND),
+                vec![("x-transaction-id".to_owned(), transaction_id)],
+            );
+        };
+        let mut headers = vec![("x-transaction-id".to_owned(), transaction_id)];
+
+        let authorized = request
+            .headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("authorization"));
+        if !authorized {
+            return response(problem(StatusCode::UNAUTHORIZED), headers);
+        }
+
+        let rate_limit = state.consume(request.method, template);
+        headers.push((
+            "x-rate-limit-limit".to_owned(),
+            rate_limit.limit.to_string(),
+        ));
+        headers.push((
+            "x-rate-limit-remaining".to_owned(),
+            rate_limit.remaining.saturating_sub(1).to_string(),
+        ));
+        headers.push((
+            "x-rate-limit-reset".to_owned(),
+            rate_limit.reset.to_string(),
+        ));
+        if rate_limit.remaining == 0 {
+            return response(problem(StatusCode::TOO_MANY_REQUESTS), headers);
+        }
+
+        if let Some(index) = state
+            .errors
+            .iter()
+            .position(|(method, it, _)| *method == request.method && *it == template)
+        {
+            let (_, _, status) = state.errors.remove(index);
+            return response(problem(status), headers);
+        }
+
+        let params = path_params(template, path);
+        let param = |name: &str| params.get(name).cloned().unwrap_or_default();
+        let query: HashMap<&str, &str> = request
+            .query
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        let body = match &request.body {
+            Some(Body::Json(value)) => value.clone(),
+            Some(Body::Form(pairs)) => pairs
+                .iter()
+                .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+                .collect(),
+            _ => Value::Null,
+        };
+        let max_results = query
+            .get("max_results")
+            .and_then(|it| it.parse::<usize>().ok());
+        let me = state.me.clone().unwrap_or_default();
+
+        let reply = match (request.method, template) {
+            (Method::Get, "/2/users/me") => state.get_user(&me),
+            (Method::Get, "/2/users/:id") => state.get_user(&param("id")),
+            (Method::Get, "/2/users") => state.get_users(split(query.get("ids")), "id"),
+            (Method::Get, "/2/users/by/username/:username") => {
+                let id = state.user_id_by_username(&param("username"));
+                match id {
+                    Some(id) => state.get_user(&id),
+                    None => not_found("user", "username", &param("username")),
+                }
+            }
+            (Method::Get, "/2/users/by") => {
+                state.get_users(split(query.get("usernames")), "username")
+            }
+            (Method::Post, "/2/tweets") => {
+                let text = body["text"].as_str().unwrap_or_default();
+                let id = state.add_tweet(&me, text);
+                ok(json!({"data": {"id": id, "text": text, "edit_history_tweet_ids": [id]}}))
+            }
+            (Method::Get, "/2/tweets/:id") => state.get_tweet(&param("id")),
+            (Method::Get, "/2/tweets") => state.get_tweets(split(query.get("ids"))),
+            (Method::Delete, "/2/tweets/:id") => state.delete_tweet(&me, &param("id")),
+            (Method::Get, "/2/users/:id/tweets") => {
+                let id = param("id");
+                let tweets = state.tweets_where(|it| it.author_id == id);
+                state.tweet_list(tweets, max_results)
+            }
+            (Method::Get, "/2/tweets/search/recent") => {
+                let query = query.get("query").copied().unwrap_or_default();
+                let tweets = state.search(query);
+                state.tweet_list(tweets, max_results)
+            }
+            (Method::Post, "/2/users/:id/following") => {
+                let target = body["target_user_id"].as_str().unwrap_or_default();
+                state.follow(&me, &param("id"), target)
+            }
+            (Method::Delete, "/2/users/:source_user_id/following/:target_user_id") => {
+                state.unfollow(&me, &param("source_user_id"), &param("target_user_id"))
+            }
+            (Method::Get, "/2/users/:id/followers") => {
+                let id = param("id");
+                let ids = state.follow_ids(|(source, target)| (target == &id).then_some(source));
+                state.user_list(ids, max_results)
+            }
+            (Method::Get, "/2/users/:id/following") => {
+                let id = param("id");
+                let ids = state.follow_ids(|(source, target)| (source == &id).then_some(target));
+                state.user_list(ids, max_results)
+            }
+            (Method::Post, "/2/users/:id/likes") => {
+                let tweet_id = body["tweet_id"].as_str().unwrap_or_default();
+                state.like(&me, &param("id"), tweet_id)
+            }
+            (Method::Delete, "/2/users/:id/likes/:tweet_id") => {
+                state.unlike(&me, &param("id"), &param("tweet_id"))
+            }
+            (Method::Get, "/2/users/:id/liked_tweets") => {
+                let id = param("id");
+                let tweets =
+                    state.tweets_where(|it| state.likes.contains(&(id.clone(), it.id.clone())));
+                state.tweet_list(tweets, max_results)
+            }
+            (Method::Get, "/2/tweets/:id/liking_users") => {
+                let id = param("id");
+                let ids: Vec<String> = state
+                    .likes
+                    .iter()
+                    .filter(|(_, tweet_id)| tweet_id == &id)
+                    .map(|(user_id, _)| user_id.clone())
+                    .collect();
+                state.user_list(ids, max_results)
+            }
+            (Method::Post, "/2/users/:id/blocking") => {
+                let target = body["target_user_id"].as_str().unwrap_or_default();
+                state.relate(Relation::Blocking, &me, &param("id"), target, true)
+            }
+            (Method::Delete, "/2/users/:source_user_id/blocking/:target_user_id") => {
+                let (id, target) = (param("source_user_id"), param("target_user_id"));
+                state.relate(Relation::Blocking, &me, &id, &target, false)
+            }
+            (Method::Get, "/2/users/:id/blocking") => {
+                let ids = state.related(Relation::Blocking, &param("id"));
+                state.user_list(ids, max_results)
+            }
+            (Method::Post, "/2/users/:id/muting") => {
+                let target = body["target_user_id"].as_str().unwrap_or_default();
+                state.relate(Relation::Muting, &me, &param("id"), target, true)
+            }
+            (Method::Delete, "/2/users/:source_user_id/muting/:target_user_id") => {
+                let (id, target) = (param("source_user_id"), param("target_user_id"));
+                state.relate(Relation::Muting, &me, &id, &target, false)
+            }
+            (Method::Get, "/2/users/:id/muting") => {
+                let ids = state.related(Relation::Muting, &param("id"));
+                state.user_list(ids, max_results)
+            }
+            (Method::Post, "/2/users/:id/bookmarks") => {
+                let tweet_id = body["tweet_id"].as_str().unwrap_or_default();
+                state.relate(Relation::Bookmark, &me, &param("id"), tweet_id, true)
+            }
+            (Method::Delete, "/2/users/:id/bookmarks/:tweet_id") => {
+                let (id, tweet_id) = (param("id"), param("tweet_id"));
+                state.relate(Relation::Bookmark, &me, &id, &tweet_id, false)
+            }
+            (Method::Get, "/2/users/:id/bookmarks") => {
+                let ids = state.related(Relation::Bookmark, &param("id"));
+                let tweets = state.tweets_where(|it| ids.contains(&it.id));
+                state.tweet_list(tweets, max_results)
+            }
+            (Method::Post, "/2/lists") => state.create_list(&me, &body),
+            (Method::Get, "/2/lists/:id") => state.get_list(&param("id")),
+            (Method::Put, "/2/lists/:id") => state.update_list(&me, &param("id"), &body),
+            (Method::Delete, "/2/lists/:id") => state.delete_list(&me, &param("id")),
+            (Method::Post, "/2/lists/:id/members") => {
+                let user_id = body["user_id"].as_str().unwrap_or_default();
+                state.add_member(&me, &param("id"), user_id)
+            }
+            (Method::Delete, "/2/lists/:id/members/:user_id") => {
+                state.remove_member(&me, &param("id"), &param("user_id"))
+            }
+            (Method::Get, "/2/lists/:id/members") => {
+                let id = param("id");
+                let ids: Vec<String> = state
+                    .members
+                    .iter()
+                    .filter(|(list_id, _)| list_id == &id)
+                    .map(|(_, user_id)| user_id.clone())
+                    .collect();
+                state.user_list(ids, max_results)
+            }
+            (Method::Get, "/2/users/:id/owned_lists") => {
+                let id = param("id");
+                let lists: Vec<Value> = state
+                    .lists
+                    .iter()
+                    .filter(|it| it.owner_id == id)
+                    .map(|it| state.list_value(it))
+                    .collect();
+                ok(list_body(lists, max_results))
+            }
+            (Method::Post, "/2/dm_conversations/with/:participant_id/messages") => {
+                let text = body["text"].as_str().unwrap_or_default();
+                state.send_dm(&me, &param("participant_id"), text)
+            }
+            (Method::Get, "/2/dm_events") => {
+                let events = state.dm_events_where(|it| it.participants.contains(&me));
+                ok(list_body(events, max_results))
+            }
+            (Method::Get, "/2/dm_conversations/with/:participant_id/dm_events") => {
+                let conversation_id = conversation_id(&me, &param("participant_id"));
+                let events = state.dm_events_where(|it| it.dm_conversation_id == conversation_id);
+                ok(list_body(events, max_results))
+            }
+            (Method::Get, "/2/tweets/search/stream/rules") => state.get_rules(),
+            (Method::Post, "/2/tweets/search/stream/rules") => state.update_rules(&body),
+            _ => not_implemented(request.method, template),
+        };
+        response(reply, headers)
+    }
+
+    /// Listen on a local port. The server stops when `FakeServer` is dropped.
+    pub async fn serve(&self) -> std::io::Result<FakeServer> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let fake = self.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let fake = fake.clone();
+                tokio::spawn(async move {
+                    let _ = fake.serve_connection(stream).await;
+                });
+            }
+        });
+        Ok(FakeServer { url, handle })
+    }
+
+    async fn serve_connection(&self, stream: TcpStream) -> std::io::Result<()> {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).await?;
+        let mut parts = line.split_whitespace();
+        let method = match parts.next() {
+            Some("GET") => Method::Get,
+            Some("POST") => Method::Post,
+            Some("PUT") => Method::Put,
+            Some("DELETE") => Method::Delete,
+            _ => return Ok(()),
+        };
+        let target = parts.next().unwrap_or("/").to_owned();
+
+        let mut headers = vec![];
+        let mut content_length = 0;
+        let mut form = false;
+        loop {
+            line.clear();
+            reader.read_line(&mut line).await?;
+            let Some((name, value)) = line.trim_end().split_once(':') else {
+                break;
+            };
+            let value = value.trim().to_owned();
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().unwrap_or(0);
+            } else if name.eq_ignore_ascii_case("content-type") {
+                form = value.starts_with("application/x-www-form-urlencoded");
+            }
+            headers.push((name.to_ascii_lowercase(), value));
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).await?;
+
+        let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+        let mut request = TwapiRequest::new(method, path);
+        request.query = decode_pairs(query);
+        request.body = if form {
+            Some(Body::Form(decode_pairs(&String::from_utf8_lossy(&body))))
+        } else {
+            serde_json::from_slice(&body).ok().map(Body::Json)
+        };
+        request.headers = headers;
+
+        let response = self.handle(&request);
+        let reason = StatusCode::from_u16(response.status)
+            .ok()
+            .and_then(|it| it.canonical_reason())
+            .unwrap_or_default();
+        let mut head = format!("HTTP/1.1 {} {}\r\n", response.status, reason);
+        for (name, value) in response.headers.iter() {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        head.push_str(&format!(
+            "content-length: {}\r\nconnection: close\r\n\r\n",
+            response.body.len()
+        ));
+        let mut stream = reader.into_inner();
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(&response.body).await?;
+        stream.shutdown().await
+    }
+}
+
+impl HttpClient for FakeTwitter {
+    async fn execute(&self, request: TwapiRequest) -> Result<HttpResponse, Error> {
+        Ok(self.handle(&request))
+    }
+}
+
+pub struct FakeServer {
+    url: String,
+    handle: JoinHandle<()>,
+}
+
+impl FakeServer {
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Options pointing the api prefix url at this server.
+    pub fn twapi_options(&self) -> TwapiOptions {
+        TwapiOptions::new().prefix_url(&self.url)
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+impl State {
+    fn make_id(&mut self) -> String {
+        self.next_id += 1;
+        self.next_id.to_string()
+    }
+
+    fn consume(&mut self, method: Method, template: &'static str) -> RateLimit {
+        let limit = self
+            .limits
+            .get(&(method, template))
+            .copied()
+            .unwrap_or(DEFAULT_RATE_LIMIT);
+        let now = Utc::now().timestamp();
+        let rate_limit = self
+            .rate_limits
+            .entry((method, template))
+            .or_insert(RateLimit {
+                limit,
+                remaining: limit,
+                reset: now + RATE_LIMIT_WINDOW_SECS,
+            });
+        if rate_limit.reset <= now {
+            rate_limit.remaining = limit;
+            rate_limit.reset = now + RATE_LIMIT_WINDOW_SECS;
+        }
+        let result = rate_limit.clone();
+        rate_limit.remaining = rate_limit.remaining.saturating_sub(1);
+        result
+    }
+
+    fn user(&self, id: &str) -> Option<&User> {
+        self.users.iter().find(|it| it.id == id)
+    }
+
+    fn user_id_by_username(&self, username: &str) -> Option<String> {
+        self.users
+            .iter()
+            .find(|it| it.username.eq_ignore_ascii_case(username))
+            .map(|it| it.id.clone())
+    }
+
+    fn user_value(&self, user: &User) -> Value {
+        let count_follows =
+            |f: &dyn Fn(&(String, String)) -> bool| self.follows.iter().filter(|it| f(it)).count();
+        json!({
+            "id": user.id,
+            "name": user.name,
+            "username": user.username,
+            "created_at": user.created_at,
+            "protected": false,
+            "public_metrics": {
+                "followers_count": count_follows(&|(_, target)| target == &user.id),
+                "following_count": count_follows(&|(source, _)| source == &user.id),
+                "tweet_count": self.tweets.iter().filter(|it| it.author_id == user.id).count(),
+                "listed_count": self.members.iter().filter(|(_, it)| it == &user.id).count(),
+                "like_count": self.likes.iter().filter(|(it, _)| it == &user.id).count(),
+            },
+        })
+    }
+
+    fn get_user(&self, id: &str) -> Reply {
+        match self.user(id) {
+            Some(user) => ok(json!({ "data": self.user_value(user) })),
+            None => not_found("user", "id", id),
+        }
+    }
+
+    fn get_users(&self, keys: Vec<String>, parameter: &str) -> Reply {
+        let mut data = vec![];
+        let mut errors = vec![];
+        for key in keys {
+            let user = if parameter == "id" {
+                self.user(&key)
+            } else {
+                self.user_id_by_username(&key)
+                    .and_then(|id| self.users.iter().find(|it| it.id == id))
+            };
+            match user {
+                Some(user) => data.push(self.user_value(user)),
+                None => errors.push(not_found_error("user", parameter, &key)),
+            }
+        }
+        ok(with_errors(data, errors))
+    }
+
+    fn user_list(&self, ids: Vec<String>, max_results: Option<usize>) -> Reply {
+        let users = ids
+            .iter()
+            .filter_map(|id| self.user(id))
+            .map(|it| self.user_value(it))
+            .collect();
+        ok(list_body(users, max_results))
+    }
+
+    fn follow_ids(&self, f: impl Fn(&(String, String)) -> Option<&String>) -> Vec<String> {
+        let mut ids: Vec<String> = self.follows.iter().filter_map(f).cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    fn add_tweet(&mut self, author_id: &str, text: &str) -> String {
+        let id = self.make_id();
+        self.tweets.push(Tweet {
+            id: id.clone(),
+            author_id: author_id.to_owned(),
+            text: text.to_owned(),
+            created_at: now(),
+        });
+        id
+    }
+
+    fn tweet(&self, id: &str) -> Option<&Tweet> {
+        self.tweets.iter().find(|it| it.id == id)
+    }
+
+    fn tweet_value(&self, tweet: &Tweet) -> Value {
+        json!({
+            "id": tweet.id,
+            "text": tweet.text,
+            "author_id": tweet.author_id,
+            "created_at": tweet.created_at,
+            "edit_history_tweet_ids": [tweet.id],
+            "public_metrics": {
+                "like_count": self.likes.iter().filter(|(_, it)| it == &tweet.id).count(),
+                "retweet_count": 0,
+                "reply_count": 0,
+                "quote_count": 0,
+            },
+        })
+    }
+
+    fn get_tweet(&self, id: &str) -> Reply {
+        match self.tweet(id) {
+            Some(tweet) => ok(json!({ "data": self.tweet_value(tweet) })),
+            None => not_found("tweet", "id", id),
+        }
+    }
+
+    fn get_tweets(&self, ids: Vec<String>) -> Reply {
+        let mut data = vec![];
+        let mut errors = vec![];
+        for id in ids {
+            match self.tweet(&id) {
+                Some(tweet) => data.push(self.tweet_value(tweet)),
+                None => errors.push(not_found_error("tweet", "ids", &id)),
+            }
+        }
+        ok(with_errors(data, errors))
+    }
+
+    fn delete_tweet(&mut self, me: &str, id: &str) -> Reply {
+        match self.tweet(id) {
+            Some(tweet) if tweet.author_id != me => problem(StatusCode::FORBIDDEN),
+            Some(_) => {
+                self.tweets.retain(|it| it.id != id);
+                self.likes.retain(|(_, it)| it != id);
+                ok(json!({"data": {"deleted": true}}))
+            }
+            None => ok(json!({"data": {"deleted": false}})),
+        }
+    }
+
+    /// Newest first.
+    fn tweets_where(&self, f: impl Fn(&Tweet) -> bool) -> Vec<Tweet> {
+        let mut tweets: Vec<Tweet> = self.tweets.iter().filter(|it| f(it)).cloned().collect();
+        tweets.sort_by_key(|it| std::cmp::Reverse(it.id.parse::<u64>().unwrap_or_default()));
+        tweets
+    }
+
+    /// Every term must match, `from:username` matches the author.
+    fn search(&self, query: &str) -> Vec<Tweet> {
+        let terms: Vec<String> = query
+            .split_whitespace()
+            .map(|it| it.to_lowercase())
+            .collect();
+        self.tweets_where(|tweet| {
+            terms.iter().all(|term| match term.strip_prefix("from:") {
+                Some(username) => self
+                    .user(&tweet.author_id)
+                    .map(|it| it.username.to_lowercase() == username)
+                    .unwrap_or(false),
+                None => tweet.text.to_lowercase().contains(term),
+            })
+        })
+    }
+
+    fn tweet_list(&self, tweets: Vec<Tweet>, max_results: Option<usize>) -> Reply {
+        let data: Vec<Value> = tweets.iter().map(|it| self.tweet_value(it)).collect();
+        let mut body = list_body(data, max_results);
+        if let Some(data) = body["data"].as_array() {
+            let newest_id = data.first().map(|it| it["id"].clone());
+            let oldest_id = data.last().map(|it| it["id"].clone());
+            body["meta"]["newest_id"] = newest_id.unwrap_or_default();
+            body["meta"]["oldest_id"] = oldest_id.unwrap_or_default();
+        }
+        ok(body)
+    }
+
+    fn follow(&mut self, me: &str, id: &str, target: &str) -> Reply {
+        if me != id {
+            return problem(StatusCode::FORBIDDEN);
+        }
+        if self.user(target).is_none() {
+            return not_found("user", "target_user_id", target);
+        }
+        self.follows.insert((id.to_owned(), target.to_owned()));
+        ok(json!({"data": {"following": true, "pending_follow": false}}))
+    }
+
+    fn unfollow(&mut self, me: &str, id: &str, target: &str) -> Reply {
+        if me != id {
+            return problem(StatusCode::FORBIDDEN);
+        }
+        self.follows.remove(&(id.to_owned(), target.to_owned()));
+        ok(json!({"data": {"following": false}}))
+    }
+
+    fn like(&mut self, me: &str, id: &str, tweet_id: &str) -> Reply {
+        if me != id {
+            return problem(StatusCode::FORBIDDEN);
+        }
+        if self.tweet(tweet_id).is_none() {
+            return not_found("tweet", "tweet_id", tweet_id);
+        }
+        self.likes.insert((id.to_owned(), tweet_id.to_owned()));
+        ok(json!({"data": {"liked": true}}))
+    }
+
+    fn unlike(&mut self, me: &str, id: &str, tweet_id: &str) -> Reply {
+        if me != id {
+            return problem(StatusCode::FORBIDDEN);
+        }
+        self.likes.remove(&(id.to_owned(), tweet_id.to_owned()));
+        ok(json!({"data": {"liked": false}}))
+    }
+
+    fn relation(&mut self, relation: Relation) -> &mut HashSet<(String, String)> {
+        match relation {
+            Relation::Blocking => &mut self.blocks,
+            Relation::Muting => &mut self.mutes,
+            Relation::Bookmark => &mut self.bookmarks,
+        }
+    }
+
+    /// Blocks, mutes or bookmarks `target` as `id`, or undoes it.
+    fn relate(&mut self, relation: Relation, me: &str, id: &str, target: &str, on: bool) -> Reply {
+        if me != id {
+            return problem(StatusCode::FORBIDDEN);
+        }
+        let (key, resource_type, parameter, exists) = match relation {
+            Relation::Blocking => (
+                "blocking",
+                "user",
+                "target_user_id",
+                self.user(target).is_some(),
+            ),
+            Relation::Muting => (
+                "muting",
+                "user",
+                "target_user_id",
+                self.user(target).is_some(),
+            ),
+            Relation::Bookmark => (
+                "bookmarked",
+                "tweet",
+                "tweet_id",
+                self.tweet(target).is_some(),
+            ),
+        };
+        if on {
+            if !exists {
+                return not_found(resource_type, parameter, target);
+            }
+            self.relation(relation)
+                .insert((id.to_owned(), target.to_owned()));
+        } else {
+            self.relation(relation)
+                .remove(&(id.to_owned(), target.to_owned()));
+        }
+        ok(json!({"data": { key: on }}))
+    }
+
+    fn related(&mut self, relation: Relation, id: &str) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .relation(relation)
+            .iter()
+            .filter(|(it, _)| it == id)
+            .map(|(_, target)| target.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    fn list(&self, id: &str) -> Option<&List> {
+        self.lists.iter().find(|it| it.id == id)
+    }
+
+    fn list_value(&self, list: &List) -> Value {
+        json!({
+            "id": list.id,
+            "name": list.name,
+            "description": list.description,
+            "private": list.private,
+            "owner_id": list.owner_id,
+            "created_at": list.created_at,
+            "follower_count": 0,
+            "member_count": self.members.iter().filter(|(it, _)| it == &list.id).count(),
+        })
+    }
+
+    fn create_list(&mut self, me: &str, body: &Value) -> Reply {
+        let id = self.make_id();
+        let name = body["name"].as_str().unwrap_or_default().to_owned();
+        self.lists.push(List {
+            id: id.clone(),
+            owner_id: me.to_owned(),
+            name: name.clone(),
+            description: body["description"].as_str().map(|it| it.to_owned()),
+            private: body["private"].as_bool().unwrap_or(false),
+            created_at: now(),
+        });
+        ok(json!({"data": {"id": id, "name": name}}))
+    }
+
+    fn get_list(&self, id: &str) -> Reply {
+        match self.list(id) {
+            Some(list) => ok(json!({ "data": self.list_value(list) })),
+            None => not_found("list", "id", id),
+        }
+    }
+
+    fn owned_list(&mut self, me: &str, id: &str) -> Result<&mut List, Reply> {
+        match self.lists.iter_mut().find(|it| it.id == id) {
+            Some(list) if list.owner_id == me => Ok(list),
+            Some(_) => Err(problem(StatusCode::FORBIDDEN)),
+            None => Err(not_found("list", "id", id)),
+        }
+    }
+
+    fn update_list(&mut self, me: &str, id: &str, body: &Value) -> Reply {
+        let list = match self.owned_list(me, id) {
+            Ok(list) => list,
+            Err(reply) => return reply,
+        };
+        if let Some(name) = body["name"].as_str() {
+            list.name = name.to_owned();
+        }
+        if let Some(description) = body["description"].as_str() {
+            list.description = Some(description.to_owned());
+        }
+        if let Some(private) = body["private"].as_bool() {
+            list.private = private;
+        }
+        ok(json!({"data": {"updated": true}}))
+    }
+
+    fn delete_list(&mut self, me: &str, id: &str) -> Reply {
+        if let Err(reply) = self.owned_list(me, id) {
+            return reply;
+        }
+        self.lists.retain(|it| it.id != id);
+        self.members.retain(|(it, _)| it != id);
+        ok(json!({"data": {"deleted": true}}))
+    }
+
+    fn add_member(&mut self, me: &str, id: &str, user_id: &str) -> Reply {
+        if let Err(reply) = self.owned_list(me, id) {
+            return reply;
+        }
+        if self.user(user_id).is_none() {
+            return not_found("user", "user_id", user_id);
+        }
+        self.members.insert((id.to_owned(), user_id.to_owned()));
+        ok(json!({"data": {"is_member": true}}))
+    }
+
+    fn remove_member(&mut self, me: &str, id: &str, user_id: &str) -> Reply {
+        if let Err(reply) = self.owned_list(me, id) {
+            return reply;
+        }
+        self.members.remove(&(id.to_owned(), user_id.to_owned()));
+        ok(json!({"data": {"is_member": false}}))
+    }
+
+    fn send_dm(&mut self, me: &str, participant_id: &str, text: &str) -> Reply {
+        if self.user(participant_id).is_none() {
+            return not_found("user", "participant_id", participant_id);
+        }
+        let id = self.make_id();
+        let dm_conversation_id = conversation_id(me, participant_id);
+        self.dm_events.push(DmEvent {
+            id: id.clone(),
+            dm_conversation_id: dm_conversation_id.clone(),
+            participants: vec![me.to_owned(), participant_id.to_owned()],
+            sender_id: me.to_owned(),
+            text: text.to_owned(),
+            created_at: now(),
+        });
+        ok(json!({"data": {"dm_conversation_id": dm_conversation_id, "dm_event_id": id}}))
+    }
+
+    /// Newest first.
+    fn dm_events_where(&self, f: impl Fn(&DmEvent) -> bool) -> Vec<Value> {
+        self.dm_events
+            .iter()
+            .rev()
+            .filter(|it| f(it))
+            .map(|it| {
+                json!({
+                    "id": it.id,
+                    "event_type": "MessageCreate",
+                    "text": it.text,
+                    "sender_id": it.sender_id,
+                    "dm_conversation_id": it.dm_conversation_id,
+                    "created_at": it.created_at,
+                })
+            })
+            .collect()
+    }
+
+    fn rule_value(rule: &Rule) -> Value {
+        json!({"id": rule.id, "value": rule.value, "tag": rule.tag})
+    }
+
+    fn get_rules(&self) -> Reply {
+        let mut body = json!({"meta": {"sent": now(), "result_count": self.rules.len()}});
+        if !self.rules.is_empty() {
+            body["data"] = self.rules.iter().map(Self::rule_value).collect();
+        }
+        ok(body)
+    }
+
+    fn update_rules(&mut self, body: &Value) -> Reply {
+        let mut summary = json!({});
+        let mut data = vec![];
+        if let Some(add) = body["add"].as_array() {
+            for item in add {
+                let id = self.make_id();
+                let rule = Rule {
+                    id,
+                    value: item["value"].as_str().unwrap_or_default().to_owned(),
+                    tag: item["tag"].as_str().map(|it| it.to_owned()),
+                };
+                data.push(Self::rule_value(&rule));
+                self.rules.push(rule);
+            }
+            summary["created"] = json!(add.len());
+            summary["not_created"] = json!(0);
+            summary["valid"] = json!(add.len());
+            summary["invalid"] = json!(0);
+        }
+        if let Some(ids) = body["delete"]["ids"].as_array() {
+            let before = self.rules.len();
+            self.rules
+                .retain(|rule| !ids.iter().any(|id| id.as_str() == Some(rule.id.as_str())));
+            let deleted = before - self.rules.len();
+            summary["deleted"] = json!(deleted);
+            summary["not_deleted"] = json!(ids.len() - deleted);
+        }
+        let mut body = json!({"meta": {"sent": now(), "summary": summary}});
+        if !data.is_empty() {
+            body["data"] = Value::Array(data);
+        }
+        ok(body)
+    }
+}
+
+fn now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn ok(value: Value) -> Reply {
+    (StatusCode::OK, value)
+}
+
+fn problem(status: StatusCode) -> Reply {
+    let title = status.canonical_reason().unwrap_or_default();
+    (
+        status,
+        json!({"title": title, "detail": title, "type": "about:blank", "status": status.as_u16()}),
+    )
+}
+
+fn not_implemented(method: Method, template: &str) -> Reply {
+    let (status, mut body) = problem(StatusCode::NOT_IMPLEMENTED);
+    body["detail"] = json!(format!(
+        "{} {} is not supported by FakeTwitter",
+        method, template
+    ));
+    (status, body)
+}
+
+fn not_found_error(resource_type: &str, parameter: &str, value: &str) -> Value {
+    json!({
+        "value": value,
+        "detail": format!("Could not find {} with {}: [{}].", resource_type, parameter, value),
+        "title": "Not Found Error",
+        "resource_type": resource_type,
+        "parameter": parameter,
+        "resource_id": value,
+        "type": "https://api.twitter.com/2/problems/resource-not-found",
+    })
+}
+
+/// Twitter answers 200 with errors for missing resources.
+fn not_found(resource_type: &str, parameter: &str, value: &str) -> Reply {
+    ok(json!({ "errors": [not_found_error(resource_type, parameter, value)] }))
+}
+
+fn with_errors(data: Vec<Value>, errors: Vec<Value>) -> Value {
+    let mut body = json!({});
+    if !data.is_empty() {
+        body["data"] = Value::Array(data);
+    }
+    if !errors.is_empty() {
+        body["errors"] = Value::Array(errors);
+    }
+    body
+}
+
+fn list_body(mut data: Vec<Value>, max_results: Option<usize>) -> Value {
+    if let Some(max_results) = max_results {
+        data.truncate(max_results);
+    }
+    let mut body = json!({"meta": {"result_count": data.len()}});
+    if !data.is_empty() {
+        body["data"] = Value::Array(data);
+    }
+    body
+}
+
+fn response((status, body): Reply, mut headers: Vec<(String, String)>) -> HttpResponse {
+    headers.push(("content-type".to_owned(), "application/json".to_owned()));
+    HttpResponse {
+        status: status.as_u16(),
+        headers,
+        body: body.to_string().into_bytes(),
+    }
+}
+
+fn conversation_id(user_id: &str, participant_id: &str) -> String {
+    let mut ids = [user_id, participant_id];
+    ids.sort_by_key(|it| it.parse::<u64>().unwrap_or_default());
+    ids.join("-")
+}
+
+fn split(value: Option<&&str>) -> Vec<String> {
+    value
+        .map(|it| it.split(',').map(|it| it.to_owned()).collect())
+        .unwrap_or_default()
+}
+
+fn url_path(url: &str) -> &str {
+    let path = match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map(|index| &rest[index..]).unwrap_or("/"),
+        None => url,
+    };
+    path.split('?').next().unwrap_or(path)
+}
+
+fn path_params(template: &str, path: &str) -> HashMap<String, String> {
+    let template: Vec<&str> = template.split('/').filter(|it| !it.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').filter(|it| !it.is_empty()).collect();
+    template
+        .iter()
+        .zip(path[path.len() - template.len()..].iter())
+        .filter_map(|(t, p)| Some((t.strip_prefix(':')?.to_owned(), decode(p))))
+        .collect()
+}
+
+// A typo in a test setup would otherwise never take effect.
+fn known_template(method: Method, template: &str) -> &'static str {
+    endpoint_template(&method.to_string(), template)
+        .unwrap_or_else(|| panic!("unknown endpoint {} {}", method, template))
+}
+
+fn decode_pairs(src: &str) -> Vec<(String, String)> {
+    src.split('&')
+        .filter(|it| !it.is_empty())
+        .map(|it| {
+            let (key, value) = it.split_once('=').unwrap_or((it, ""));
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+fn decode(src: &str) -> String {
+    let bytes = src.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => result.push(b' '),
+            b'%' if index + 2 < bytes.len() => {
+                // hex digits are ASCII, a multibyte char after % is kept as is
+                let hex = |byte: u8| (byte as char).to_digit(16);
+                match (hex(bytes[index + 1]), hex(bytes[index + 2])) {
+                    (Some(high), Some(low)) => {
+                        result.push((high * 16 + low) as u8);
+                        index += 2;
+                    }
+                    _ => result.push(b'%'),
+                }
+            }
+            byte => result.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8_lossy(&result).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_path() {
+        assert_eq!(
+            url_path("https://api.twitter.com/2/users/1?a=b"),
+            "/2/users/1"
+        );
+        assert_eq!(url_path("/2/users/me"), "/2/users/me");
+        let params = path_params(
+            "/2/users/:source_user_id/following/:target_user_id",
+            "/prefix/2/users/1/following/2",
+        );
+        assert_eq!(params["source_user_id"], "1");
+        assert_eq!(params["target_user_id"], "2");
+        assert_eq!(decode("a+b%20%E6%9D%B1"), "a b 東");
+        assert_eq!(decode("100%"), "100%");
+        assert_eq!(decode("%a東"), "%a東");
+        assert_eq!(decode("%zz"), "%zz");
+    }
+
+    #[test]
+    fn it_form_and_not_implemented() {
+        let fake = FakeTwitter::new();
+        let me = fake.add_user("me", "Me");
+        let mut request = TwapiRequest::new(Method::Post, "/2/tweets");
+        request.headers = vec![("Authorization".to_owned(), "Bearer token".to_owned())];
+        request.body = Some(Body::Form(decode_pairs("text=hello+form")));
+        let response = fake.handle(&request);
+        assert_eq!(response.status, 200);
+        let tweets = fake
+            .state
+            .lock()
+            .unwrap()
+            .tweets_where(|it| it.author_id == me);
+        assert_eq!(tweets[0].text, "hello form");
+
+        request.method = Method::Get;
+        request.url = "/2/spaces/1".to_owned();
+        request.body = None;
+        let response = fake.handle(&request);
+        assert_eq!(response.status, 501);
+        let body: Value = serde_json::from_slice(&response.body).unwrap();
+        assert_eq!(
+            body["detail"],
+            "GET /2/spaces/:id is not supported by FakeTwitter"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown endpoint")]
+    fn it_inject_error_unknown() {
+        FakeTwitter::new().inject_error(Method::Get, "/2/unknown", StatusCode::BAD_REQUEST);
+    }
+}
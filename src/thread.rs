@@ -0,0 +1,396 @@
+use thiserror::Error;
+
+#[cfg(feature = "reqwest")]
+use crate::api::{
+    post_2_tweets::{self, Body, Media, Reply},
+    Authentication,
+};
+use crate::{
+    api::TwapiOptions,
+    error::Error,
+    twitter_text::{normalize, units, weighted_length, Configuration},
+};
+
+const MAX_WEIGHTED_LENGTH: usize = 280;
+const SENTENCE_TERMINATORS: [char; 6] = ['.', '!', '?', '。', '！', '？'];
+
+#[derive(Debug, Clone, Default)]
+pub struct ThreadPart {
+    pub text: String,
+    pub media_ids: Vec<String>,
+}
+
+#[derive(Error, Debug)]
+#[error("Thread failed at part {index} {error}")]
+pub struct ThreadError {
+    pub index: usize,
+    pub posted_ids: Vec<String>,
+    pub error: Error,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Thread {
+    parts: Vec<ThreadPart>,
+    in_reply_to_tweet_id: Option<String>,
+    twapi_options: Option<TwapiOptions>,
+}
+
+impl Thread {
+    pub fn new(parts: Vec<ThreadPart>) -> Self {
+        Self {
+            parts,
+            ..Default::default()
+        }
+    }
+
+    pub fn in_reply_to_tweet_id(mut self, value: &str) -> Self {
+        self.in_reply_to_tweet_id = Some(value.to_owned());
+        self
+    }
+
+    pub fn twapi_options(mut self, value: TwapiOptions) -> Self {
+        self.twapi_options = Some(value);
+        self
+    }
+
+    pub fn parts(&self) -> &[ThreadPart] {
+        &self.parts
+    }
+
+    #[cfg(feature = "reqwest")]
+    pub async fn execute(
+        &self,
+        authentication: &impl Authentication,
+    ) -> Result<Vec<String>, ThreadError> {
+        self.resume(authentication, vec![]).await
+    }
+
+    #[cfg(feature = "reqwest")]
+    /// Continue posting after `posted_ids`, typically `ThreadError::posted_ids` of a failed run.
+    pub async fn resume(
+        &self,
+        authentication: &impl Authentication,
+        posted_ids: Vec<String>,
+    ) -> Result<Vec<String>, ThreadError> {
+        let mut posted_ids = posted_ids;
+        for (index, part) in self.parts.iter().enumerate().skip(posted_ids.len()) {
+            let in_reply_to_tweet_id = posted_ids
+                .last()
+                .cloned()
+                .or_else(|| self.in_reply_to_tweet_id.clone());
+            match self.post(authentication, part, in_reply_to_tweet_id).await {
+                Ok(id) => {
+                    tracing::info!(index = index, id = id, "thread");
+                    posted_ids.push(id);
+                }
+                Err(error) => {
+                    return Err(ThreadError {
+                        index,
+                        posted_ids,
+                        error,
+                    })
+                }
+            }
+        }
+        Ok(posted_ids)
+    }
+
+    #[cfg(feature = "reqwest")]
+    async fn post(
+        &self,
+        authentication: &impl Authentication,
+        part: &ThreadPart,
+        in_reply_to_tweet_id: Option<String>,
+    ) -> Result<String, Error> {
+        let body = Body {
+            text: Some(part.text.clone()),
+            media: if part.media_ids.is_empty() {
+                None
+            } else {
+                Some(Media {
+                    media_ids: part.media_ids.clone(),
+                    ..Default::default()
+                })
+            },
+            reply: in_reply_to_tweet_id.map(|it| Reply {
+                in_reply_to_tweet_id: it,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut api = post_2_tweets::Api::new(body);
+        if let Some(twapi_options) = self.twapi_options.clone() {
+            api = api.twapi_options(twapi_options);
+        }
+        let (response, _) = api.execute(authentication).await?;
+        response
+            .data
+            .and_then(|it| it.id)
+            .ok_or(Error::Other("tweet id not found".to_owned(), None))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ThreadBuilder {
+    text: String,
+    max_weighted_length: Option<usize>,
+    numbering: bool,
+    media_ids: Vec<Vec<String>>,
+    in_reply_to_tweet_id: Option<String>,
+    twapi_options: Option<TwapiOptions>,
+}
+
+impl ThreadBuilder {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn max_weighted_length(mut self, value: usize) -> Self {
+        self.max_weighted_length = Some(value);
+        self
+    }
+
+    /// Append " 1/3" style counters to every part.
+    pub fn numbering(mut self, value: bool) -> Self {
+        self.numbering = value;
+        self
+    }
+
+    /// Attach media to the part at `index`.
+    pub fn media_ids(mut self, index: usize, value: Vec<String>) -> Self {
+        if self.media_ids.len() <= index {
+            self.media_ids.resize(index + 1, vec![]);
+        }
+        self.media_ids[index] = value;
+        self
+    }
+
+    pub fn in_reply_to_tweet_id(mut self, value: &str) -> Self {
+        self.in_reply_to_tweet_id = Some(value.to_owned());
+        self
+    }
+
+    pub fn twapi_options(mut self, value: TwapiOptions) -> Self {
+        self.twapi_options = Some(value);
+        self
+    }
+
+    pub fn split(&self) -> Vec<String> {
+        let max = self.max_weighted_length.unwrap_or(MAX_WEIGHTED_LENGTH);
+        if !self.numbering {
+            return split_text(&self.text, max);
+        }
+        // The counter width depends on the number of parts, so split until it is stable.
+        let mut count = 1;
+        loop {
+            let reserved = weighted_length(&format!(" {}/{}", count, count));
+            let texts = split_text(&self.text, max.saturating_sub(reserved).max(1));
+            if texts.len().to_string().len() <= count.to_string().len() {
+                let total = texts.len();
+                return texts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, text)| format!("{} {}/{}", text, index + 1, total))
+                    .collect();
+            }
+            count = texts.len();
+        }
+    }
+
+    pub fn build(self) -> Thread {
+        let parts = self
+            .split()
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| ThreadPart {
+                text,
+                media_ids: self.media_ids.get(index).cloned().unwrap_or_default(),
+            })
+            .collect();
+        Thread {
+            parts,
+            in_reply_to_tweet_id: self.in_reply_to_tweet_id,
+            twapi_options: self.twapi_options,
+        }
+    }
+
+    #[cfg(feature = "reqwest")]
+    pub async fn execute(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<Vec<String>, ThreadError> {
+        self.build().execute(authentication).await
+    }
+}
+
+fn split_text(text: &str, max: usize) -> Vec<String> {
+    // Cut in the normalized text, it is what Twitter counts and stores.
+    let config = Configuration::default();
+    let normalized = normalize(text);
+    let chars: Vec<char> = normalized.trim().chars().collect();
+    let mut result = vec![];
+    let mut start = 0;
+    while start < chars.len() {
+        let cut = start + find_cut(&chars[start..], max, &config);
+        let part: String = chars[start..cut].iter().collect();
+        result.push(part.trim_end().to_owned());
+        start = cut;
+        while start < chars.len() && chars[start].is_whitespace() {
+            start += 1;
+        }
+    }
+    result
+}
+
+// Char index to cut at: the whole text if it fits,
+// else the last sentence end in the second half, else the last whitespace, else the limit.
+// Only unit boundaries are cut, a URL counts 23 only when it is whole.
+fn find_cut(chars: &[char], max: usize, config: &Configuration) -> usize {
+    let max_scaled = max * config.scale;
+    // (char end, weight of the prefix) of each unit. The window is doubled until it is over the limit,
+    // the cost is proportional to the cut rather than to the whole text.
+    let mut size = max + 1;
+    let ends = loop {
+        let end = size.min(chars.len());
+        let mut ends: Vec<(usize, usize)> = units(&chars[..end], config)
+            .iter()
+            .scan((0, 0), |(index, weight), unit| {
+                *index += unit.length;
+                *weight += unit.weight;
+                Some((*index, *weight))
+            })
+            .collect();
+        if end == chars.len() {
+            if ends.last().map(|it| it.1).unwrap_or(0) <= max_scaled {
+                return chars.len();
+            }
+            break ends;
+        }
+        // The last unit may go on after the window.
+        ends.pop();
+        if ends.last().is_some_and(|it| it.1 > max_scaled) {
+            break ends;
+        }
+        size *= 2;
+    };
+    let count = ends.partition_point(|(_, weight)| *weight <= max_scaled);
+    let limit = count
+        .checked_sub(1)
+        .map(|it| ends[it].0)
+        .unwrap_or(ends[0].0);
+
+    let mut sentence = None;
+    let mut word = None;
+    for (index, _) in ends.iter().take_while(|(index, _)| *index <= limit) {
+        let prev = chars[index - 1];
+        match chars.get(*index) {
+            Some(c) if c.is_whitespace() && SENTENCE_TERMINATORS.contains(&prev) => {
+                sentence = Some(*index)
+            }
+            Some(c) if c.is_whitespace() => word = Some(*index),
+            Some(_) if "。！？".contains(prev) => sentence = Some(*index),
+            _ => {}
+        }
+    }
+    let cut = match (sentence, word) {
+        (Some(sentence), _) if sentence * 2 >= limit => sentence,
+        (_, Some(word)) => word,
+        (Some(sentence), None) => sentence,
+        (None, None) => limit,
+    };
+    // Units of the window may differ from the cut text, e.g. a domain which ends at the cut. Step back if so.
+    let fits = |cut: usize| {
+        let text: String = chars[..cut].iter().collect();
+        weighted_length(text.trim_end()) <= max
+    };
+    if fits(cut) {
+        return cut;
+    }
+    ends.iter()
+        .map(|(index, _)| *index)
+        .filter(|index| *index < cut)
+        .rev()
+        .find(|index| fits(*index))
+        .unwrap_or(ends[0].0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadBuilder;
+    use crate::twitter_text::weighted_length;
+
+    #[test]
+    fn it_split_sentences() {
+        let text = "First sentence is here. Second sentence is a bit longer. Third!";
+        let parts = ThreadBuilder::new(text).max_weighted_length(40).split();
+        assert_eq!(
+            parts,
+            vec![
+                "First sentence is here.",
+                "Second sentence is a bit longer. Third!"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_split_cjk() {
+        let text = "あいうえお。かきくけこ。さしすせそ。";
+        let parts = ThreadBuilder::new(text).max_weighted_length(24).split();
+        assert_eq!(parts, vec!["あいうえお。かきくけこ。", "さしすせそ。"]);
+    }
+
+    #[test]
+    fn it_split_numbering() {
+        let text = "one two three four five six seven eight nine ten";
+        let parts = ThreadBuilder::new(text)
+            .max_weighted_length(16)
+            .numbering(true)
+            .split();
+        assert_eq!(parts.len(), 5);
+        assert_eq!(parts[0], "one two 1/5");
+        assert!(parts.iter().all(|it| weighted_length(it) <= 16));
+    }
+
+    #[test]
+    fn it_split_url() {
+        // The URL counts 23 when whole, its prefixes count per char.
+        let text = "Read https://example.com/a/very/long/path/to/the/article now, thanks";
+        let parts = ThreadBuilder::new(text).max_weighted_length(30).split();
+        assert_eq!(
+            parts,
+            vec![
+                "Read https://example.com/a/very/long/path/to/the/article",
+                "now, thanks"
+            ]
+        );
+        let parts = ThreadBuilder::new(text).max_weighted_length(25).split();
+        assert_eq!(
+            parts,
+            vec![
+                "Read",
+                "https://example.com/a/very/long/path/to/the/article",
+                "now, thanks"
+            ]
+        );
+        let text = "word ".repeat(40) + "https://example.com/path " + &"word ".repeat(40);
+        let parts = ThreadBuilder::new(&text)
+            .max_weighted_length(50)
+            .numbering(true)
+            .split();
+        assert!(parts.iter().all(|it| weighted_length(it) <= 50));
+        assert!(parts
+            .iter()
+            .any(|it| it.contains("https://example.com/path")));
+    }
+
+    #[test]
+    fn it_split_long() {
+        let text = "word ".repeat(20000);
+        let parts = ThreadBuilder::new(&text).split();
+        assert_eq!(parts.len(), 358);
+        assert!(parts.iter().all(|it| weighted_length(it) <= 280));
+    }
+}
@@ -0,0 +1,275 @@
+use std::ops::Range;
+use thiserror::Error;
+
+mod emoji;
+pub mod extractor;
+pub mod render;
+mod tld;
+mod url;
+
+const INVALID_CHARACTERS: [char; 3] = ['\u{FFFE}', '\u{FEFF}', '\u{FFFF}'];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightRange {
+    pub start: u32,
+    pub end: u32,
+    pub weight: usize,
+}
+
+// https://github.com/twitter/twitter-text/blob/master/config/v3.json
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Configuration {
+    pub max_weighted_tweet_length: usize,
+    pub scale: usize,
+    pub default_weight: usize,
+    pub emoji_parsing_enabled: bool,
+    pub transformed_url_length: usize,
+    pub ranges: Vec<WeightRange>,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            max_weighted_tweet_length: 280,
+            scale: 100,
+            default_weight: 200,
+            emoji_parsing_enabled: true,
+            transformed_url_length: 23,
+            ranges: vec![
+                WeightRange {
+                    start: 0,
+                    end: 4351,
+                    weight: 100,
+                },
+                WeightRange {
+                    start: 8192,
+                    end: 8205,
+                    weight: 100,
+                },
+                WeightRange {
+                    start: 8208,
+                    end: 8223,
+                    weight: 100,
+                },
+                WeightRange {
+                    start: 8242,
+                    end: 8247,
+                    weight: 100,
+                },
+            ],
+        }
+    }
+}
+
+impl Configuration {
+    fn char_weight(&self, c: char) -> usize {
+        let code = c as u32;
+        self.ranges
+            .iter()
+            .find(|it| it.start <= code && code <= it.end)
+            .map(|it| it.weight)
+            .unwrap_or(self.default_weight)
+    }
+}
+
+/// Ranges are code point offsets into the NFC normalized text, the same unit as entity `start`/`end`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseResults {
+    pub weighted_length: usize,
+    pub permillage: usize,
+    pub is_valid: bool,
+    pub display_range: Range<usize>,
+    pub valid_range: Range<usize>,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("Empty")]
+    Empty,
+
+    #[error("TooLong {weighted_length} > {max_weighted_length} at {range:?}")]
+    TooLong {
+        weighted_length: usize,
+        max_weighted_length: usize,
+        range: Range<usize>,
+    },
+
+    #[error("InvalidCharacter at {range:?}")]
+    InvalidCharacter { range: Range<usize> },
+}
+
+pub fn parse_tweet(text: &str) -> ParseResults {
+    parse_tweet_with_config(text, &Configuration::default())
+}
+
+pub fn parse_tweet_with_config(text: &str, config: &Configuration) -> ParseResults {
+    parse(text, config).0
+}
+
+pub fn weighted_length(text: &str) -> usize {
+    parse_tweet(text).weighted_length
+}
+
+pub fn validate(text: &str) -> Result<ParseResults, ValidationError> {
+    validate_with_config(text, &Configuration::default())
+}
+
+pub fn validate_with_config(
+    text: &str,
+    config: &Configuration,
+) -> Result<ParseResults, ValidationError> {
+    let (results, invalid_index) = parse(text, config);
+    if results.display_range.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    if let Some(index) = invalid_index {
+        return Err(ValidationError::InvalidCharacter {
+            range: index..index + 1,
+        });
+    }
+    if results.weighted_length > config.max_weighted_tweet_length {
+        return Err(ValidationError::TooLong {
+            weighted_length: results.weighted_length,
+            max_weighted_length: config.max_weighted_tweet_length,
+            range: results.valid_range.end..results.display_range.end,
+        });
+    }
+    Ok(results)
+}
+
+// twitter-text counts the NFC normalized text.
+pub(crate) fn normalize(text: &str) -> String {
+    use unicode_normalization::UnicodeNormalization;
+    text.nfc().collect()
+}
+
+/// A URL, an emoji sequence or a char, the text is never cut inside it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Unit {
+    /// In chars.
+    pub(crate) length: usize,
+    /// Scaled by `Configuration::scale`.
+    pub(crate) weight: usize,
+    pub(crate) url: bool,
+}
+
+/// Units of a normalized text.
+pub(crate) fn units(chars: &[char], config: &Configuration) -> Vec<Unit> {
+    let mut result = vec![];
+    let mut urls = url::extract(chars).into_iter().peekable();
+    let mut index = 0;
+    while index < chars.len() {
+        let unit = match urls.peek() {
+            Some(url) if url.start == index => {
+                let length = url.end - url.start;
+                urls.next();
+                Unit {
+                    length,
+                    weight: config.transformed_url_length * config.scale,
+                    url: true,
+                }
+            }
+            _ => {
+                let emoji = if config.emoji_parsing_enabled {
+                    emoji::emoji_length(&chars[index..])
+                } else {
+                    0
+                };
+                let (length, weight) = if emoji > 0 {
+                    (emoji, config.default_weight)
+                } else {
+                    (1, config.char_weight(chars[index]))
+                };
+                Unit {
+                    length,
+                    weight,
+                    url: false,
+                }
+            }
+        };
+        index += unit.length;
+        result.push(unit);
+    }
+    result
+}
+
+fn parse(text: &str, config: &Configuration) -> (ParseResults, Option<usize>) {
+    let normalized = normalize(text);
+    let chars: Vec<char> = normalized.chars().collect();
+    let max_scaled = config.max_weighted_tweet_length * config.scale;
+
+    let mut weighted = 0;
+    let mut valid_end = 0;
+    let mut invalid_index = None;
+    let mut index = 0;
+    for unit in units(&chars, config) {
+        if invalid_index.is_none() && INVALID_CHARACTERS.contains(&chars[index]) {
+            invalid_index = Some(index);
+        }
+        weighted += unit.weight;
+        if weighted <= max_scaled {
+            valid_end = index + unit.length;
+        }
+        index += unit.length;
+    }
+
+    let weighted_length = weighted / config.scale;
+    let results = ParseResults {
+        weighted_length,
+        permillage: weighted_length * 1000 / config.max_weighted_tweet_length.max(1),
+        is_valid: !chars.is_empty() && weighted <= max_scaled && invalid_index.is_none(),
+        display_range: 0..chars.len(),
+        valid_range: 0..valid_end,
+    };
+    (results, invalid_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_tweet, validate, weighted_length, ValidationError};
+
+    #[test]
+    fn it_weighted_length() {
+        assert_eq!(weighted_length("hello"), 5);
+        assert_eq!(weighted_length("こんにちは"), 10);
+        assert_eq!(weighted_length("👨‍👩‍👧‍👦"), 2);
+        assert_eq!(weighted_length("🇯🇵 #️⃣"), 5);
+        assert_eq!(
+            weighted_length("https://example.com/a/very/long/path/to/somewhere"),
+            23
+        );
+        assert_eq!(weighted_length("see example.com"), 27);
+    }
+
+    #[test]
+    fn it_nfc() {
+        // e + combining acute is normalized to é, the ranges are offsets into the normalized text
+        assert_eq!(weighted_length("e\u{0301}"), 1);
+        let results = parse_tweet("cafe\u{0301}");
+        assert_eq!(results.weighted_length, 4);
+        assert_eq!(results.display_range, 0..4);
+        let text = "e\u{0301}".repeat(280);
+        assert!(validate(&text).is_ok());
+    }
+
+    #[test]
+    fn it_validate() {
+        assert_eq!(validate(""), Err(ValidationError::Empty));
+        assert_eq!(
+            validate("a\u{FFFE}"),
+            Err(ValidationError::InvalidCharacter { range: 1..2 })
+        );
+        let text = "あ".repeat(141);
+        assert_eq!(
+            validate(&text),
+            Err(ValidationError::TooLong {
+                weighted_length: 282,
+                max_weighted_length: 280,
+                range: 140..141,
+            })
+        );
+        let results = parse_tweet(&"a".repeat(280));
+        assert!(results.is_valid);
+        assert_eq!(results.permillage, 1000);
+    }
+}
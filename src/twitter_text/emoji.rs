@@ -0,0 +1,100 @@
+// Emoji sequences are weighted as a single character by twitter-text.
+// https://unicode.org/reports/tr51/#Definitions
+
+const ZWJ: char = '\u{200D}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+const COMBINING_ENCLOSING_KEYCAP: char = '\u{20E3}';
+
+fn is_pictographic(c: char) -> bool {
+    matches!(c as u32,
+        0x203C | 0x2049 | 0x2122 | 0x2139 | 0x2194..=0x2199 | 0x21A9..=0x21AA
+        | 0x231A..=0x231B | 0x2328 | 0x23CF | 0x23E9..=0x23F3 | 0x23F8..=0x23FA
+        | 0x24C2 | 0x25AA..=0x25AB | 0x25B6 | 0x25C0 | 0x25FB..=0x25FE
+        | 0x2600..=0x27BF | 0x2934..=0x2935 | 0x2B05..=0x2B07 | 0x2B1B..=0x2B1C
+        | 0x2B50 | 0x2B55 | 0x3030 | 0x303D | 0x3297 | 0x3299
+        | 0x1F000..=0x1F1E5 | 0x1F200..=0x1F3FA | 0x1F400..=0x1FAFF)
+}
+
+fn is_text_default(c: char) -> bool {
+    matches!(c, '\u{00A9}' | '\u{00AE}')
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+fn is_skin_tone(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+fn is_tag(c: char) -> bool {
+    ('\u{E0020}'..='\u{E007F}').contains(&c)
+}
+
+fn is_keycap_base(c: char) -> bool {
+    c.is_ascii_digit() || c == '#' || c == '*'
+}
+
+/// Number of chars of the emoji sequence starting at `chars[0]`, or 0.
+pub(crate) fn emoji_length(chars: &[char]) -> usize {
+    let Some(&first) = chars.first() else {
+        return 0;
+    };
+    if is_regional_indicator(first) {
+        return match chars.get(1) {
+            Some(&c) if is_regional_indicator(c) => 2,
+            _ => 0,
+        };
+    }
+    if is_keycap_base(first) {
+        return match (chars.get(1), chars.get(2)) {
+            (Some(&VARIATION_SELECTOR_16), Some(&COMBINING_ENCLOSING_KEYCAP)) => 3,
+            (Some(&COMBINING_ENCLOSING_KEYCAP), _) => 2,
+            _ => 0,
+        };
+    }
+    let mut length = if is_pictographic(first) {
+        1
+    } else if is_text_default(first) && chars.get(1) == Some(&VARIATION_SELECTOR_16) {
+        2
+    } else {
+        return 0;
+    };
+    loop {
+        match chars.get(length) {
+            Some(&c) if c == VARIATION_SELECTOR_16 || is_skin_tone(c) || is_tag(c) => {
+                length += 1;
+            }
+            Some(&ZWJ) => match chars.get(length + 1) {
+                Some(&c) if is_pictographic(c) => length += 2,
+                _ => return length,
+            },
+            _ => return length,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::emoji_length;
+
+    fn length(src: &str) -> usize {
+        emoji_length(&src.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn it_emoji_length() {
+        assert_eq!(length("a"), 0);
+        assert_eq!(length("©"), 0);
+        assert_eq!(length("©\u{FE0F}"), 2);
+        assert_eq!(length("😀"), 1);
+        assert_eq!(length("👍🏽"), 2);
+        assert_eq!(length("👨‍👩‍👧‍👦"), 7);
+        assert_eq!(length("🇯🇵"), 2);
+        assert_eq!(length("1\u{FE0F}\u{20E3}"), 3);
+        assert_eq!(
+            length("🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}"),
+            7
+        );
+    }
+}
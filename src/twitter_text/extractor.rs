@@ -0,0 +1,270 @@
+// https://github.com/twitter/twitter-text/blob/master/js/src/extractEntitiesWithIndices.js
+// start/end are code point offsets into the given text, like the entities returned by the API.
+
+use std::ops::Range;
+
+use unicode_normalization::char::is_combining_mark;
+
+use crate::responses::{
+    cashtags::Cashtags, entities::Entities, hashtags::Hashtags, mentions::Mentions, urls::Urls,
+};
+
+use super::url;
+
+const MAX_USERNAME_LENGTH: usize = 20;
+const HASHTAG_SPECIAL_CHARS: [char; 16] = [
+    '_', '\u{200C}', '\u{200D}', '\u{A67E}', '\u{05BE}', '\u{05F3}', '\u{05F4}', '\u{FF5E}',
+    '\u{301C}', '\u{309B}', '\u{309C}', '\u{30A0}', '\u{30FB}', '\u{3003}', '\u{0F0B}', '\u{0F0C}',
+];
+
+fn is_hash(c: char) -> bool {
+    c == '#' || c == '＃'
+}
+
+fn is_at(c: char) -> bool {
+    c == '@' || c == '＠'
+}
+
+fn is_hashtag_letter(c: char) -> bool {
+    c.is_alphabetic() || is_combining_mark(c)
+}
+
+fn is_hashtag_char(c: char) -> bool {
+    is_hashtag_letter(c) || c.is_numeric() || HASHTAG_SPECIAL_CHARS.contains(&c) || c == '\u{00B7}'
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_latin_accent(c: char) -> bool {
+    matches!(c as u32,
+        0xC0..=0xD6 | 0xD8..=0xF6 | 0xF8..=0xFF | 0x100..=0x24F | 0x253..=0x254
+        | 0x256..=0x257 | 0x259 | 0x25B | 0x263 | 0x268 | 0x26F | 0x272 | 0x289 | 0x28B
+        | 0x2BB | 0x300..=0x36F | 0x1E00..=0x1EFF)
+}
+
+fn followed_by_protocol(chars: &[char], index: usize) -> bool {
+    chars.get(index..index + 3) == Some(&[':', '/', '/'])
+}
+
+fn overlaps(urls: &[Range<usize>], start: usize, end: usize) -> bool {
+    urls.iter().any(|it| it.start < end && start < it.end)
+}
+
+fn url_ranges(chars: &[char]) -> Vec<Range<usize>> {
+    url::extract(chars)
+        .into_iter()
+        .map(|it| it.start..it.end)
+        .collect()
+}
+
+fn collect(chars: &[char], range: Range<usize>) -> String {
+    chars[range].iter().collect()
+}
+
+pub fn extract_urls(text: &str) -> Vec<Urls> {
+    let chars: Vec<char> = text.chars().collect();
+    url::extract(&chars)
+        .into_iter()
+        .map(|it| {
+            let url = collect(&chars, it.start..it.end);
+            let display_url = url
+                .split_once("://")
+                .map(|(_, rest)| rest.to_owned())
+                .unwrap_or(url.clone());
+            Urls {
+                start: Some(it.start as i64),
+                end: Some(it.end as i64),
+                expanded_url: Some(url.clone()),
+                display_url: Some(display_url),
+                url: Some(url),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+pub fn extract_hashtags(text: &str) -> Vec<Hashtags> {
+    let chars: Vec<char> = text.chars().collect();
+    let urls = url_ranges(&chars);
+    let mut result = vec![];
+    let mut index = 0;
+    while index < chars.len() {
+        if !is_hash(chars[index])
+            || (index > 0 && (is_hashtag_char(chars[index - 1]) || chars[index - 1] == '&'))
+            || matches!(chars.get(index + 1), Some('\u{FE0F}') | Some('\u{20E3}'))
+        {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        let mut end = index + 1;
+        while end < chars.len() && is_hashtag_char(chars[end]) {
+            end += 1;
+        }
+        let tag = &chars[start + 1..end];
+        let valid = tag.iter().any(|c| is_hashtag_letter(*c))
+            && !matches!(chars.get(end), Some(c) if is_hash(*c))
+            && !followed_by_protocol(&chars, end)
+            && !overlaps(&urls, start, end);
+        if valid {
+            result.push(Hashtags {
+                start: Some(start as i64),
+                end: Some(end as i64),
+                tag: Some(tag.iter().collect()),
+                ..Default::default()
+            });
+        }
+        index = end.max(start + 1);
+    }
+    result
+}
+
+pub fn extract_cashtags(text: &str) -> Vec<Cashtags> {
+    let chars: Vec<char> = text.chars().collect();
+    let urls = url_ranges(&chars);
+    let mut result = vec![];
+    for (start, c) in chars.iter().enumerate() {
+        if *c != '$' || (start > 0 && !chars[start - 1].is_whitespace()) {
+            continue;
+        }
+        let mut end = start + 1;
+        while end < chars.len() && end - start <= 6 && chars[end].is_ascii_alphabetic() {
+            end += 1;
+        }
+        if end == start + 1 {
+            continue;
+        }
+        // optional suffix such as $BRK.A
+        if matches!(chars.get(end), Some('.') | Some('_')) {
+            let suffix_end = chars[end + 1..]
+                .iter()
+                .take(3)
+                .take_while(|c| c.is_ascii_alphabetic())
+                .count();
+            if (1..=2).contains(&suffix_end) {
+                end += suffix_end + 1;
+            }
+        }
+        let terminated = match chars.get(end) {
+            None => true,
+            Some(c) => c.is_whitespace() || c.is_ascii_punctuation(),
+        };
+        if terminated && !overlaps(&urls, start, end) {
+            result.push(Cashtags {
+                start: Some(start as i64),
+                end: Some(end as i64),
+                tag: Some(collect(&chars, start + 1..end)),
+                ..Default::default()
+            });
+        }
+    }
+    result
+}
+
+pub fn extract_mentions(text: &str) -> Vec<Mentions> {
+    let chars: Vec<char> = text.chars().collect();
+    let urls = url_ranges(&chars);
+    let mut result = vec![];
+    let mut index = 0;
+    while index < chars.len() {
+        let preceded = index > 0 && {
+            let prev = chars[index - 1];
+            prev.is_ascii_alphanumeric() || "_!#$%&*".contains(prev) || is_at(prev)
+        };
+        if !is_at(chars[index]) || preceded {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        let mut end = index + 1;
+        while end < chars.len() && is_username_char(chars[end]) {
+            end += 1;
+        }
+        let length = end - start - 1;
+        let valid = (1..=MAX_USERNAME_LENGTH).contains(&length)
+            && !matches!(chars.get(end), Some(c) if is_at(*c) || is_latin_accent(*c))
+            && !followed_by_protocol(&chars, end)
+            && !overlaps(&urls, start, end);
+        if valid {
+            result.push(Mentions {
+                start: Some(start as i64),
+                end: Some(end as i64),
+                username: Some(collect(&chars, start + 1..end)),
+                ..Default::default()
+            });
+        }
+        index = end.max(start + 1);
+    }
+    result
+}
+
+pub fn extract_entities(text: &str) -> Entities {
+    fn non_empty<T>(src: Vec<T>) -> Option<Vec<T>> {
+        if src.is_empty() {
+            None
+        } else {
+            Some(src)
+        }
+    }
+    Entities {
+        cashtags: non_empty(extract_cashtags(text)),
+        hashtags: non_empty(extract_hashtags(text)),
+        mentions: non_empty(extract_mentions(text)),
+        urls: non_empty(extract_urls(text)),
+        ..Default::default()
+    }
+}
+
+/// Convert code point offsets `start..end` into a byte range of `text`.
+pub fn byte_range(text: &str, start: i64, end: i64) -> Option<Range<usize>> {
+    if start < 0 || end < start {
+        return None;
+    }
+    let mut indices = text
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(text.len()));
+    let byte_start = indices.nth(start as usize)?;
+    let byte_end = if end == start {
+        byte_start
+    } else {
+        indices.nth((end - start - 1) as usize)?
+    };
+    Some(byte_start..byte_end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_extract_entities() {
+        let text = "RT @rust_lang: 日本語 #ハッシュタグ and #rust2024 $TSLA https://example.com/#nohash @too_long_username_here_x";
+        let mentions = extract_mentions(text);
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].username.as_deref(), Some("rust_lang"));
+        assert_eq!((mentions[0].start, mentions[0].end), (Some(3), Some(13)));
+
+        let hashtags = extract_hashtags(text);
+        let tags: Vec<_> = hashtags.iter().map(|it| it.tag.clone().unwrap()).collect();
+        assert_eq!(tags, vec!["ハッシュタグ", "rust2024"]);
+        let range = byte_range(text, hashtags[0].start.unwrap(), hashtags[0].end.unwrap()).unwrap();
+        assert_eq!(&text[range], "#ハッシュタグ");
+
+        let cashtags = extract_cashtags(text);
+        assert_eq!(cashtags[0].tag.as_deref(), Some("TSLA"));
+
+        let urls = extract_urls(text);
+        assert_eq!(urls[0].url.as_deref(), Some("https://example.com/#nohash"));
+        assert_eq!(urls[0].display_url.as_deref(), Some("example.com/#nohash"));
+    }
+
+    #[test]
+    fn it_skip_invalid() {
+        assert!(extract_hashtags("#123 a#b &#39; #️⃣").is_empty());
+        assert!(extract_mentions("user@example.com @@a").is_empty());
+        assert!(extract_cashtags("US$100 $1 $toolongtag").is_empty());
+    }
+}
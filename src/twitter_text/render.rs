@@ -0,0 +1,377 @@
+// Render tweet text with its entities. Offsets are applied to the HTML-unescaped text.
+
+use crate::{
+    request::encode,
+    responses::{entities::Entities, tweets::Tweets, urls::Urls},
+};
+
+const PREFIX_URL: &str = "https://x.com";
+const MEDIA_DISPLAY_URLS: [&str; 2] = ["pic.twitter.com/", "pic.x.com/"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    Html,
+    Markdown,
+    #[default]
+    PlainText,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffsetUnit {
+    #[default]
+    CodePoint,
+    Utf16,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<'a> {
+    Url(&'a Urls),
+    Mention(&'a str),
+    Hashtag(&'a str),
+    Cashtag(&'a str),
+}
+
+#[derive(Debug, Clone)]
+struct Span<'a> {
+    start: usize,
+    end: usize,
+    kind: Kind<'a>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Renderer {
+    format: Format,
+    offset_unit: OffsetUnit,
+    remove_media_links: bool,
+    prefix_url: String,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new(Format::default())
+    }
+}
+
+impl Renderer {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            offset_unit: OffsetUnit::default(),
+            remove_media_links: true,
+            prefix_url: PREFIX_URL.to_owned(),
+        }
+    }
+
+    pub fn offset_unit(mut self, value: OffsetUnit) -> Self {
+        self.offset_unit = value;
+        self
+    }
+
+    /// Drop pic.twitter.com style links at the end of the text.
+    pub fn remove_media_links(mut self, value: bool) -> Self {
+        self.remove_media_links = value;
+        self
+    }
+
+    /// Where mention, hashtag and cashtag links point to.
+    pub fn prefix_url(mut self, value: &str) -> Self {
+        self.prefix_url = value.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Render `note_tweet` when present, otherwise `text`.
+    pub fn render_tweet(&self, tweet: &Tweets) -> String {
+        match tweet.note_tweet.as_ref() {
+            Some(note_tweet) if note_tweet.text.is_some() => self.render(
+                note_tweet.text.as_deref().unwrap_or_default(),
+                note_tweet.entities.as_ref(),
+            ),
+            _ => self.render(&tweet.text, tweet.entities.as_ref()),
+        }
+    }
+
+    pub fn render(&self, text: &str, entities: Option<&Entities>) -> String {
+        let chars: Vec<char> = unescape(text).chars().collect();
+        let mut spans = self.spans(&chars, entities);
+        let mut end = chars.len();
+        if self.remove_media_links {
+            while let Some(span) = spans.last() {
+                let is_trailing = chars[span.end..end].iter().all(|c| c.is_whitespace());
+                match span.kind {
+                    Kind::Url(url) if is_trailing && is_media_url(url) => {
+                        end = span.start;
+                        spans.pop();
+                    }
+                    _ => break,
+                }
+            }
+            while end > 0 && chars[end - 1].is_whitespace() {
+                end -= 1;
+            }
+        }
+
+        let mut result = String::new();
+        let mut index = 0;
+        for span in spans.iter() {
+            result.push_str(&self.text(&chars[index..span.start]));
+            result.push_str(&self.entity(&chars[span.start..span.end], &span.kind));
+            index = span.end;
+        }
+        result.push_str(&self.text(&chars[index..end]));
+        result
+    }
+
+    fn spans<'a>(&self, chars: &[char], entities: Option<&'a Entities>) -> Vec<Span<'a>> {
+        let Some(entities) = entities else {
+            return vec![];
+        };
+        let offsets = self.offsets(chars);
+        let mut spans = vec![];
+        let mut push = |start: Option<i64>, end: Option<i64>, kind: Kind<'a>| {
+            let (Some(start), Some(end)) = (start, end) else {
+                return;
+            };
+            let (Some(start), Some(end)) = (offsets(start), offsets(end)) else {
+                return;
+            };
+            if start < end {
+                spans.push(Span { start, end, kind });
+            }
+        };
+        for it in entities.urls.iter().flatten() {
+            push(it.start, it.end, Kind::Url(it));
+        }
+        for it in entities.mentions.iter().flatten() {
+            let username = it.username.as_deref().unwrap_or_default();
+            push(it.start, it.end, Kind::Mention(username));
+        }
+        for it in entities.hashtags.iter().flatten() {
+            let tag = it.tag.as_deref().or(it.hashtag.as_deref());
+            push(it.start, it.end, Kind::Hashtag(tag.unwrap_or_default()));
+        }
+        for it in entities.cashtags.iter().flatten() {
+            let tag = it.tag.as_deref().or(it.cashtag.as_deref());
+            push(it.start, it.end, Kind::Cashtag(tag.unwrap_or_default()));
+        }
+        spans.sort_by_key(|it| it.start);
+        let mut last_end = 0;
+        spans.retain(|it| {
+            let keep = it.start >= last_end;
+            if keep {
+                last_end = it.end;
+            }
+            keep
+        });
+        spans
+    }
+
+    // Maps an entity offset to a char index.
+    fn offsets<'b>(&self, chars: &'b [char]) -> impl Fn(i64) -> Option<usize> + 'b {
+        let unit = self.offset_unit;
+        move |offset: i64| {
+            let offset = usize::try_from(offset).ok()?;
+            match unit {
+                OffsetUnit::CodePoint => (offset <= chars.len()).then_some(offset),
+                OffsetUnit::Utf16 => {
+                    let mut utf16 = 0;
+                    for (index, c) in chars.iter().enumerate() {
+                        if utf16 >= offset {
+                            return Some(index);
+                        }
+                        utf16 += c.len_utf16();
+                    }
+                    (utf16 >= offset).then_some(chars.len())
+                }
+            }
+        }
+    }
+
+    fn text(&self, chars: &[char]) -> String {
+        let text: String = chars.iter().collect();
+        match self.format {
+            Format::Html => escape_html(&text).replace('\n', "<br>"),
+            Format::Markdown => escape_markdown(&text),
+            Format::PlainText => text,
+        }
+    }
+
+    fn entity(&self, chars: &[char], kind: &Kind) -> String {
+        let original: String = chars.iter().collect();
+        let (label, href) = match kind {
+            Kind::Url(url) => {
+                let href = url
+                    .unwound_url
+                    .as_deref()
+                    .or(url.expanded_url.as_deref())
+                    .or(url.url.as_deref())
+                    .unwrap_or(&original)
+                    .to_owned();
+                if self.format == Format::PlainText {
+                    return href;
+                }
+                let label = url.display_url.clone().unwrap_or(original);
+                (label, href)
+            }
+            Kind::Mention(username) => (
+                original,
+                format!("{}/{}", self.prefix_url, encode(username)),
+            ),
+            Kind::Hashtag(tag) => (
+                original,
+                format!("{}/hashtag/{}", self.prefix_url, encode(tag)),
+            ),
+            Kind::Cashtag(tag) => (
+                original,
+                format!("{}/search?q=%24{}", self.prefix_url, encode(tag)),
+            ),
+        };
+        // e.g. `javascript:` in expanded_url would be a live link.
+        if !is_http_url(&href) {
+            return self.text(&label.chars().collect::<Vec<_>>());
+        }
+        match self.format {
+            Format::Html => format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(&href),
+                escape_html(&label)
+            ),
+            Format::Markdown => format!(
+                "[{}]({})",
+                escape_markdown(&label),
+                href.replace('(', "%28")
+                    .replace(')', "%29")
+                    .replace(' ', "%20")
+            ),
+            Format::PlainText => label,
+        }
+    }
+}
+
+pub fn to_html(tweet: &Tweets) -> String {
+    Renderer::new(Format::Html).render_tweet(tweet)
+}
+
+pub fn to_markdown(tweet: &Tweets) -> String {
+    Renderer::new(Format::Markdown).render_tweet(tweet)
+}
+
+pub fn to_plain_text(tweet: &Tweets) -> String {
+    Renderer::new(Format::PlainText).render_tweet(tweet)
+}
+
+fn is_media_url(url: &Urls) -> bool {
+    url.media_key.is_some()
+        || url
+            .display_url
+            .as_deref()
+            .map(|it| {
+                MEDIA_DISPLAY_URLS
+                    .iter()
+                    .any(|prefix| it.starts_with(prefix))
+            })
+            .unwrap_or(false)
+}
+
+fn unescape(src: &str) -> String {
+    src.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn is_http_url(src: &str) -> bool {
+    let lower = src.trim_start().to_ascii_lowercase();
+    lower.starts_with("http://") || lower.starts_with("https://")
+}
+
+fn escape_html(src: &str) -> String {
+    src.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn escape_markdown(src: &str) -> String {
+    let mut result = String::with_capacity(src.len());
+    for c in src.chars() {
+        if "\\`*_[]()<>#~|!".contains(c) {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Format, OffsetUnit, Renderer};
+    use crate::responses::tweets::Tweets;
+
+    fn tweet() -> Tweets {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "edit_history_tweet_ids": ["1"],
+            "text": "😀 @rustlang &amp; #rust <b> https://t.co/abc https://t.co/pic",
+            "entities": {
+                "mentions": [{"start": 2, "end": 11, "username": "rustlang"}],
+                "hashtags": [{"start": 14, "end": 19, "tag": "rust"}],
+                "urls": [
+                    {"start": 24, "end": 40, "url": "https://t.co/abc", "expanded_url": "https://www.rust-lang.org/", "display_url": "rust-lang.org"},
+                    {"start": 41, "end": 57, "url": "https://t.co/pic", "expanded_url": "https://x.com/a/status/1/photo/1", "display_url": "pic.x.com/pic", "media_key": "3_1"}
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_render() {
+        let tweet = tweet();
+        assert_eq!(
+            Renderer::new(Format::PlainText).render_tweet(&tweet),
+            "😀 @rustlang & #rust <b> https://www.rust-lang.org/"
+        );
+        assert_eq!(
+            Renderer::new(Format::Html).render_tweet(&tweet),
+            "😀 <a href=\"https://x.com/rustlang\">@rustlang</a> &amp; <a href=\"https://x.com/hashtag/rust\">#rust</a> &lt;b&gt; <a href=\"https://www.rust-lang.org/\">rust-lang.org</a>"
+        );
+        assert_eq!(
+            Renderer::new(Format::Markdown).render_tweet(&tweet),
+            "😀 [@rustlang](https://x.com/rustlang) & [\\#rust](https://x.com/hashtag/rust) \\<b\\> [rust-lang.org](https://www.rust-lang.org/)"
+        );
+    }
+
+    #[test]
+    fn it_render_unsafe_url() {
+        let text = "see https://t.co/x";
+        let entities = serde_json::from_value(serde_json::json!({
+            "urls": [{"start": 4, "end": 18, "url": "https://t.co/x", "expanded_url": "javascript:alert(1)", "display_url": "<click>"}]
+        }))
+        .unwrap();
+        assert_eq!(
+            Renderer::new(Format::Html).render(text, Some(&entities)),
+            "see &lt;click&gt;"
+        );
+        assert_eq!(
+            Renderer::new(Format::Markdown).render(text, Some(&entities)),
+            "see \\<click\\>"
+        );
+    }
+
+    #[test]
+    fn it_render_utf16() {
+        let text = "😀 #rust";
+        let entities = serde_json::from_value(serde_json::json!({
+            "hashtags": [{"start": 3, "end": 8, "tag": "rust"}]
+        }))
+        .unwrap();
+        assert_eq!(
+            Renderer::new(Format::Markdown)
+                .offset_unit(OffsetUnit::Utf16)
+                .render(text, Some(&entities)),
+            "😀 [\\#rust](https://x.com/hashtag/rust)"
+        );
+    }
+}
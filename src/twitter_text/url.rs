@@ -0,0 +1,228 @@
+// A subset of twitter-text's URL recognition, enough to weight and extract links.
+// https://github.com/twitter/twitter-text/blob/master/js/src/regexp/extractUrl.js
+
+use super::tld::{COUNTRY_TLDS, GENERIC_TLDS};
+
+// ccTLDs linkified without protocol and path.
+const SPECIAL_CC_TLDS: [&str; 2] = ["co", "tv"];
+
+const TRAILING_PUNCTUATION: [char; 12] =
+    ['.', ',', ':', ';', '!', '?', '\'', '"', ')', ']', '}', '…'];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UrlMatch {
+    pub start: usize,
+    pub end: usize,
+    pub has_protocol: bool,
+}
+
+/// Char ranges of the URLs found in `chars`.
+pub(crate) fn extract(chars: &[char]) -> Vec<UrlMatch> {
+    let mut result = vec![];
+    let mut index = 0;
+    while index < chars.len() {
+        if let Some(url) = match_at(chars, index) {
+            index = url.end;
+            result.push(url);
+        } else {
+            index += 1;
+        }
+    }
+    result
+}
+
+fn starts_with_ignore_case(chars: &[char], prefix: &str) -> bool {
+    chars.len() >= prefix.len()
+        && prefix
+            .chars()
+            .zip(chars)
+            .all(|(a, b)| a.eq_ignore_ascii_case(b))
+}
+
+fn is_domain_char(c: char, has_protocol: bool) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_' || (has_protocol && c.is_alphanumeric())
+}
+
+fn is_path_char(c: char) -> bool {
+    !c.is_whitespace() && !"<>\"'`|\\{}^".contains(c) && (c as u32) < 0x2E80
+}
+
+// The lists are sorted.
+fn is_country_tld(tld: &str) -> bool {
+    COUNTRY_TLDS
+        .binary_search(&tld.to_lowercase().as_str())
+        .is_ok()
+}
+
+fn is_valid_tld(tld: &str) -> bool {
+    let lower = tld.to_lowercase();
+    GENERIC_TLDS.binary_search(&lower.as_str()).is_ok()
+        || COUNTRY_TLDS.binary_search(&lower.as_str()).is_ok()
+        || lower
+            .strip_prefix("xn--")
+            .map(|it| !it.is_empty() && it.chars().all(|c| c.is_ascii_alphanumeric()))
+            .unwrap_or(false)
+}
+
+fn match_at(chars: &[char], start: usize) -> Option<UrlMatch> {
+    let prev = if start > 0 {
+        Some(chars[start - 1])
+    } else {
+        None
+    };
+    if let Some(prev) = prev {
+        if prev.is_ascii_alphanumeric() || "@＠$#＃".contains(prev) {
+            return None;
+        }
+    }
+    let protocol_length = if starts_with_ignore_case(&chars[start..], "https://") {
+        8
+    } else if starts_with_ignore_case(&chars[start..], "http://") {
+        7
+    } else {
+        0
+    };
+    let has_protocol = protocol_length > 0;
+    if !has_protocol && prev.map(|it| "-_./".contains(it)).unwrap_or(false) {
+        return None;
+    }
+
+    // domain labels
+    let mut labels: Vec<(usize, usize)> = vec![];
+    let mut index = start + protocol_length;
+    loop {
+        let label_start = index;
+        while index < chars.len() && is_domain_char(chars[index], has_protocol) {
+            index += 1;
+        }
+        if label_start == index {
+            break;
+        }
+        labels.push((label_start, index));
+        if index + 1 < chars.len()
+            && chars[index] == '.'
+            && is_domain_char(chars[index + 1], has_protocol)
+        {
+            index += 1;
+        } else {
+            break;
+        }
+    }
+    while labels.len() >= 2 {
+        let (tld_start, tld_end) = labels[labels.len() - 1];
+        let tld: String = chars[tld_start..tld_end].iter().collect();
+        if is_valid_tld(&tld) {
+            break;
+        }
+        labels.pop();
+    }
+    if labels.len() < 2 {
+        return None;
+    }
+    let (tld_start, mut end) = labels[labels.len() - 1];
+    if end < chars.len() && is_domain_char(chars[end], has_protocol) {
+        return None;
+    }
+    if !has_protocol && chars.get(end) == Some(&'@') {
+        return None;
+    }
+
+    // port
+    if chars.get(end) == Some(&':') {
+        let port_end = chars[end + 1..]
+            .iter()
+            .position(|c| !c.is_ascii_digit())
+            .map(|it| it + end + 1)
+            .unwrap_or(chars.len());
+        if port_end > end + 1 {
+            end = port_end;
+        }
+    }
+
+    // path and query
+    let mut has_path = false;
+    if matches!(chars.get(end), Some('/') | Some('?') | Some('#')) {
+        let mut path_end = end;
+        while path_end < chars.len() && is_path_char(chars[path_end]) {
+            path_end += 1;
+        }
+        while path_end > end + 1 {
+            let last = chars[path_end - 1];
+            if !TRAILING_PUNCTUATION.contains(&last) {
+                break;
+            }
+            if last == ')' {
+                let opens = chars[end..path_end].iter().filter(|c| **c == '(').count();
+                let closes = chars[end..path_end].iter().filter(|c| **c == ')').count();
+                if opens >= closes {
+                    break;
+                }
+            }
+            path_end -= 1;
+        }
+        if chars[end] == '/' || path_end > end + 1 {
+            has_path = true;
+            end = path_end;
+        }
+    }
+
+    if !has_protocol && !has_path {
+        let tld: String = chars[tld_start..labels[labels.len() - 1].1]
+            .iter()
+            .collect::<String>()
+            .to_lowercase();
+        if is_country_tld(&tld) && !SPECIAL_CC_TLDS.contains(&tld.as_str()) {
+            return None;
+        }
+    }
+
+    Some(UrlMatch {
+        start,
+        end,
+        has_protocol,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extract;
+
+    fn urls(src: &str) -> Vec<String> {
+        let chars: Vec<char> = src.chars().collect();
+        extract(&chars)
+            .into_iter()
+            .map(|it| chars[it.start..it.end].iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn it_extract() {
+        assert_eq!(
+            urls("see https://example.com/a?b=c."),
+            vec!["https://example.com/a?b=c"]
+        );
+        assert_eq!(
+            urls("example.com and t.co/abc"),
+            vec!["example.com", "t.co/abc"]
+        );
+        assert_eq!(
+            urls("(https://en.wikipedia.org/wiki/Rust_(language))"),
+            vec!["https://en.wikipedia.org/wiki/Rust_(language)"]
+        );
+        assert_eq!(urls("mail me user@example.com"), Vec::<String>::new());
+        assert_eq!(urls("file.rs and example.jp"), Vec::<String>::new());
+        assert_eq!(
+            urls("東京https://example.jp/東京"),
+            vec!["https://example.jp/"]
+        );
+        assert_eq!(urls("Visit example.com.Thanks"), vec!["example.com"]);
+        assert_eq!(
+            urls("example.photography and example.ac/abc"),
+            vec!["example.photography", "example.ac/abc"]
+        );
+        assert_eq!(
+            urls("https://example.рф and https://example.notatld"),
+            vec!["https://example.рф"]
+        );
+    }
+}
@@ -1,20 +1,27 @@
-use std::{
-    io::{BufReader, Cursor, Read},
-    path::PathBuf,
-};
+use std::io::{Cursor, Read};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{io::BufReader, path::PathBuf};
 
 use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    api::{make_url_with_prefix, Authentication, TwapiOptions},
+    api::{Authentication, TwapiOptions},
     error::Error,
     headers::Headers,
+    telemetry,
 };
 
 use self::{media_category::MediaCategory, response::Response};
 
+pub mod get_2_media_upload;
 pub mod get_media_upload;
 pub mod media_category;
+pub mod post_2_media_metadata;
+pub mod post_2_media_upload;
+pub mod post_2_media_upload_append;
+pub mod post_2_media_upload_finalize;
+pub mod post_2_media_upload_init;
 pub mod post_media_metadata_create;
 pub mod post_media_subtitles_create;
 pub mod post_media_subtitles_delete;
@@ -22,29 +29,53 @@ pub mod post_media_upload_append;
 pub mod post_media_upload_finalize;
 pub mod post_media_upload_init;
 pub mod response;
+pub mod response_v2;
 
 const POSTFIX_URL: &str = "/1.1/media/upload.json";
 const ENV_KEY: &str = "TWAPI_V2_MEDIA_API_PREFIX_API";
 const PREFIX_URL_MEDIA: &str = "https://upload.twitter.com";
+const SEGMENT_SIZE: u64 = 5000000;
 
+#[deprecated(note = "process global, use TwapiOptions::upload_prefix_url")]
 pub fn clear_prefix_url() {
-    std::env::set_var(ENV_KEY, PREFIX_URL_MEDIA);
+    crate::runtime::set_var(ENV_KEY, PREFIX_URL_MEDIA);
 }
 
+#[deprecated(note = "process global, use TwapiOptions::upload_prefix_url")]
 pub fn setup_prefix_url(url: &str) {
-    std::env::set_var(ENV_KEY, url);
+    crate::runtime::set_var(ENV_KEY, url);
+}
+
+/// Which media upload API `upload_media` and `check_processing` talk to, set by `TwapiOptions::media_api_version`.
+/// V2 is `/2/media/upload` on the api prefix and the default, V1 is `/1.1/media/upload.json` on upload.twitter.com.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MediaApiVersion {
+    V1,
+    #[default]
+    V2,
+}
+
+impl std::fmt::Display for MediaApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::V1 => write!(f, "1.1"),
+            Self::V2 => write!(f, "2"),
+        }
+    }
 }
 
 pub(crate) fn make_url(twapi_options: &Option<TwapiOptions>, postfix_url: Option<&str>) -> String {
-    make_url_with_prefix(
-        &std::env::var(ENV_KEY).unwrap_or(PREFIX_URL_MEDIA.to_owned()),
-        twapi_options,
-        postfix_url.unwrap_or(POSTFIX_URL),
-    )
+    let prefix_url = twapi_options
+        .as_ref()
+        .and_then(|it| it.upload_prefix_url.as_ref().or(it.prefix_url.as_ref()))
+        .cloned()
+        .or(crate::runtime::get_var(ENV_KEY))
+        .unwrap_or(PREFIX_URL_MEDIA.to_owned());
+    format!("{}{}", prefix_url, postfix_url.unwrap_or(POSTFIX_URL))
 }
 
 pub(crate) async fn execute_no_response(builder: RequestBuilder) -> Result<Headers, Error> {
-    let response = builder.send().await?;
+    let response = telemetry::send(builder, 0).await?;
     let status_code = response.status();
     let header = response.headers();
     let headers = Headers::new(header);
@@ -56,16 +87,120 @@ pub(crate) async fn execute_no_response(builder: RequestBuilder) -> Result<Heade
     }
 }
 
+/// INIT, APPEND and FINALIZE the file at `path`.
+/// Not available on wasm32, which has no file system; use `upload_media_bytes` there.
+#[cfg(not(target_arch = "wasm32"))]
 pub async fn upload_media(
     path: &PathBuf,
     media_type: &str,
     media_category: Option<MediaCategory>,
     additional_owners: Option<String>,
     authentication: &impl Authentication,
+) -> Result<(response::Response, Headers), Error> {
+    upload_media_with_options(
+        path,
+        media_type,
+        media_category,
+        additional_owners,
+        authentication,
+        &TwapiOptions::default(),
+    )
+    .await
+}
+
+/// `upload_media` with the upload prefix url, timeout and media api version of `twapi_options`.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn upload_media_with_options(
+    path: &PathBuf,
+    media_type: &str,
+    media_category: Option<MediaCategory>,
+    additional_owners: Option<String>,
+    authentication: &impl Authentication,
+    twapi_options: &TwapiOptions,
+) -> Result<(response::Response, Headers), Error> {
+    let file_size = std::fs::metadata(path)?.len();
+    let reader = BufReader::new(std::fs::File::open(path)?);
+    upload_media_reader(
+        file_size,
+        reader,
+        media_type,
+        media_category,
+        additional_owners,
+        authentication,
+        twapi_options,
+    )
+    .await
+}
+
+/// Same as `upload_media` without file system access, for wasm32.
+pub async fn upload_media_bytes(
+    bytes: Vec<u8>,
+    media_type: &str,
+    media_category: Option<MediaCategory>,
+    additional_owners: Option<String>,
+    authentication: &impl Authentication,
+    twapi_options: &TwapiOptions,
+) -> Result<(response::Response, Headers), Error> {
+    upload_media_reader(
+        bytes.len() as u64,
+        Cursor::new(bytes),
+        media_type,
+        media_category,
+        additional_owners,
+        authentication,
+        twapi_options,
+    )
+    .await
+}
+
+async fn upload_media_reader(
+    file_size: u64,
+    reader: impl Read,
+    media_type: &str,
+    media_category: Option<MediaCategory>,
+    additional_owners: Option<String>,
+    authentication: &impl Authentication,
+    twapi_options: &TwapiOptions,
+) -> Result<(response::Response, Headers), Error> {
+    // Resolved once, INIT, APPEND and FINALIZE use the same API.
+    match twapi_options.media_api_version.unwrap_or_default() {
+        MediaApiVersion::V1 => {
+            upload_media_v1(
+                file_size,
+                reader,
+                media_type,
+                media_category,
+                additional_owners,
+                authentication,
+                twapi_options,
+            )
+            .await
+        }
+        MediaApiVersion::V2 => {
+            upload_media_v2(
+                file_size,
+                reader,
+                media_type,
+                media_category,
+                additional_owners,
+                authentication,
+                twapi_options,
+            )
+            .await
+        }
+    }
+}
+
+async fn upload_media_v1(
+    file_size: u64,
+    reader: impl Read,
+    media_type: &str,
+    media_category: Option<MediaCategory>,
+    additional_owners: Option<String>,
+    authentication: &impl Authentication,
+    twapi_options: &TwapiOptions,
 ) -> Result<(response::Response, Headers), Error> {
     // INIT
-    let metadata = std::fs::metadata(path)?;
-    let file_size = metadata.len();
     let data = post_media_upload_init::Data {
         total_bytes: file_size,
         media_type: media_type.to_owned(),
@@ -73,50 +208,136 @@ pub async fn upload_media(
         additional_owners,
     };
     let (response, _) = post_media_upload_init::Api::new(data)
+        .twapi_options(twapi_options.clone())
         .execute(authentication)
         .await?;
     let media_id = response.media_id_string;
     tracing::info!(media_id = media_id, "post_media_upload_init");
 
     // APPEND
-    execute_append(path, authentication, file_size, &media_id).await?;
+    execute_append(
+        MediaApiVersion::V1,
+        reader,
+        authentication,
+        twapi_options,
+        file_size,
+        &media_id,
+    )
+    .await?;
 
     // FINALIZE
     let data = post_media_upload_finalize::Data {
         media_id: media_id.clone(),
     };
     let res = post_media_upload_finalize::Api::new(data)
+        .twapi_options(twapi_options.clone())
         .execute(authentication)
         .await;
     tracing::info!(media_id = media_id, "post_media_upload_finalize");
     res
 }
 
+async fn upload_media_v2(
+    file_size: u64,
+    reader: impl Read,
+    media_type: &str,
+    media_category: Option<MediaCategory>,
+    additional_owners: Option<String>,
+    authentication: &impl Authentication,
+    twapi_options: &TwapiOptions,
+) -> Result<(response::Response, Headers), Error> {
+    // INIT
+    let data = post_2_media_upload_init::Data {
+        total_bytes: file_size,
+        media_type: media_type.to_owned(),
+        media_category,
+        additional_owners,
+    };
+    let (response, _) = post_2_media_upload_init::Api::new(data)
+        .twapi_options(twapi_options.clone())
+        .execute(authentication)
+        .await?;
+    let media_id = response
+        .data
+        .ok_or(Error::Upload("media id not found".to_owned()))?
+        .id;
+    tracing::info!(media_id = media_id, "post_2_media_upload_init");
+
+    // APPEND
+    execute_append(
+        MediaApiVersion::V2,
+        reader,
+        authentication,
+        twapi_options,
+        file_size,
+        &media_id,
+    )
+    .await?;
+
+    // FINALIZE
+    let data = post_2_media_upload_finalize::Data {
+        media_id: media_id.clone(),
+    };
+    let (response, headers) = post_2_media_upload_finalize::Api::new(data)
+        .twapi_options(twapi_options.clone())
+        .execute(authentication)
+        .await?;
+    tracing::info!(media_id = media_id, "post_2_media_upload_finalize");
+    let data = response
+        .data
+        .ok_or(Error::Upload("media id not found".to_owned()))?;
+    Ok((data.into(), headers))
+}
+
+// The APPEND segment at `segment_index`, None after the last one.
+fn read_segment(
+    reader: &mut impl Read,
+    file_size: u64,
+    segment_index: u64,
+) -> Result<Option<Cursor<Vec<u8>>>, Error> {
+    if segment_index * SEGMENT_SIZE >= file_size {
+        return Ok(None);
+    }
+    let read_size = (file_size - segment_index * SEGMENT_SIZE).min(SEGMENT_SIZE) as usize;
+    let mut cursor = Cursor::new(vec![0; read_size]);
+    reader.read_exact(cursor.get_mut())?;
+    Ok(Some(cursor))
+}
+
 async fn execute_append(
-    path: &PathBuf,
+    version: MediaApiVersion,
+    mut reader: impl Read,
     authentication: &impl Authentication,
+    twapi_options: &TwapiOptions,
     file_size: u64,
     media_id: &str,
 ) -> Result<(), Error> {
     let mut segment_index = 0;
-    let f = std::fs::File::open(path)?;
-    let mut reader = BufReader::new(f);
-    while segment_index * 5000000 < file_size {
-        let read_size: usize = if (segment_index + 1) * 5000000 < file_size {
-            5000000
-        } else {
-            (file_size - segment_index * 5000000) as usize
-        };
-        let mut cursor = Cursor::new(vec![0; read_size]);
-        reader.read_exact(cursor.get_mut())?;
-        let data = post_media_upload_append::Data {
-            media_id: media_id.to_owned(),
-            segment_index,
-            cursor,
-        };
-        let _ = post_media_upload_append::Api::new(data)
-            .execute(authentication)
-            .await?;
+    while let Some(cursor) = read_segment(&mut reader, file_size, segment_index)? {
+        match version {
+            MediaApiVersion::V1 => {
+                let data = post_media_upload_append::Data {
+                    media_id: media_id.to_owned(),
+                    segment_index,
+                    cursor,
+                };
+                let _ = post_media_upload_append::Api::new(data)
+                    .twapi_options(twapi_options.clone())
+                    .execute(authentication)
+                    .await?;
+            }
+            MediaApiVersion::V2 => {
+                let data = post_2_media_upload_append::Data {
+                    media_id: media_id.to_owned(),
+                    segment_index,
+                    cursor,
+                };
+                let _ = post_2_media_upload_append::Api::new(data)
+                    .twapi_options(twapi_options.clone())
+                    .execute(authentication)
+                    .await?;
+            }
+        }
         tracing::info!(
             segment_index = segment_index,
             media_id = media_id,
@@ -127,11 +348,120 @@ async fn execute_append(
     Ok(())
 }
 
+/// Blocking `upload_media_with_options`. It does not wait for processing,
+/// poll `get_2_media_upload::Api::execute_blocking` (v1.1: `get_media_upload`) for videos.
+#[cfg(feature = "blocking")]
+pub fn upload_media_blocking(
+    path: &PathBuf,
+    media_type: &str,
+    media_category: Option<MediaCategory>,
+    additional_owners: Option<String>,
+    authentication: &impl Authentication,
+    twapi_options: &TwapiOptions,
+) -> Result<(response::Response, Headers), Error> {
+    let file_size = std::fs::metadata(path)?.len();
+    let mut reader = BufReader::new(std::fs::File::open(path)?);
+    let version = twapi_options.media_api_version.unwrap_or_default();
+
+    // INIT
+    let media_id = match version {
+        MediaApiVersion::V1 => {
+            let data = post_media_upload_init::Data {
+                total_bytes: file_size,
+                media_type: media_type.to_owned(),
+                media_category,
+                additional_owners,
+            };
+            let (response, _) = post_media_upload_init::Api::new(data)
+                .twapi_options(twapi_options.clone())
+                .execute_blocking(authentication)?;
+            response.media_id_string
+        }
+        MediaApiVersion::V2 => {
+            let data = post_2_media_upload_init::Data {
+                total_bytes: file_size,
+                media_type: media_type.to_owned(),
+                media_category,
+                additional_owners,
+            };
+            let (response, _) = post_2_media_upload_init::Api::new(data)
+                .twapi_options(twapi_options.clone())
+                .execute_blocking(authentication)?;
+            response
+                .data
+                .ok_or(Error::Upload("media id not found".to_owned()))?
+                .id
+        }
+    };
+    tracing::info!(media_id = media_id, "upload_media_blocking init");
+
+    // APPEND
+    let mut segment_index = 0;
+    while let Some(cursor) = read_segment(&mut reader, file_size, segment_index)? {
+        let media_id = media_id.clone();
+        match version {
+            MediaApiVersion::V1 => {
+                let data = post_media_upload_append::Data {
+                    media_id,
+                    segment_index,
+                    cursor,
+                };
+                post_media_upload_append::Api::new(data)
+                    .twapi_options(twapi_options.clone())
+                    .execute_blocking(authentication)?;
+            }
+            MediaApiVersion::V2 => {
+                let data = post_2_media_upload_append::Data {
+                    media_id,
+                    segment_index,
+                    cursor,
+                };
+                post_2_media_upload_append::Api::new(data)
+                    .twapi_options(twapi_options.clone())
+                    .execute_blocking(authentication)?;
+            }
+        }
+        segment_index += 1;
+    }
+
+    // FINALIZE
+    tracing::info!(media_id = media_id, "upload_media_blocking finalize");
+    match version {
+        MediaApiVersion::V1 => {
+            let data = post_media_upload_finalize::Data { media_id };
+            post_media_upload_finalize::Api::new(data)
+                .twapi_options(twapi_options.clone())
+                .execute_blocking(authentication)
+        }
+        MediaApiVersion::V2 => {
+            let data = post_2_media_upload_finalize::Data { media_id };
+            let (response, headers) = post_2_media_upload_finalize::Api::new(data)
+                .twapi_options(twapi_options.clone())
+                .execute_blocking(authentication)?;
+            let data = response
+                .data
+                .ok_or(Error::Upload("media id not found".to_owned()))?;
+            Ok((data.into(), headers))
+        }
+    }
+}
+
 pub async fn check_processing(
     response: Response,
     authentication: &impl Authentication,
     f: Option<impl Fn(i64, &Response, &Headers) -> Result<(), Error>>,
 ) -> Result<(), Error> {
+    check_processing_with_options(response, authentication, f, &TwapiOptions::default()).await
+}
+
+/// `check_processing` against the media api version of `twapi_options`.
+pub async fn check_processing_with_options(
+    response: Response,
+    authentication: &impl Authentication,
+    f: Option<impl Fn(i64, &Response, &Headers) -> Result<(), Error>>,
+    twapi_options: &TwapiOptions,
+) -> Result<(), Error> {
+    let version = twapi_options.media_api_version.unwrap_or_default();
     let media_id = response.media_id_string.clone();
     let mut processing_info = response.processing_info;
     let mut count = 0;
@@ -144,10 +474,9 @@ pub async fn check_processing(
         }
 
         if let Some(check_after_secs) = info.check_after_secs {
-            tokio::time::sleep(std::time::Duration::from_secs(check_after_secs)).await;
-            let (res, header) = get_media_upload::Api::new(media_id.clone())
-                .execute(authentication)
-                .await?;
+            crate::runtime::sleep(std::time::Duration::from_secs(check_after_secs)).await;
+            let (res, header) =
+                get_status(version, &media_id, authentication, twapi_options).await?;
             let progress_percent = res
                 .processing_info
                 .as_ref()
@@ -169,3 +498,29 @@ pub async fn check_processing(
         count += 1;
     }
 }
+
+async fn get_status(
+    version: MediaApiVersion,
+    media_id: &str,
+    authentication: &impl Authentication,
+    twapi_options: &TwapiOptions,
+) -> Result<(Response, Headers), Error> {
+    match version {
+        MediaApiVersion::V1 => {
+            get_media_upload::Api::new(media_id.to_owned())
+                .twapi_options(twapi_options.clone())
+                .execute(authentication)
+                .await
+        }
+        MediaApiVersion::V2 => {
+            let (res, header) = get_2_media_upload::Api::new(media_id.to_owned())
+                .twapi_options(twapi_options.clone())
+                .execute(authentication)
+                .await?;
+            let data = res
+                .data
+                .ok_or(Error::Upload("media id not found".to_owned()))?;
+            Ok((data.into(), header))
+        }
+    }
+}
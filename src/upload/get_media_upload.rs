@@ -2,6 +2,7 @@ use crate::{
     api::{execute_twitter, Authentication, TwapiOptions},
     error::Error,
     headers::Headers,
+    request::{execute_request, HttpClient, Method, TwapiRequest},
     upload::{make_url, response::Response},
 };
 use reqwest::RequestBuilder;
@@ -25,12 +26,16 @@ impl Api {
         self
     }
 
-    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
-        let client = reqwest::Client::new();
+    pub fn request(self) -> TwapiRequest {
         let url = make_url(&self.twapi_options, None);
-        let query = [("media_id", self.media_id.as_str()), ("command", "STATUS")];
-        let builder = client.get(&url).query(&query);
-        authentication.execute(builder, "GET", &url, &query)
+        TwapiRequest::new(Method::Get, &url).query(vec![
+            ("media_id", self.media_id),
+            ("command", "STATUS".to_owned()),
+        ])
+    }
+
+    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+        self.request().build(authentication)
     }
 
     pub async fn execute(
@@ -39,4 +44,20 @@ impl Api {
     ) -> Result<(Response, Headers), Error> {
         execute_twitter(self.build(authentication)).await
     }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        execute_request(client, self.request().authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        crate::blocking::execute_twitter(self.build(authentication))
+    }
 }
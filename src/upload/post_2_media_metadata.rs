@@ -0,0 +1,132 @@
+use crate::{
+    api::{execute_twitter, make_url, Authentication, TwapiOptions},
+    error::Error,
+    headers::Headers,
+    request::{execute_request, HttpClient, Method, TwapiRequest},
+    responses::errors::Errors,
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/2/media/metadata";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AltText {
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Metadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<AltText>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Body {
+    pub id: String,
+    pub metadata: Metadata,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    body: Body,
+    twapi_options: Option<TwapiOptions>,
+}
+
+impl Api {
+    pub fn new(body: Body) -> Self {
+        Self {
+            body,
+            ..Default::default()
+        }
+    }
+
+    pub fn twapi_options(mut self, value: TwapiOptions) -> Self {
+        self.twapi_options = Some(value);
+        self
+    }
+
+    pub fn request(self) -> TwapiRequest {
+        let url = make_url(&self.twapi_options, URL);
+        TwapiRequest::new(Method::Post, &url)
+            .json(&self.body)
+            .twapi_options(&self.twapi_options)
+    }
+
+    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+        self.request().build(authentication)
+    }
+
+    pub async fn execute(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        execute_twitter(self.build(authentication)).await
+    }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        execute_request(client, self.request().authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        crate::blocking::execute_twitter(self.build(authentication))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<Errors>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .errors
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub associated_metadata: Option<Metadata>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,79 @@
+use std::io::Cursor;
+
+use crate::{
+    api::{make_url, Authentication, TwapiOptions},
+    error::Error,
+    headers::Headers,
+    request::{execute_request_no_response, Form, HttpClient, Method, Part, TwapiRequest},
+    upload::execute_no_response,
+};
+use reqwest::RequestBuilder;
+
+const URL: &str = "/2/media/upload";
+
+#[derive(Debug, Clone, Default)]
+pub struct Data {
+    pub media_id: String,
+    pub segment_index: u64,
+    pub cursor: Cursor<Vec<u8>>,
+}
+
+impl Data {
+    fn make_form(self) -> Form {
+        Form::new()
+            .text("command", "APPEND")
+            .text("media_id", self.media_id)
+            .text("segment_index", self.segment_index.to_string())
+            .part("media", Part::bytes(self.cursor.into_inner()))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    data: Data,
+    twapi_options: Option<TwapiOptions>,
+}
+
+impl Api {
+    pub fn new(data: Data) -> Self {
+        Self {
+            data,
+            ..Default::default()
+        }
+    }
+
+    pub fn twapi_options(mut self, value: TwapiOptions) -> Self {
+        self.twapi_options = Some(value);
+        self
+    }
+
+    pub fn request(self) -> TwapiRequest {
+        let url = make_url(&self.twapi_options, URL);
+        TwapiRequest::new(Method::Post, &url)
+            .multipart(self.data.make_form())
+            .twapi_options(&self.twapi_options)
+    }
+
+    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+        self.request().build(authentication)
+    }
+
+    pub async fn execute(self, authentication: &impl Authentication) -> Result<Headers, Error> {
+        execute_no_response(self.build(authentication)).await
+    }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<Headers, Error> {
+        execute_request_no_response(client, self.request().authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(self, authentication: &impl Authentication) -> Result<Headers, Error> {
+        crate::blocking::execute_no_response(crate::blocking::from_request(
+            self.request().authorize(authentication),
+        ))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    api::{execute_twitter, make_url, Authentication, TwapiOptions},
+    error::Error,
+    headers::Headers,
+    request::{execute_request, Form, HttpClient, Method, TwapiRequest},
+    upload::response_v2::Response,
+};
+use reqwest::RequestBuilder;
+
+const URL: &str = "/2/media/upload";
+
+#[derive(Debug, Clone, Default)]
+pub struct Data {
+    pub media_id: String,
+}
+
+impl Data {
+    fn make_form(self) -> Form {
+        Form::new()
+            .text("command", "FINALIZE")
+            .text("media_id", self.media_id)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    data: Data,
+    twapi_options: Option<TwapiOptions>,
+}
+
+impl Api {
+    pub fn new(data: Data) -> Self {
+        Self {
+            data,
+            ..Default::default()
+        }
+    }
+
+    pub fn twapi_options(mut self, value: TwapiOptions) -> Self {
+        self.twapi_options = Some(value);
+        self
+    }
+
+    pub fn request(self) -> TwapiRequest {
+        let url = make_url(&self.twapi_options, URL);
+        TwapiRequest::new(Method::Post, &url)
+            .multipart(self.data.make_form())
+            .twapi_options(&self.twapi_options)
+    }
+
+    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+        self.request().build(authentication)
+    }
+
+    pub async fn execute(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        execute_twitter(self.build(authentication)).await
+    }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        execute_request(client, self.request().authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        crate::blocking::execute_twitter_blocking(crate::blocking::from_request(
+            self.request().authorize(authentication),
+        ))
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     api::{Authentication, TwapiOptions},
     error::Error,
     headers::Headers,
+    request::{execute_request_no_response, HttpClient, Method, TwapiRequest},
     upload::{execute_no_response, make_url},
 };
 use reqwest::RequestBuilder;
@@ -47,14 +48,31 @@ impl Api {
         self
     }
 
-    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
-        let client = reqwest::Client::new();
+    pub fn request(self) -> TwapiRequest {
         let url = make_url(&self.twapi_options, Some(URL));
-        let builder = client.post(&url).json(&self.body);
-        authentication.execute(builder, "POST", &url, &[])
+        TwapiRequest::new(Method::Post, &url).json(&self.body)
+    }
+
+    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+        self.request().build(authentication)
     }
 
     pub async fn execute(self, authentication: &impl Authentication) -> Result<Headers, Error> {
         execute_no_response(self.build(authentication)).await
     }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<Headers, Error> {
+        execute_request_no_response(client, self.request().authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(self, authentication: &impl Authentication) -> Result<Headers, Error> {
+        crate::blocking::execute_no_response(crate::blocking::into_blocking(
+            self.build(authentication),
+        )?)
+    }
 }
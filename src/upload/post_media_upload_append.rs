@@ -4,12 +4,10 @@ use crate::{
     api::{Authentication, TwapiOptions},
     error::Error,
     headers::Headers,
+    request::{execute_request_no_response, Form, HttpClient, Method, Part, TwapiRequest},
     upload::{execute_no_response, make_url},
 };
-use reqwest::{
-    multipart::{Form, Part},
-    RequestBuilder,
-};
+use reqwest::RequestBuilder;
 
 #[derive(Debug, Clone, Default)]
 pub struct Data {
@@ -47,14 +45,31 @@ impl Api {
         self
     }
 
-    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
-        let client = reqwest::Client::new();
+    pub fn request(self) -> TwapiRequest {
         let url = make_url(&self.twapi_options, None);
-        let builder = client.post(&url).multipart(self.data.make_form());
-        authentication.execute(builder, "POST", &url, &[])
+        TwapiRequest::new(Method::Post, &url).multipart(self.data.make_form())
+    }
+
+    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+        self.request().build(authentication)
     }
 
     pub async fn execute(self, authentication: &impl Authentication) -> Result<Headers, Error> {
         execute_no_response(self.build(authentication)).await
     }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<Headers, Error> {
+        execute_request_no_response(client, self.request().authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(self, authentication: &impl Authentication) -> Result<Headers, Error> {
+        crate::blocking::execute_no_response(crate::blocking::from_request(
+            self.request().authorize(authentication),
+        ))
+    }
 }
@@ -2,9 +2,10 @@ use crate::{
     api::{execute_twitter, Authentication, TwapiOptions},
     error::Error,
     headers::Headers,
+    request::{execute_request, Form, HttpClient, Method, TwapiRequest},
     upload::{make_url, media_category::MediaCategory, response::Response},
 };
-use reqwest::{multipart::Form, RequestBuilder};
+use reqwest::RequestBuilder;
 
 #[derive(Debug, Clone, Default)]
 pub struct Data {
@@ -49,11 +50,13 @@ impl Api {
         self
     }
 
-    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
-        let client = reqwest::Client::new();
+    pub fn request(self) -> TwapiRequest {
         let url = make_url(&self.twapi_options, None);
-        let builder = client.post(&url).multipart(self.data.make_form());
-        authentication.execute(builder, "POST", &url, &[])
+        TwapiRequest::new(Method::Post, &url).multipart(self.data.make_form())
+    }
+
+    pub fn build(self, authentication: &impl Authentication) -> RequestBuilder {
+        self.request().build(authentication)
     }
 
     pub async fn execute(
@@ -62,4 +65,22 @@ impl Api {
     ) -> Result<(Response, Headers), Error> {
         execute_twitter(self.build(authentication)).await
     }
+
+    pub async fn execute_with(
+        self,
+        client: &impl HttpClient,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        execute_request(client, self.request().authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(
+        self,
+        authentication: &impl Authentication,
+    ) -> Result<(Response, Headers), Error> {
+        crate::blocking::execute_twitter_blocking(crate::blocking::from_request(
+            self.request().authorize(authentication),
+        ))
+    }
 }
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::responses::errors::Errors;
+
+use super::response::{Image, ProcessingInfo, Video};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    pub id: String,
+    pub media_key: Option<String>,
+    pub size: Option<u64>,
+    pub expires_after_secs: Option<u64>,
+    pub image: Option<Image>,
+    pub video: Option<Video>,
+    pub processing_info: Option<ProcessingInfo>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+impl From<Data> for super::response::Response {
+    fn from(value: Data) -> Self {
+        Self {
+            media_id: value.id.parse().unwrap_or_default(),
+            media_id_string: value.id,
+            expires_after_secs: value.expires_after_secs,
+            media_key: value.media_key,
+            size: value.size,
+            image: value.image,
+            video: value.video,
+            processing_info: value.processing_info,
+            extra: value.extra,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<Errors>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .errors
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,472 @@
+//! Checks fields and expansions before sending.
+//! Set `TwapiOptions::validator`, then `execute`, `execute_with` and `execute_blocking` fail with `Error::Validation`
+//! when the request will be rejected. Fields which will be silently dropped are logged by tracing as warnings.
+//!
+//! ```no_run
+//! use twapi_v2::{
+//!     api::{get_2_tweets_id, BearerAuthentication, TwapiOptions},
+//!     error::Error,
+//!     validation::{AccessLevel, Validator},
+//! };
+//!
+//! # async fn run(token: &str, id: &str) -> Result<(), Error> {
+//! let options = TwapiOptions::new().validator(Validator::new().access_level(AccessLevel::Basic));
+//! let auth = BearerAuthentication::new(token).user_context();
+//! get_2_tweets_id::Api::all(id).twapi_options(options).execute(&auth).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::RateLimit, request::TwapiRequest, telemetry::endpoint_template};
+
+pub(crate) mod endpoints;
+
+/// Kind of `Authentication`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthContext {
+    /// Not told by the `Authentication`, rules which depend on the context are skipped.
+    #[default]
+    Unknown,
+    /// App only bearer token.
+    App,
+    /// OAuth 1.0a and OAuth 2.0 user tokens.
+    User,
+}
+
+/// Access level of the project.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AccessLevel {
+    Free,
+    Basic,
+    Pro,
+    Enterprise,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Silently dropped by Twitter.
+    Warning,
+    /// Rejected by Twitter.
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub severity: Severity,
+    /// Query parameter such as `tweet.fields`, empty for the endpoint itself.
+    pub parameter: String,
+    pub value: Option<String>,
+    pub message: String,
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{}={}: {}", self.parameter, value, self.message),
+            None if self.parameter.is_empty() => write!(f, "{}", self.message),
+            None => write!(f, "{}: {}", self.parameter, self.message),
+        }
+    }
+}
+
+pub(crate) struct Endpoint {
+    pub(crate) method: &'static str,
+    pub(crate) template: &'static str,
+    pub(crate) rate_limit: Option<RateLimit>,
+    /// Object of `data`, its fields are never dropped.
+    primary: Option<&'static str>,
+    expansions: &'static [&'static str],
+    fields: &'static [&'static str],
+}
+
+enum Rule {
+    UserContext,
+    Ads,
+    /// Dropped without user context.
+    UserContextDropped,
+}
+
+const FIELDS: [(&str, &str, Rule); 7] = [
+    ("tweet.fields", "non_public_metrics", Rule::UserContext),
+    ("tweet.fields", "organic_metrics", Rule::UserContext),
+    ("tweet.fields", "promoted_metrics", Rule::Ads),
+    ("media.fields", "non_public_metrics", Rule::UserContext),
+    ("media.fields", "organic_metrics", Rule::UserContext),
+    ("media.fields", "promoted_metrics", Rule::Ads),
+    ("user.fields", "connection_status", Rule::UserContextDropped),
+];
+
+/// Objects in `includes` by the expansion.
+const EXPANSIONS: [(&str, &str); 18] = [
+    ("attachments.media_keys", "media"),
+    ("attachments.poll_ids", "poll"),
+    ("author_id", "user"),
+    ("creator_id", "user"),
+    ("edit_history_tweet_ids", "tweet"),
+    ("entities.mentions.username", "user"),
+    ("geo.place_id", "place"),
+    ("host_ids", "user"),
+    ("in_reply_to_user_id", "user"),
+    ("invited_user_ids", "user"),
+    ("owner_id", "user"),
+    ("participant_ids", "user"),
+    ("pinned_tweet_id", "tweet"),
+    ("referenced_tweets.id", "tweet"),
+    ("referenced_tweets.id.author_id", "user"),
+    ("sender_id", "user"),
+    ("speaker_ids", "user"),
+    ("topics_ids", "topic"),
+];
+
+/// Required access level of the endpoints, Basic if not listed.
+const ACCESS_LEVELS: [(&str, &str, AccessLevel); 15] = [
+    ("DELETE", "/2/tweets/:id", AccessLevel::Free),
+    ("GET", "/2/users/me", AccessLevel::Free),
+    ("POST", "/2/tweets", AccessLevel::Free),
+    ("GET", "/2/tweets/counts/all", AccessLevel::Pro),
+    ("GET", "/2/tweets/counts/recent", AccessLevel::Pro),
+    ("GET", "/2/tweets/sample/stream", AccessLevel::Pro),
+    ("GET", "/2/tweets/search/all", AccessLevel::Pro),
+    ("GET", "/2/tweets/search/stream", AccessLevel::Pro),
+    ("GET", "/2/tweets/search/stream/rules", AccessLevel::Pro),
+    ("POST", "/2/tweets/search/stream/rules", AccessLevel::Pro),
+    ("GET", "/2/usage/tweets", AccessLevel::Pro),
+    ("GET", "/2/spaces/:id/buyers", AccessLevel::Pro),
+    (
+        "GET",
+        "/2/tweets/compliance/stream",
+        AccessLevel::Enterprise,
+    ),
+    ("GET", "/2/tweets/sample10/stream", AccessLevel::Enterprise),
+    ("GET", "/2/users/compliance/stream", AccessLevel::Enterprise),
+];
+
+/// GET endpoints which need user context. POST, PUT and DELETE endpoints need it except `APP_ONLY` and the token endpoint.
+const USER_CONTEXT: [&str; 9] = [
+    "/2/dm_conversations/:dm_conversation_id/dm_events",
+    "/2/dm_conversations/with/:participant_id/dm_events",
+    "/2/dm_events",
+    "/2/spaces/:id/buyers",
+    "/2/users/:id/blocking",
+    "/2/users/:id/bookmarks",
+    "/2/users/:id/muting",
+    "/2/users/:id/timelines/reverse_chronological",
+    "/2/users/me",
+];
+
+const APP_ONLY: [(&str, &str); 11] = [
+    ("GET", "/2/compliance/jobs"),
+    ("GET", "/2/compliance/jobs/:id"),
+    ("GET", "/2/tweets/compliance/stream"),
+    ("GET", "/2/tweets/sample/stream"),
+    ("GET", "/2/tweets/sample10/stream"),
+    ("GET", "/2/tweets/search/stream"),
+    ("GET", "/2/tweets/search/stream/rules"),
+    ("GET", "/2/usage/tweets"),
+    ("GET", "/2/users/compliance/stream"),
+    ("POST", "/2/compliance/jobs"),
+    ("POST", "/2/tweets/search/stream/rules"),
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Validator {
+    access_level: Option<AccessLevel>,
+    ads: bool,
+    strict: bool,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Endpoints are not checked without it.
+    pub fn access_level(mut self, value: AccessLevel) -> Self {
+        self.access_level = Some(value);
+        self
+    }
+
+    /// The user has an ads account, `promoted_metrics` is available.
+    pub fn ads(mut self, value: bool) -> Self {
+        self.ads = value;
+        self
+    }
+
+    /// Warnings are errors.
+    pub fn strict(mut self, value: bool) -> Self {
+        self.strict = value;
+        self
+    }
+
+    pub fn validate(&self, request: &TwapiRequest, context: AuthContext) -> Vec<Issue> {
+        let mut issues = vec![];
+        let method = request.method.to_string();
+        let Some(template) = endpoint_template(&method, path(&request.url)) else {
+            return issues;
+        };
+
+        if let Some(access_level) = self.access_level {
+            let required = ACCESS_LEVELS
+                .iter()
+                .find(|(m, it, _)| *m == method && *it == template)
+                .map(|(_, _, it)| *it)
+                .unwrap_or(AccessLevel::Basic);
+            if access_level < required {
+                issues.push(endpoint_issue(format!(
+                    "{:?} access level is required",
+                    required
+                )));
+            }
+        }
+
+        let app_only = APP_ONLY
+            .iter()
+            .any(|(m, it)| *m == method && *it == template);
+        let user_only = !app_only
+            && template != "/2/oauth2/token"
+            && (method != "GET" || USER_CONTEXT.contains(&template));
+        // Both are skipped for AuthContext::Unknown.
+        if app_only && context == AuthContext::User {
+            issues.push(endpoint_issue(
+                "app only authentication is required".to_owned(),
+            ));
+        }
+        if user_only && context == AuthContext::App {
+            issues.push(endpoint_issue("user context is required".to_owned()));
+        }
+
+        let endpoint = endpoints::ENDPOINTS
+            .iter()
+            .find(|it| it.method == method && it.template == template);
+        let expansions: Vec<&str> = values(request, "expansions").collect();
+        if let Some(endpoint) = endpoint {
+            for value in expansions.iter() {
+                if !endpoint.expansions.contains(value) {
+                    issues.push(Issue {
+                        severity: Severity::Error,
+                        parameter: "expansions".to_owned(),
+                        value: Some(value.to_string()),
+                        message: "not an expansion of this endpoint".to_owned(),
+                    });
+                }
+            }
+        }
+        let objects: HashSet<&str> = expansions
+            .iter()
+            .filter_map(|value| EXPANSIONS.iter().find(|(it, _)| it == value))
+            .map(|(_, object)| *object)
+            .collect();
+
+        for (parameter, _) in request.query.iter() {
+            let Some(object) = parameter.strip_suffix(".fields") else {
+                continue;
+            };
+            let Some(endpoint) = endpoint else {
+                continue;
+            };
+            if !endpoint.fields.contains(&parameter.as_str()) {
+                issues.push(Issue {
+                    severity: Severity::Error,
+                    parameter: parameter.clone(),
+                    value: None,
+                    message: "not a parameter of this endpoint".to_owned(),
+                });
+            } else if endpoint.primary.is_some()
+                && endpoint.primary != Some(object)
+                && !objects.contains(object)
+            {
+                issues.push(Issue {
+                    severity: Severity::Warning,
+                    parameter: parameter.clone(),
+                    value: None,
+                    message: format!("dropped without an expansion to {}", object),
+                });
+            }
+        }
+
+        for (parameter, value, rule) in FIELDS.iter() {
+            if !values(request, parameter).any(|it| it == *value) {
+                continue;
+            }
+            let (severity, message) = match rule {
+                Rule::UserContext if context == AuthContext::App => {
+                    (Severity::Error, "user context is required")
+                }
+                Rule::Ads if context == AuthContext::App => {
+                    (Severity::Error, "user context is required")
+                }
+                Rule::Ads if !self.ads => (Severity::Error, "ads account is required"),
+                Rule::UserContextDropped if context == AuthContext::App => {
+                    (Severity::Warning, "dropped without user context")
+                }
+                _ => continue,
+            };
+            issues.push(Issue {
+                severity,
+                parameter: parameter.to_string(),
+                value: Some(value.to_string()),
+                message: message.to_owned(),
+            });
+        }
+
+        if self.strict {
+            for issue in issues.iter_mut() {
+                issue.severity = Severity::Error;
+            }
+        }
+        issues
+    }
+
+    /// Err when some issues are errors, otherwise warnings are logged and returned.
+    pub fn check(
+        &self,
+        request: &TwapiRequest,
+        context: AuthContext,
+    ) -> Result<Vec<Issue>, crate::error::Error> {
+        let issues = self.validate(request, context);
+        if issues.iter().any(|it| it.severity == Severity::Error) {
+            return Err(crate::error::Error::Validation(issues));
+        }
+        for issue in issues.iter() {
+            tracing::warn!(url = request.url, "{}", issue);
+        }
+        Ok(issues)
+    }
+}
+
+fn endpoint_issue(message: String) -> Issue {
+    Issue {
+        severity: Severity::Error,
+        parameter: String::new(),
+        value: None,
+        message,
+    }
+}
+
+/// `https://api.twitter.com/2/tweets` -> `/2/tweets`
+fn path(url: &str) -> &str {
+    let rest = url.split_once("://").map(|it| it.1).unwrap_or(url);
+    match rest.find('/') {
+        Some(index) => &rest[index..],
+        None => "/",
+    }
+}
+
+fn values<'a>(request: &'a TwapiRequest, parameter: &'a str) -> impl Iterator<Item = &'a str> {
+    request
+        .query
+        .iter()
+        .filter(move |(key, _)| key == parameter)
+        .flat_map(|(_, value)| value.split(','))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{get_2_tweets_id, get_2_tweets_search_stream, get_2_users_me, post_2_tweets},
+        fields::{media_fields::MediaFields, tweet_fields::TweetFields},
+    };
+
+    fn messages(issues: &[Issue]) -> Vec<String> {
+        issues.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn it_fields() {
+        let validator = Validator::new();
+        let request = get_2_tweets_id::Api::all("1").request();
+        assert_eq!(
+            messages(&validator.validate(&request, AuthContext::App)),
+            [
+                "tweet.fields=non_public_metrics: user context is required",
+                "tweet.fields=organic_metrics: user context is required",
+                "media.fields=non_public_metrics: user context is required",
+                "media.fields=organic_metrics: user context is required",
+                "media.fields=promoted_metrics: user context is required",
+                "user.fields=connection_status: dropped without user context",
+            ]
+        );
+        assert_eq!(
+            messages(&validator.validate(&request, AuthContext::User)),
+            ["media.fields=promoted_metrics: ads account is required"]
+        );
+        assert!(validator
+            .clone()
+            .ads(true)
+            .validate(&request, AuthContext::User)
+            .is_empty());
+        assert_eq!(
+            messages(
+                &validator.validate(&get_2_tweets_id::Api::open("1").request(), AuthContext::App)
+            ),
+            ["user.fields=connection_status: dropped without user context"]
+        );
+    }
+
+    #[test]
+    fn it_dropped() {
+        let validator = Validator::new();
+        let request = get_2_tweets_id::Api::new("1")
+            .tweet_fields(TweetFields::open())
+            .media_fields(MediaFields::open())
+            .request();
+        let issues = validator.validate(&request, AuthContext::App);
+        assert_eq!(
+            messages(&issues),
+            ["media.fields: dropped without an expansion to media"]
+        );
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert!(validator.check(&request, AuthContext::App).is_ok());
+        assert!(validator
+            .strict(true)
+            .check(&request, AuthContext::App)
+            .is_err());
+
+        let request = TwapiRequest::new(
+            crate::request::Method::Get,
+            "https://api.twitter.com/2/tweets/1",
+        )
+        .query(vec![
+            ("expansions", "author_id,owner_id".to_owned()),
+            ("list.fields", "id".to_owned()),
+        ]);
+        assert_eq!(
+            messages(&Validator::new().validate(&request, AuthContext::App)),
+            [
+                "expansions=owner_id: not an expansion of this endpoint",
+                "list.fields: not a parameter of this endpoint",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_endpoint() {
+        let validator = Validator::new().access_level(AccessLevel::Free);
+        let request = post_2_tweets::Api::new(post_2_tweets::Body::default()).request();
+        assert_eq!(
+            messages(&validator.validate(&request, AuthContext::App)),
+            ["user context is required"]
+        );
+        assert!(validator.validate(&request, AuthContext::User).is_empty());
+        // e.g. an OAuth 2.0 user token without `user_context()`
+        assert!(validator
+            .validate(&request, AuthContext::Unknown)
+            .is_empty());
+        let request = get_2_users_me::Api::new().request();
+        assert!(validator.validate(&request, AuthContext::User).is_empty());
+        let request = get_2_tweets_search_stream::Api::new().request();
+        assert_eq!(
+            messages(&validator.validate(&request, AuthContext::User)),
+            [
+                "Pro access level is required",
+                "app only authentication is required"
+            ]
+        );
+    }
+}
@@ -0,0 +1,1179 @@
+use super::Endpoint;
+use crate::endpoint::RateLimit;
+
+pub(crate) const ENDPOINTS: [Endpoint; 80] = [
+    Endpoint {
+        method: "DELETE",
+        template: "/2/lists/:id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/lists/:id/members/:user_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/tweets/:id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/users/:id/bookmarks/:tweet_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/users/:id/followed_lists/:list_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/users/:id/likes/:tweet_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/users/:id/pinned_lists/:list_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/users/:id/retweets/:source_tweet_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/users/:source_user_id/blocking/:target_user_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/users/:source_user_id/following/:target_user_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "DELETE",
+        template: "/2/users/:source_user_id/muting/:target_user_id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/compliance/jobs",
+        rate_limit: Some(RateLimit {
+            app: Some(150),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/compliance/jobs/:id",
+        rate_limit: Some(RateLimit {
+            app: Some(150),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/dm_conversations/:dm_conversation_id/dm_events",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: Some("dm_event"),
+        expansions: &[
+            "attachments.media_keys",
+            "referenced_tweets.id",
+            "sender_id",
+            "participant_ids",
+        ],
+        fields: &[
+            "dm_event.fields",
+            "media.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/dm_conversations/with/:participant_id/dm_events",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: Some("dm_event"),
+        expansions: &[
+            "attachments.media_keys",
+            "referenced_tweets.id",
+            "sender_id",
+            "participant_ids",
+        ],
+        fields: &[
+            "dm_event.fields",
+            "media.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/dm_events",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: Some("dm_event"),
+        expansions: &[
+            "attachments.media_keys",
+            "referenced_tweets.id",
+            "sender_id",
+            "participant_ids",
+        ],
+        fields: &[
+            "dm_event.fields",
+            "media.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/lists/:id",
+        rate_limit: Some(RateLimit {
+            app: Some(75),
+            user: Some(75),
+        }),
+        primary: Some("list"),
+        expansions: &["owner_id"],
+        fields: &["list.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/lists/:id/followers",
+        rate_limit: Some(RateLimit {
+            app: Some(180),
+            user: Some(180),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/lists/:id/members",
+        rate_limit: Some(RateLimit {
+            app: Some(900),
+            user: Some(900),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/lists/:id/tweets",
+        rate_limit: Some(RateLimit {
+            app: Some(900),
+            user: Some(900),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/spaces",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(300),
+        }),
+        primary: Some("space"),
+        expansions: &[
+            "invited_user_ids",
+            "speaker_ids",
+            "creator_id",
+            "host_ids",
+            "topics_ids",
+        ],
+        fields: &["space.fields", "topic.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/spaces/by/creator_ids",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(300),
+        }),
+        primary: Some("space"),
+        expansions: &[
+            "invited_user_ids",
+            "speaker_ids",
+            "creator_id",
+            "host_ids",
+            "topics_ids",
+        ],
+        fields: &["space.fields", "topic.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/spaces/:id",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(300),
+        }),
+        primary: Some("space"),
+        expansions: &[
+            "invited_user_ids",
+            "speaker_ids",
+            "creator_id",
+            "host_ids",
+            "topics_ids",
+        ],
+        fields: &["space.fields", "topic.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/spaces/:id/buyers",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/spaces/:id/tweets",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(300),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/spaces/search",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(300),
+        }),
+        primary: Some("space"),
+        expansions: &[
+            "invited_user_ids",
+            "speaker_ids",
+            "creator_id",
+            "host_ids",
+            "topics_ids",
+        ],
+        fields: &["space.fields", "topic.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/trends/by/woeid/:woeid",
+        rate_limit: Some(RateLimit {
+            app: Some(75),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(900),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/compliance/stream",
+        rate_limit: Some(RateLimit {
+            app: Some(100),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/counts/all",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/counts/recent",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/:id",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(900),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/:id/liking_users",
+        rate_limit: Some(RateLimit {
+            app: Some(75),
+            user: Some(75),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/:id/quote_tweets",
+        rate_limit: Some(RateLimit {
+            app: Some(75),
+            user: Some(75),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/:id/retweeted_by",
+        rate_limit: Some(RateLimit {
+            app: Some(75),
+            user: Some(75),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/sample10/stream",
+        rate_limit: Some(RateLimit {
+            app: Some(50),
+            user: None,
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/sample/stream",
+        rate_limit: Some(RateLimit {
+            app: Some(50),
+            user: None,
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/search/all",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: None,
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/search/recent",
+        rate_limit: Some(RateLimit {
+            app: Some(450),
+            user: Some(180),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/search/stream",
+        rate_limit: Some(RateLimit {
+            app: Some(50),
+            user: None,
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/tweets/search/stream/rules",
+        rate_limit: Some(RateLimit {
+            app: Some(450),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/usage/tweets",
+        rate_limit: Some(RateLimit {
+            app: Some(50),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &["usage.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(900),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/by",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(900),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/by/username/:username",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(900),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/compliance/stream",
+        rate_limit: Some(RateLimit {
+            app: Some(100),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(900),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/blocking",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(15),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/bookmarks",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(180),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/followed_lists",
+        rate_limit: Some(RateLimit {
+            app: Some(15),
+            user: Some(15),
+        }),
+        primary: Some("list"),
+        expansions: &["owner_id"],
+        fields: &["list.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/followers",
+        rate_limit: Some(RateLimit {
+            app: Some(15),
+            user: Some(15),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/following",
+        rate_limit: Some(RateLimit {
+            app: Some(15),
+            user: Some(15),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/liked_tweets",
+        rate_limit: Some(RateLimit {
+            app: Some(75),
+            user: Some(75),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/list_memberships",
+        rate_limit: Some(RateLimit {
+            app: Some(75),
+            user: Some(75),
+        }),
+        primary: None,
+        expansions: &["owner_id"],
+        fields: &["list.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/mentions",
+        rate_limit: Some(RateLimit {
+            app: Some(450),
+            user: Some(180),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/muting",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(15),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/owned_lists",
+        rate_limit: Some(RateLimit {
+            app: Some(15),
+            user: Some(15),
+        }),
+        primary: Some("list"),
+        expansions: &["owner_id"],
+        fields: &["list.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/pinned_lists",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(15),
+        }),
+        primary: None,
+        expansions: &["owner_id"],
+        fields: &["list.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/timelines/reverse_chronological",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(180),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/:id/tweets",
+        rate_limit: Some(RateLimit {
+            app: Some(1500),
+            user: Some(900),
+        }),
+        primary: Some("tweet"),
+        expansions: &[
+            "attachments.poll_ids",
+            "attachments.media_keys",
+            "author_id",
+            "edit_history_tweet_ids",
+            "entities.mentions.username",
+            "geo.place_id",
+            "in_reply_to_user_id",
+            "referenced_tweets.id",
+            "referenced_tweets.id.author_id",
+        ],
+        fields: &[
+            "media.fields",
+            "place.fields",
+            "poll.fields",
+            "tweet.fields",
+            "user.fields",
+        ],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/me",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(75),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "GET",
+        template: "/2/users/search",
+        rate_limit: Some(RateLimit {
+            app: Some(300),
+            user: Some(900),
+        }),
+        primary: Some("user"),
+        expansions: &["pinned_tweet_id"],
+        fields: &["tweet.fields", "user.fields"],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/compliance/jobs",
+        rate_limit: Some(RateLimit {
+            app: Some(150),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/dm_conversations",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(15),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/dm_conversations/:dm_conversation_id/messages",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(15),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/dm_conversations/with/:participant_id/messages",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(15),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/lists",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/lists/:id/members",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/tweets",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(200),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/tweets/search/stream/rules",
+        rate_limit: Some(RateLimit {
+            app: Some(450),
+            user: None,
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/users/:id/blocking",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/users/:id/bookmarks",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/users/:id/followed_lists",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/users/:id/following",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/users/:id/likes",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/users/:id/muting",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/users/:id/pinned_lists",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "POST",
+        template: "/2/users/:id/retweets",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "PUT",
+        template: "/2/lists/:id",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(300),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+    Endpoint {
+        method: "PUT",
+        template: "/2/tweets/:id/hidden",
+        rate_limit: Some(RateLimit {
+            app: None,
+            user: Some(50),
+        }),
+        primary: None,
+        expansions: &[],
+        fields: &[],
+    },
+];
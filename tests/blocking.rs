@@ -0,0 +1,100 @@
+use anyhow::Result;
+use mockito::{Matcher, Server};
+use serde_json::json;
+use twapi_v2::{
+    api::{get_2_users_me, BearerAuthentication, TwapiOptions},
+    oauth10a::OAuthAuthentication,
+    upload::{self, post_2_media_upload_init},
+};
+
+// cargo test test_blocking_mock --all-features -- --nocapture
+
+#[test]
+fn test_blocking_mock() -> Result<()> {
+    let mut server = Server::new();
+    let me = server
+        .mock("GET", "/2/users/me")
+        .match_header("authorization", "Bearer token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-rate-limit-remaining", "74")
+        .with_body(json!({"data": {"id": "1", "name": "name", "username": "username"}}).to_string())
+        .create();
+    let init = server
+        .mock("POST", "/2/media/upload")
+        .match_body(Matcher::Regex("INIT".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"data": {"id": "1880028106020515840", "media_key": "3_1880028106020515840"}})
+                .to_string(),
+        )
+        .create();
+    let twapi_options = TwapiOptions {
+        prefix_url: Some(server.url()),
+        ..Default::default()
+    };
+
+    let auth = BearerAuthentication::new("token");
+    let (response, headers) = get_2_users_me::Api::new()
+        .twapi_options(twapi_options.clone())
+        .execute_blocking(&auth)?;
+    assert_eq!(response.data.unwrap().username, "username");
+    assert_eq!(headers.x_rate_limit_remaining, Some(74));
+    me.assert();
+
+    let auth = OAuthAuthentication::new("a", "b", "c", "d");
+    let data = post_2_media_upload_init::Data {
+        total_bytes: 4,
+        media_type: "image/jpeg".to_owned(),
+        ..Default::default()
+    };
+    let (response, _) = post_2_media_upload_init::Api::new(data)
+        .twapi_options(twapi_options)
+        .execute_blocking(&auth)?;
+    assert_eq!(response.data.unwrap().id, "1880028106020515840");
+    init.assert();
+    Ok(())
+}
+
+#[test]
+fn test_upload_media_blocking_mock() -> Result<()> {
+    let mut server = Server::new();
+    let init = server
+        .mock("POST", "/2/media/upload")
+        .match_body(Matcher::Regex("INIT".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"data": {"id": "1880028106020515840"}}).to_string())
+        .create();
+    let append = server
+        .mock("POST", "/2/media/upload")
+        .match_body(Matcher::Regex("APPEND".to_owned()))
+        .with_status(204)
+        .create();
+    let finalize = server
+        .mock("POST", "/2/media/upload")
+        .match_body(Matcher::Regex("FINALIZE".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"data": {"id": "1880028106020515840", "size": 4}}).to_string())
+        .create();
+
+    let path = std::env::temp_dir().join("twapi_v2_upload_media_blocking.jpg");
+    std::fs::write(&path, [0xff, 0xd8, 0xff, 0xd9])?;
+    let auth = OAuthAuthentication::new("a", "b", "c", "d");
+    let (response, _) = upload::upload_media_blocking(
+        &path,
+        "image/jpeg",
+        None,
+        None,
+        &auth,
+        &TwapiOptions::new().prefix_url(&server.url()),
+    )?;
+    assert_eq!(response.media_id_string, "1880028106020515840");
+    assert_eq!(response.size, Some(4));
+    init.assert();
+    append.assert();
+    finalize.assert();
+    Ok(())
+}
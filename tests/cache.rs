@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use mockito::Server;
+use serde_json::json;
+use twapi_v2::{
+    api::{get_2_users_id, BearerAuthentication, TwapiOptions},
+    cache::{execute_cache, CacheOptions, CacheStatus, DiskCache, MemoryCache},
+};
+
+// cargo test test_cache_mock --all-features -- --nocapture
+
+#[tokio::test]
+async fn test_cache_mock() -> Result<()> {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/2/users/1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-rate-limit-remaining", "299")
+        .with_body(json!({"data": {"id": "1", "name": "name", "username": "username"}}).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let auth = BearerAuthentication::new("token");
+    let twapi_options = TwapiOptions {
+        prefix_url: Some(server.url()),
+        ..Default::default()
+    };
+    let api = get_2_users_id::Api::new("1").twapi_options(twapi_options);
+    let options = CacheOptions::new(Duration::from_secs(60));
+
+    let cache = MemoryCache::new(10);
+    let (_, _, status) =
+        execute_cache::<get_2_users_id::Response>(api.clone().build(&auth), &cache, &options)
+            .await?;
+    assert_eq!(status, CacheStatus::Miss);
+    let (response, headers, status) =
+        execute_cache::<get_2_users_id::Response>(api.clone().build(&auth), &cache, &options)
+            .await?;
+    assert_eq!(status, CacheStatus::Hit);
+    assert_eq!(response.data.unwrap().username, "username");
+    // Rate limit headers are not replayed from the cache.
+    assert_eq!(headers.x_rate_limit_remaining, None);
+
+    let dir = std::env::temp_dir().join("twapi_v2_test_cache_mock");
+    let _ = std::fs::remove_dir_all(&dir);
+    let cache = DiskCache::new(&dir)?;
+    let (_, _, status) =
+        execute_cache::<get_2_users_id::Response>(api.clone().build(&auth), &cache, &options)
+            .await?;
+    assert_eq!(status, CacheStatus::Miss);
+    let (_, _, status) =
+        execute_cache::<get_2_users_id::Response>(api.build(&auth), &cache, &options).await?;
+    assert_eq!(status, CacheStatus::Hit);
+    mock.assert();
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anyhow::Result;
+use twapi_v2::{
+    api::{get_2_tweets_search_recent, get_2_users_me, BearerAuthentication},
+    cassette::{Cassette, Mode},
+    testing::FakeTwitter,
+};
+
+// cargo test --test cassette --all-features -- --nocapture
+
+#[tokio::test]
+async fn test_cassette_fake() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("twapi-v2-cassette-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let auth = BearerAuthentication::new("token");
+
+    let fake = FakeTwitter::new();
+    let me = fake.add_user("me", "Me");
+    fake.add_tweet(&me, "hello cassette");
+    let cassette = Cassette::open(&path, fake.clone())?;
+    assert_eq!(cassette.mode(), Mode::Record);
+    get_2_users_me::Api::all()
+        .execute_with(&cassette, &auth)
+        .await?;
+    get_2_tweets_search_recent::Api::new("cassette")
+        .max_results(10)
+        .execute_with(&cassette, &auth)
+        .await?;
+    cassette.save(&path)?;
+
+    // The fake is not called anymore.
+    fake.add_tweet(&me, "another cassette");
+    let cassette = Cassette::open(&path, fake)?;
+    assert_eq!(cassette.mode(), Mode::Replay);
+    let (res, _) = get_2_tweets_search_recent::Api::new("cassette")
+        .max_results(10)
+        .execute_with(&cassette, &auth)
+        .await?;
+    assert_eq!(res.meta.unwrap().result_count, 1);
+    let (res, headers) = get_2_users_me::Api::all()
+        .execute_with(&cassette, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap().id, me);
+    assert_eq!(headers.x_transaction_id, None);
+    assert!(get_2_tweets_search_recent::Api::new("other")
+        .execute_with(&cassette, &auth)
+        .await
+        .is_err());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
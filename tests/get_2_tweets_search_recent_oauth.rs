@@ -9,7 +9,6 @@ use twapi_v2::oauth10a::OAuthAuthentication;
 
 #[tokio::test]
 async fn test_get_2_tweets_search_recent_oauth() -> Result<()> {
-    api::clear_prefix_url();
     let auth = OAuthAuthentication::new(
         std::env::var("CONSUMER_KEY").unwrap_or_default(),
         std::env::var("CONSUMER_SECRET").unwrap_or_default(),
@@ -31,9 +30,7 @@ async fn test_get_2_tweets_search_recent_oauth() -> Result<()> {
 
 #[tokio::test]
 async fn test_get_2_tweets_search_recent_oauth_mock() -> Result<()> {
-    api::clear_prefix_url();
     let mut server = Server::new_async().await;
-    api::setup_prefix_url(&server.url());
 
     let mock = server
         .mock("GET", "/2/tweets/search/recent")
@@ -51,10 +48,7 @@ async fn test_get_2_tweets_search_recent_oauth_mock() -> Result<()> {
         std::env::var("ACCESS_SECRET").unwrap_or_default(),
     );
 
-    let twapi_options = api::TwapiOptions {
-        prefix_url: Some(server.url()),
-        ..Default::default()
-    };
+    let twapi_options = api::TwapiOptions::new().prefix_url(&server.url());
 
     let builder = get_2_tweets_search_recent::Api::open("東京")
         .max_results(10)
@@ -71,7 +65,6 @@ async fn test_get_2_tweets_search_recent_oauth_mock() -> Result<()> {
 #[tokio::test]
 async fn test_get_2_tweets_search_recent_oauth_mock_rate_limet() -> Result<()> {
     let mut server = Server::new_async().await;
-    api::setup_prefix_url(&server.url());
 
     let data = json!({
         "status": "error",
@@ -95,6 +88,7 @@ async fn test_get_2_tweets_search_recent_oauth_mock_rate_limet() -> Result<()> {
     );
     let builder = get_2_tweets_search_recent::Api::open("東京")
         .max_results(10)
+        .twapi_options(api::TwapiOptions::new().prefix_url(&server.url()))
         .build(&auth);
     let res = execute_twitter::<get_2_tweets_search_recent::Response>(builder).await;
     match res {
@@ -107,3 +101,36 @@ async fn test_get_2_tweets_search_recent_oauth_mock_rate_limet() -> Result<()> {
     mock.assert();
     Ok(())
 }
+
+// cargo test test_get_2_tweets_search_recent_oauth_mock_two_clients --all-features -- --nocapture
+
+#[tokio::test]
+async fn test_get_2_tweets_search_recent_oauth_mock_two_clients() -> Result<()> {
+    let mut servers = [Server::new_async().await, Server::new_async().await];
+    let mut mocks = vec![];
+    for (index, server) in servers.iter_mut().enumerate() {
+        let mock = server
+            .mock("GET", "/2/tweets/search/recent")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "origin": index }).to_string())
+            .create_async()
+            .await;
+        mocks.push(mock);
+    }
+
+    let auth = OAuthAuthentication::new("a", "b", "c", "d");
+    for (index, server) in servers.iter().enumerate() {
+        let builder = get_2_tweets_search_recent::Api::open("東京")
+            .twapi_options(api::TwapiOptions::new().prefix_url(&server.url()))
+            .build(&auth);
+        let (res, _headers) =
+            execute_twitter::<get_2_tweets_search_recent::Response>(builder).await?;
+        assert_eq!(res.extra.get("origin"), Some(&json!(index)));
+    }
+    for mock in mocks {
+        mock.assert();
+    }
+    Ok(())
+}
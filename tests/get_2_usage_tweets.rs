@@ -1,11 +1,10 @@
 use anyhow::Result;
-use twapi_v2::api::{self, execute_twitter, get_2_usage_tweets, BearerAuthentication};
+use twapi_v2::api::{execute_twitter, get_2_usage_tweets, BearerAuthentication};
 
 // BEARER_CODE=XXXX cargo test test_get_2_usage_tweets_oauth --all-features -- --nocapture --test-threads=1
 
 #[tokio::test]
 async fn test_get_2_usage_tweets_oauth() -> Result<()> {
-    api::clear_prefix_url();
     let bearer_code = std::env::var("BEARER_CODE").unwrap_or_default();
     let bearer_auth = BearerAuthentication::new(bearer_code);
     let builder = get_2_usage_tweets::Api::all().build(&bearer_auth);
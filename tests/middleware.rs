@@ -0,0 +1,187 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use chrono::Utc;
+use mockito::{Matcher, Server};
+use reqwest::{
+    header::{HeaderName, HeaderValue},
+    Request, Response, StatusCode,
+};
+use serde_json::json;
+use twapi_v2::{
+    api::{get_2_users_me, BearerAuthentication, TwapiOptions},
+    cache::{CacheOptions, MemoryCache},
+    error::Error,
+    middleware::{
+        BoxFuture, CacheResponses, Chain, InjectHeaders, Middleware, Next, Retry, WaitRateLimit,
+    },
+};
+
+// cargo test test_middleware_mock --all-features -- --nocapture
+
+struct Counter(Arc<AtomicUsize>);
+
+impl Middleware for Counter {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, Error>> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        next.run(request)
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_mock() -> Result<()> {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/2/users/me")
+        .match_header("x-test", "1")
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"title": "Service Unavailable", "status": 503}).to_string())
+        .expect(3)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("GET", "/2/users/me")
+        .match_header("x-test", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"data": {"id": "1", "name": "name", "username": "username"}}).to_string())
+        .create_async()
+        .await;
+
+    let auth = BearerAuthentication::new("token");
+    let api = get_2_users_me::Api::new().twapi_options(TwapiOptions {
+        prefix_url: Some(server.url()),
+        ..Default::default()
+    });
+
+    let chain = Chain::new()
+        .with(Counter(Default::default()))
+        .with(Retry::new(2, &[StatusCode::SERVICE_UNAVAILABLE]).delay(Duration::from_millis(1)))
+        .with(InjectHeaders::new().header(
+            HeaderName::from_static("x-test"),
+            HeaderValue::from_static("1"),
+        ));
+    let res = chain
+        .execute::<get_2_users_me::Response>(api.clone().build(&auth))
+        .await;
+    match res {
+        Err(Error::Twitter(err, _, _)) => assert_eq!(err.status, 503),
+        _ => panic!("unexpected {:?}", res),
+    }
+    mock.assert();
+
+    let (response, _) = Chain::new()
+        .execute::<get_2_users_me::Response>(api.build(&auth))
+        .await?;
+    assert_eq!(response.data.unwrap().id, "1");
+    ok.assert();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_retry_error() -> Result<()> {
+    let auth = BearerAuthentication::new("token");
+    // nothing listens on the port, every attempt fails to connect
+    let api = get_2_users_me::Api::new().twapi_options(TwapiOptions {
+        prefix_url: Some("http://127.0.0.1:1".to_owned()),
+        ..Default::default()
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    let chain = Chain::new()
+        .with(Retry::new(2, &[]).delay(Duration::from_millis(1)))
+        .with(Counter(count.clone()));
+    let res = chain
+        .execute::<get_2_users_me::Response>(api.build(&auth))
+        .await;
+    assert!(matches!(res, Err(Error::Reqwest(_))));
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_wait_rate_limit_mock() -> Result<()> {
+    let mut server = Server::new_async().await;
+    let reset = (Utc::now().timestamp() + 2).to_string();
+    let mock = server
+        .mock("GET", "/2/users/me")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-rate-limit-remaining", "0")
+        .with_header("x-rate-limit-reset", &reset)
+        .with_body(json!({"data": {"id": "1", "name": "name", "username": "username"}}).to_string())
+        .expect(3)
+        .create_async()
+        .await;
+
+    let auth = BearerAuthentication::new("token");
+    let api = get_2_users_me::Api::new().twapi_options(TwapiOptions {
+        prefix_url: Some(server.url()),
+        ..Default::default()
+    });
+    // the reset is ahead, the wait is over max_wait
+    let chain = Chain::new().with(WaitRateLimit::new().max_wait(Duration::ZERO));
+    chain
+        .execute::<get_2_users_me::Response>(api.clone().build(&auth))
+        .await?;
+    let res = chain
+        .execute::<get_2_users_me::Response>(api.clone().build(&auth))
+        .await;
+    match res {
+        Err(Error::Other(_, Some(status))) => assert_eq!(status, StatusCode::TOO_MANY_REQUESTS),
+        _ => panic!("unexpected {:?}", res),
+    }
+
+    let chain = Chain::new().with(WaitRateLimit::new().max_wait(Duration::from_secs(10)));
+    chain
+        .execute::<get_2_users_me::Response>(api.clone().build(&auth))
+        .await?;
+    let start = Instant::now();
+    chain
+        .execute::<get_2_users_me::Response>(api.build(&auth))
+        .await?;
+    assert!(start.elapsed() >= Duration::from_millis(500));
+    mock.assert();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_cache_responses_mock() -> Result<()> {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/2/users/me")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"data": {"id": "1", "name": "name", "username": "username"}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = BearerAuthentication::new("token");
+    let api = get_2_users_me::Api::new().twapi_options(TwapiOptions {
+        prefix_url: Some(server.url()),
+        ..Default::default()
+    });
+    let chain = Chain::new().with(CacheResponses::new(
+        MemoryCache::new(10),
+        CacheOptions::default(),
+    ));
+    for _ in 0..2 {
+        let (response, _) = chain
+            .execute::<get_2_users_me::Response>(api.clone().build(&auth))
+            .await?;
+        assert_eq!(response.data.unwrap().id, "1");
+    }
+    mock.assert();
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anyhow::Result;
+use mockito::{Mock, Server, ServerGuard};
+use serde_json::json;
+use twapi_v2::{
+    api::{self, TwapiOptions},
+    oauth10a::OAuthAuthentication,
+    upload::{self, media_category::MediaCategory, MediaApiVersion},
+};
+
+// cargo test test_post_2_media_upload_mock --all-features -- --nocapture --test-threads=1
+
+async fn mock_v2(server: &mut ServerGuard) -> Vec<Mock> {
+    let init = server
+        .mock("POST", "/2/media/upload")
+        .match_body(mockito::Matcher::Regex("INIT".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"data": {"id": "1880028106020515840", "media_key": "3_1880028106020515840", "expires_after_secs": 86400}}).to_string())
+        .create_async()
+        .await;
+    let append = server
+        .mock("POST", "/2/media/upload")
+        .match_body(mockito::Matcher::Regex("APPEND".to_owned()))
+        .with_status(204)
+        .create_async()
+        .await;
+    let finalize = server
+        .mock("POST", "/2/media/upload")
+        .match_body(mockito::Matcher::Regex("FINALIZE".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"data": {"id": "1880028106020515840", "media_key": "3_1880028106020515840", "size": 4}}).to_string())
+        .create_async()
+        .await;
+    vec![init, append, finalize]
+}
+
+fn image(name: &str) -> Result<std::path::PathBuf> {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, [0xff, 0xd8, 0xff, 0xd9])?;
+    Ok(path)
+}
+
+#[tokio::test]
+async fn test_post_2_media_upload_mock() -> Result<()> {
+    let mut server = Server::new_async().await;
+    let mocks = mock_v2(&mut server).await;
+    let twapi_options = TwapiOptions::new()
+        .prefix_url(&server.url())
+        .media_api_version(MediaApiVersion::V2);
+
+    let path = image("twapi_v2_post_2_media_upload.jpg")?;
+    let auth = OAuthAuthentication::new("a", "b", "c", "d");
+    let (response, _headers) = upload::upload_media_with_options(
+        &path,
+        "image/jpeg",
+        Some(MediaCategory::TweetImage),
+        None,
+        &auth,
+        &twapi_options,
+    )
+    .await?;
+    assert_eq!(response.media_id, 1880028106020515840);
+    assert_eq!(response.media_id_string, "1880028106020515840");
+    assert_eq!(response.size, Some(4));
+    for mock in mocks {
+        mock.assert();
+    }
+
+    Ok(())
+}
+
+// upload_media without options talks to the v2 API.
+#[tokio::test]
+async fn test_post_2_media_upload_default_mock() -> Result<()> {
+    let mut server = Server::new_async().await;
+    let mocks = mock_v2(&mut server).await;
+    #[allow(deprecated)]
+    api::setup_prefix_url(&server.url());
+
+    let path = image("twapi_v2_post_2_media_upload_default.jpg")?;
+    let auth = OAuthAuthentication::new("a", "b", "c", "d");
+    let (response, _headers) = upload::upload_media(
+        &path,
+        "image/jpeg",
+        Some(MediaCategory::TweetImage),
+        None,
+        &auth,
+    )
+    .await?;
+    assert_eq!(response.media_id_string, "1880028106020515840");
+    for mock in mocks {
+        mock.assert();
+    }
+
+    Ok(())
+}
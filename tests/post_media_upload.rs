@@ -10,7 +10,6 @@ use twapi_v2::{
 
 #[tokio::test]
 async fn test_post_media_upload() -> Result<()> {
-    upload::clear_prefix_url();
     let auth = OAuthAuthentication::new(
         std::env::var("CONSUMER_KEY").unwrap_or_default(),
         std::env::var("CONSUMER_SECRET").unwrap_or_default(),
@@ -0,0 +1,91 @@
+use proptest::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use twapi_v2::{
+    fixtures::{generate, Arbitrary},
+    responses::*,
+};
+
+// cargo test --test responses --all-features
+
+/// serialize -> deserialize -> serialize gives the same JSON and the same value.
+fn round_trip<T>(bytes: &[u8]) -> Result<(), TestCaseError>
+where
+    T: for<'a> Arbitrary<'a> + Serialize + DeserializeOwned + std::fmt::Debug,
+{
+    let Ok(value) = generate::<T>(bytes) else {
+        return Ok(());
+    };
+    let json = serde_json::to_value(&value).unwrap();
+    let parsed: T = serde_json::from_value(json.clone())
+        .map_err(|err| TestCaseError::fail(format!("{} {}", err, json)))?;
+    prop_assert_eq!(&serde_json::to_value(&parsed).unwrap(), &json);
+    prop_assert_eq!(format!("{:?}", parsed), format!("{:?}", value));
+    Ok(())
+}
+
+macro_rules! round_trip_tests {
+    ($($name:ident: $type:ty,)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(bytes in prop::collection::vec(any::<u8>(), 0..4096)) {
+                    round_trip::<$type>(&bytes)?;
+                }
+            )*
+        }
+    };
+}
+
+round_trip_tests! {
+    test_annotations: annotations::Annotations,
+    test_attachments: attachments::Attachments,
+    test_cashtags: cashtags::Cashtags,
+    test_compliance: compliance::Compliance,
+    test_compliance_tweet: compliance_tweet::ComplianceTweet,
+    test_compliance_user: compliance_user::ComplianceUser,
+    test_context_annotations: context_annotations::ContextAnnotations,
+    test_coordinates: coordinates::Coordinates,
+    test_counts: counts::Counts,
+    test_daily_client_app_usage: daily_client_app_usage::DailyClientAppUsage,
+    test_daily_project_usage: daily_project_usage::DailyProjectUsage,
+    test_description: description::Description,
+    test_dm_events: dm_events::DmEvents,
+    test_edit_controls: edit_controls::EditControls,
+    test_entities: entities::Entities,
+    test_errors: errors::Errors,
+    test_geo: geo::Geo,
+    test_hashtags: hashtags::Hashtags,
+    test_images: images::Images,
+    test_includes: includes::Includes,
+    test_jobs: jobs::Jobs,
+    test_lists: lists::Lists,
+    test_matching_rules: matching_rules::MatchingRules,
+    test_media: media::Media,
+    test_media_public_metrics: media_public_metrics::MediaPublicMetrics,
+    test_memberships: memberships::Memberships,
+    test_mentions: mentions::Mentions,
+    test_meta: meta::Meta,
+    test_meta_count: meta_count::MetaCount,
+    test_non_public_metrics: non_public_metrics::NonPublicMetrics,
+    test_note_tweet: note_tweet::NoteTweet,
+    test_options: options::Options,
+    test_organic_metrics: organic_metrics::OrganicMetrics,
+    test_places: places::Places,
+    test_polls: polls::Polls,
+    test_promoted_metrics: promoted_metrics::PromotedMetrics,
+    test_public_metrics: public_metrics::PublicMetrics,
+    test_referenced_tweets: referenced_tweets::ReferencedTweets,
+    test_spaces: spaces::Spaces,
+    test_streams: streams::Streams,
+    test_summary: summary::Summary,
+    test_topics: topics::Topics,
+    test_trends: trends::Trends,
+    test_tweets: tweets::Tweets,
+    test_urls: urls::Urls,
+    test_usage: usage::Usage,
+    test_user_entities: user_entities::UserEntities,
+    test_user_url: user_url::UserUrl,
+    test_users: users::Users,
+    test_variants: variants::Variants,
+    test_withheld: withheld::Withheld,
+}
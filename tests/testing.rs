@@ -0,0 +1,219 @@
+use anyhow::Result;
+use twapi_v2::{
+    api::{
+        delete_2_tweets_id, delete_2_users_source_user_id_muting_target_user_id, get_2_spaces_id,
+        get_2_tweets_search_recent, get_2_users_id, get_2_users_id_blocking,
+        get_2_users_id_bookmarks, get_2_users_id_followers, get_2_users_id_muting,
+        get_2_users_id_tweets, get_2_users_me, post_2_lists, post_2_lists_id_members,
+        post_2_tweets, post_2_users_id_blocking, post_2_users_id_bookmarks,
+        post_2_users_id_following, post_2_users_id_muting, BearerAuthentication,
+    },
+    error::Error,
+    request::Method,
+    reqwest::StatusCode,
+    testing::FakeTwitter,
+};
+
+// cargo test --test testing --all-features -- --nocapture
+
+#[tokio::test]
+async fn test_testing_execute_with() -> Result<()> {
+    let fake = FakeTwitter::new();
+    let me = fake.add_user("me", "Me");
+    let other = fake.add_user("other", "Other");
+    fake.add_tweet(&other, "hello rust");
+    fake.add_tweet(&other, "hello world");
+    let auth = BearerAuthentication::new("token");
+
+    let (res, headers) = get_2_users_me::Api::all()
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap().id, me);
+    assert_eq!(headers.x_rate_limit_limit, Some(900));
+    assert_eq!(headers.x_rate_limit_remaining, Some(899));
+
+    let (res, _) = get_2_users_id::Api::new("999")
+        .execute_with(&fake, &auth)
+        .await?;
+    assert!(res.data.is_none());
+    assert_eq!(
+        res.errors.unwrap()[0].title.as_deref(),
+        Some("Not Found Error")
+    );
+
+    let body = post_2_tweets::Body {
+        text: Some("hello from me".to_owned()),
+        ..Default::default()
+    };
+    let (res, _) = post_2_tweets::Api::new(body)
+        .execute_with(&fake, &auth)
+        .await?;
+    let tweet_id = res.data.unwrap().id.unwrap();
+
+    let (res, _) = get_2_tweets_search_recent::Api::new("hello from:other")
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.meta.unwrap().result_count, 2);
+
+    let (res, _) = get_2_users_id_tweets::Api::new(&other)
+        .max_results(1)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap()[0].text, "hello world");
+
+    let body = post_2_users_id_following::Body {
+        target_user_id: other.clone(),
+    };
+    let (res, _) = post_2_users_id_following::Api::new(&me, body)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap().following, Some(true));
+    let (res, _) = get_2_users_id_followers::Api::new(&other)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap()[0].id, me);
+
+    let body = post_2_lists::Body {
+        name: "list".to_owned(),
+        ..Default::default()
+    };
+    let (res, _) = post_2_lists::Api::new(body)
+        .execute_with(&fake, &auth)
+        .await?;
+    let list_id = res.data.unwrap().id.unwrap();
+    let body = post_2_lists_id_members::Body { user_id: other };
+    let (res, _) = post_2_lists_id_members::Api::new(&list_id, body)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap().is_member, Some(true));
+
+    let (res, _) = delete_2_tweets_id::Api::new(&tweet_id)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap().deleted, Some(true));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_testing_serve() -> Result<()> {
+    let fake = FakeTwitter::new();
+    let me = fake.add_user("me", "Me");
+    let server = fake.serve().await?;
+    let auth = BearerAuthentication::new("token");
+
+    let body = post_2_tweets::Body {
+        text: Some("hello server".to_owned()),
+        ..Default::default()
+    };
+    post_2_tweets::Api::new(body)
+        .twapi_options(server.twapi_options())
+        .execute(&auth)
+        .await?;
+    let (res, headers) = get_2_tweets_search_recent::Api::new("SERVER")
+        .twapi_options(server.twapi_options())
+        .execute(&auth)
+        .await?;
+    let data = res.data.unwrap();
+    assert_eq!(data[0].author_id.as_deref(), Some(me.as_str()));
+    assert!(headers.x_transaction_id.is_some());
+
+    fake.rate_limit(Method::Get, "/2/users/:id", 1);
+    get_2_users_id::Api::new(&me)
+        .twapi_options(server.twapi_options())
+        .execute(&auth)
+        .await?;
+    let res = get_2_users_id::Api::new(&me)
+        .twapi_options(server.twapi_options())
+        .execute(&auth)
+        .await;
+    match res {
+        Err(Error::Twitter(err, _, headers)) => {
+            assert_eq!(err.status_code, StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(headers.x_rate_limit_remaining, Some(0));
+        }
+        _ => panic!("expected 429"),
+    }
+
+    fake.inject_error(Method::Get, "/2/users/me", StatusCode::SERVICE_UNAVAILABLE);
+    let res = get_2_users_me::Api::new()
+        .twapi_options(server.twapi_options())
+        .execute(&auth)
+        .await;
+    assert!(matches!(res, Err(Error::Twitter(err, _, _)) if err.status == 503));
+    get_2_users_me::Api::new()
+        .twapi_options(server.twapi_options())
+        .execute(&auth)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_testing_relations() -> Result<()> {
+    let fake = FakeTwitter::new();
+    let me = fake.add_user("me", "Me");
+    let other = fake.add_user("other", "Other");
+    let tweet_id = fake.add_tweet(&other, "hello");
+    let auth = BearerAuthentication::new("token");
+
+    let body = post_2_users_id_blocking::Body {
+        target_user_id: other.clone(),
+    };
+    let (res, _) = post_2_users_id_blocking::Api::new(&me, body)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap().blocking, Some(true));
+    let (res, _) = get_2_users_id_blocking::Api::new(&me)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap()[0].id, other);
+
+    let body = post_2_users_id_muting::Body {
+        target_user_id: other.clone(),
+    };
+    post_2_users_id_muting::Api::new(&me, body)
+        .execute_with(&fake, &auth)
+        .await?;
+    let (res, _) = delete_2_users_source_user_id_muting_target_user_id::Api::new(&me, &other)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap().muting, Some(false));
+    let (res, _) = get_2_users_id_muting::Api::new(&me)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert!(res.data.is_none());
+
+    let body = post_2_users_id_bookmarks::Body {
+        tweet_id: tweet_id.clone(),
+    };
+    post_2_users_id_bookmarks::Api::new(&me, body)
+        .execute_with(&fake, &auth)
+        .await?;
+    let (res, _) = get_2_users_id_bookmarks::Api::new(&me)
+        .execute_with(&fake, &auth)
+        .await?;
+    assert_eq!(res.data.unwrap()[0].id, tweet_id);
+
+    let res = get_2_spaces_id::Api::new("1")
+        .execute_with(&fake, &auth)
+        .await;
+    assert!(matches!(res, Err(Error::Twitter(err, _, _)) if err.status == 501));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_testing_serve_form() -> Result<()> {
+    let fake = FakeTwitter::new();
+    fake.add_user("me", "Me");
+    let server = fake.serve().await?;
+
+    let res = twapi_v2::reqwest::Client::new()
+        .post(format!("{}/2/tweets", server.url()))
+        .bearer_auth("token")
+        .form(&[("text", "hello form")])
+        .send()
+        .await?;
+    assert_eq!(res.status(), StatusCode::OK);
+    let json: serde_json::Value = res.json().await?;
+    assert_eq!(json["data"]["text"], "hello form");
+    Ok(())
+}
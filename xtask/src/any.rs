@@ -0,0 +1,117 @@
+//! Tagged enum over every endpoint, `src/endpoint/any.rs`.
+
+use crate::{model::ApiDef, names::ucc};
+
+pub fn generate(apis: &[(String, ApiDef)]) -> String {
+    let variants: Vec<(&str, String)> = apis
+        .iter()
+        .map(|(name, _)| (name.as_str(), ucc(name)))
+        .collect();
+    // `Self::Variant(binding) => body(module name),`
+    let arms = |binding: &str, body: &dyn Fn(&str) -> String| -> String {
+        variants
+            .iter()
+            .map(|(name, variant)| {
+                format!(
+                    "            Self::{}({}) => {},\n",
+                    variant,
+                    binding,
+                    body(name)
+                )
+            })
+            .collect()
+    };
+
+    let mut code = String::from(
+        "use serde::{Deserialize, Serialize};
+use super::Endpoint;
+use crate::{api::{self, Authentication}, error::Error, headers::Headers, request::{execute_request, HttpClient, Method, TwapiRequest}};
+
+/// Every generated `Api`, tagged by the module name.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = \"endpoint\")]
+pub enum AnyEndpoint {
+",
+    );
+    for (name, variant) in variants.iter() {
+        code.push_str(&format!(
+            "    #[serde(rename = \"{}\")]\n    {}(api::{}::Api),\n",
+            name, variant, name
+        ));
+    }
+    code.push_str("}\n\nimpl AnyEndpoint {\n");
+    code.push_str(&format!(
+        "    /// Module name, e.g. `get_2_users_id_followers`.
+    pub fn name(&self) -> &'static str {{
+        match self {{
+{}        }}
+    }}
+
+    pub fn method(&self) -> Method {{
+        match self {{
+{}        }}
+    }}
+
+    pub fn template(&self) -> &'static str {{
+        match self {{
+{}        }}
+    }}
+
+    pub fn request(self) -> TwapiRequest {{
+        match self {{
+{}        }}
+    }}
+",
+        arms("_", &|name| format!("\"{}\"", name)),
+        arms("_", &|name| format!("api::{}::Api::METHOD", name)),
+        arms("_", &|name| format!("api::{}::Api::TEMPLATE", name)),
+        arms("it", &|_| "it.request()".to_owned()),
+    ));
+    code.push_str(EXECUTE);
+    code.push_str("}\n");
+    for (name, variant) in variants.iter() {
+        code.push_str(&format!(
+            "
+impl From<api::{}::Api> for AnyEndpoint {{
+    fn from(value: api::{}::Api) -> Self {{
+        Self::{}(value)
+    }}
+}}
+",
+            name, name, variant
+        ));
+    }
+    code
+}
+
+const EXECUTE: &str = r#"
+    /// Deserialize the value by `Endpoint::Response` of the Api.
+    #[cfg(feature = "reqwest")]
+    pub async fn execute(self, authentication: &impl Authentication) -> Result<(serde_json::Value, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        api::execute_twitter(request.build(authentication)).await
+    }
+
+    pub async fn execute_with(self, client: &impl HttpClient, authentication: &impl Authentication) -> Result<(serde_json::Value, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        execute_request(client, request.authorize(authentication)).await
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(self, authentication: &impl Authentication) -> Result<(serde_json::Value, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        crate::blocking::execute_twitter(request.build(authentication))
+    }
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_golden, load};
+
+    #[test]
+    fn it_generate() {
+        let apis = load::<ApiDef>("api").unwrap();
+        assert_golden("src/endpoint/any.rs", &generate(&apis));
+    }
+}
@@ -0,0 +1,586 @@
+//! Endpoints, `src/api/*.rs`.
+
+use crate::{
+    fields::expansions,
+    model::{split_values, ApiDef, BodyProp, Param},
+    names::{field, member, name, singularize, ucc},
+    responses::{self, use_responses, Context},
+};
+
+const MODULES_BEGIN: &str = "// Generated from maker/api/*.yaml by `cargo xtask codegen`.\n";
+const MODULES_END: &str = "// End of generated modules.\n";
+
+/// `src/api.rs` with the `pub mod` lines between the markers replaced, the rest is hand-written.
+pub fn modules(source: &str, apis: &[(String, ApiDef)]) -> Result<String, String> {
+    let (Some(begin), Some(end)) = (source.find(MODULES_BEGIN), source.find(MODULES_END)) else {
+        return Err("src/api.rs: generated modules markers not found".to_owned());
+    };
+    let mut code = source[..begin + MODULES_BEGIN.len()].to_owned();
+    for (name, _) in apis {
+        code.push_str(&format!("pub mod {};\n", name));
+    }
+    code.push_str(&source[end..]);
+    Ok(code)
+}
+
+pub fn generate(def: &ApiDef) -> String {
+    let mut context = Context {
+        date: def.queries.iter().any(|it| it.kind == "date"),
+        ..Default::default()
+    };
+    let paths: Vec<Param> = def
+        .paths
+        .iter()
+        .cloned()
+        .map(|it| Param {
+            required: true,
+            ..it
+        })
+        .collect();
+    let queries = &def.queries;
+    let form = &def.form;
+    let enum_flag = queries.iter().any(|it| it.kind == "enum");
+    let fields: Vec<&Param> = queries.iter().filter(|it| is_fields(&it.name)).collect();
+
+    let expansion_list: Vec<String> = queries
+        .iter()
+        .chain(form.iter())
+        .filter(|it| (it.kind == "enum" || it.kind == "enum_single") && !is_fields(&it.name))
+        .map(|it| expansion(&it.name, it.value.as_deref(), &it.kind))
+        .collect();
+    let mut bodies = vec![];
+    if let Some(body) = &def.body {
+        make_body(body, &mut bodies, None);
+    }
+
+    let mut response_list = vec![];
+    let mut refs = vec![];
+    if let Some(response) = &def.response {
+        if !response.properties.is_empty() {
+            let rendered = responses::render("response", &response.properties, &mut context);
+            response_list.push(rendered.code);
+            refs = rendered.refs;
+            for (key, value) in rendered.inner.iter() {
+                response_list.push(responses::render(key, &value.properties, &mut context).code);
+            }
+        }
+    }
+
+    let mut code = String::new();
+    if context.date {
+        code.push_str("use chrono::prelude::*;\n");
+    }
+    if enum_flag {
+        code.push_str("use itertools::Itertools;\nuse std::collections::HashSet;\n");
+    }
+    code.push_str("use serde::{Serialize, Deserialize};\n");
+    if !fields.is_empty() {
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|it| format!("{}::{}", field(&it.name), ucc(&it.name)))
+            .collect();
+        code.push_str(&format!("use crate::fields::{{{}}};\n", fields.join(", ")));
+    }
+    if !refs.is_empty() {
+        code.push_str(&use_responses(&refs));
+    }
+    code.push_str("use crate::{endpoint::Endpoint, error::Error, headers::Headers, api::{Authentication, make_url, TwapiOptions}, request::{execute_request, HttpClient, Method, TwapiRequest}};\n\n");
+    code.push_str(&format!("const URL: &str = \"{}\";\n\n", def.url));
+    code.push_str(&bodies.join("\n"));
+    code.push('\n');
+    code.push_str(&expansion_list.join("\n"));
+    code.push('\n');
+    code.push_str(&api_struct(&paths, queries, form, !bodies.is_empty()));
+    code.push_str("\nimpl Api {\n");
+    code.push_str(&api_new(&paths, queries, form, !bodies.is_empty()));
+    code.push_str(&setter(queries));
+    code.push_str(&request(def, &paths, queries, form, !bodies.is_empty()));
+    code.push_str(EXECUTE);
+    code.push_str("}\n\n");
+    code.push_str(&endpoint(def, &paths));
+    code.push_str(&response_list.join("\n"));
+    code
+}
+
+const EXECUTE: &str = "
+    #[cfg(feature = \"reqwest\")]
+    pub fn build(self, authentication: &impl Authentication) -> reqwest::RequestBuilder {
+        self.request().build(authentication)
+    }
+
+    #[cfg(feature = \"reqwest\")]
+    pub async fn execute(self, authentication: &impl Authentication) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        crate::api::execute_twitter(request.build(authentication)).await
+    }
+
+    pub async fn execute_with(self, client: &impl HttpClient, authentication: &impl Authentication) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        execute_request(client, request.authorize(authentication)).await
+    }
+
+    #[cfg(feature = \"blocking\")]
+    pub fn execute_blocking(self, authentication: &impl Authentication) -> Result<(Response, Headers), Error> {
+        let request = self.request().validate(authentication)?;
+        crate::blocking::execute_twitter(request.build(authentication))
+    }
+";
+
+/// `impl Endpoint for Api`
+fn endpoint(def: &ApiDef, paths: &[Param]) -> String {
+    let pagination = def
+        .queries
+        .iter()
+        .map(|it| it.name.as_str())
+        .find(|it| *it == "pagination_token" || *it == "next_token");
+    // The next token is in `meta`.
+    let pagination = pagination.filter(|_| {
+        def.response
+            .as_ref()
+            .and_then(|it| it.properties.get("meta"))
+            .is_some()
+    });
+
+    let mut code = String::from("impl Endpoint for Api {\n    type Response = Response;\n");
+    code.push_str(&format!(
+        "    const METHOD: Method = Method::{};\n    const TEMPLATE: &'static str = URL;\n",
+        capitalize(&def.method)
+    ));
+    if let Some(pagination) = pagination {
+        code.push_str(&format!(
+            "    const PAGINATION: Option<&'static str> = Some(\"{}\");\n",
+            pagination
+        ));
+    }
+    let params: Vec<String> = paths
+        .iter()
+        .map(|it| {
+            format!(
+                "(\"{}\", self.{}.clone())",
+                field(&it.name),
+                field(&it.name)
+            )
+        })
+        .collect();
+    code.push_str(&format!(
+        "
+    fn path_params(&self) -> Vec<(&'static str, String)> {{
+        vec![{}]
+    }}
+
+    fn request(self) -> TwapiRequest {{
+        Api::request(self)
+    }}
+",
+        params.join(", ")
+    ));
+    if let Some(pagination) = pagination {
+        code.push_str(&format!(
+            "
+    fn next_token(response: &Response) -> Option<String> {{
+        response.meta.as_ref().and_then(|it| it.next_token.clone())
+    }}
+
+    fn with_pagination_token(self, token: &str) -> Self {{
+        self.{}(token)
+    }}
+",
+            pagination
+        ));
+    }
+    code.push_str("}\n\n");
+    code
+}
+
+fn is_fields(name: &str) -> bool {
+    name.ends_with(".fields")
+}
+
+fn expansion(param_name: &str, value: Option<&str>, kind: &str) -> String {
+    expansions(
+        &ucc(&field(param_name)),
+        &split_values(value.unwrap_or_default()),
+        kind == "enum",
+        &[],
+    )
+}
+
+/// Nested objects and enums come before the struct using them.
+fn make_body(body: &BodyProp, results: &mut Vec<String>, array_name: Option<String>) {
+    for it in body.properties.iter() {
+        match it.kind.as_str() {
+            "object" => make_body(it, results, None),
+            "array" => {
+                if let Some(items) = it.items.as_ref().filter(|items| items.kind == "object") {
+                    let name = singularize(it.name.as_deref().unwrap_or_default());
+                    make_body(items, results, Some(name));
+                }
+            }
+            "enum_single" => results.push(expansion(
+                it.name.as_deref().unwrap_or_default(),
+                it.value.as_deref(),
+                &it.kind,
+            )),
+            _ => {}
+        }
+    }
+    let struct_name = array_name
+        .or_else(|| body.name.clone())
+        .unwrap_or_else(|| "body".to_owned());
+    let mut code = format!(
+        "#[derive(Serialize, Deserialize, Debug, Default, Clone)]\npub struct {} {{\n",
+        ucc(&struct_name)
+    );
+    for it in body.properties.iter() {
+        if !it.required {
+            code.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+        }
+        code.push_str(&format!(
+            "    pub {}: {},\n",
+            name(it.name.as_deref().unwrap_or_default()),
+            body_type(it)
+        ));
+    }
+    code.push_str("}\n");
+    results.push(code);
+}
+
+fn body_type(src: &BodyProp) -> String {
+    let src_name = src.name.as_deref().unwrap_or_default();
+    let res = match src.kind.as_str() {
+        "enum_single" | "object" => ucc(src_name),
+        "bool" => "bool".to_owned(),
+        "array" => {
+            if src.items.as_ref().map(|it| it.kind == "object") == Some(true) {
+                format!("Vec<{}>", ucc(&singularize(src_name)))
+            } else {
+                let items = src.items.as_ref().map(|it| it.kind.as_str());
+                format!("Vec<{}>", simple_type(items.unwrap_or_default()))
+            }
+        }
+        _ => "String".to_owned(),
+    };
+    if src.required {
+        res
+    } else {
+        format!("Option<{}>", res)
+    }
+}
+
+fn simple_type(kind: &str) -> &'static str {
+    match kind {
+        "integer" => "usize",
+        _ => "String",
+    }
+}
+
+fn param_type(it: &Param) -> String {
+    match it.kind.as_str() {
+        "integer" => "usize".to_owned(),
+        "enum" => format!("HashSet<{}>", ucc(&it.name)),
+        "enum_single" => ucc(&it.name),
+        "date" => "DateTime<Utc>".to_owned(),
+        "bool" => "bool".to_owned(),
+        _ => "String".to_owned(),
+    }
+}
+
+/// Argument type of `new` and setters, `&str` instead of `String`.
+fn argument_type(it: &Param) -> String {
+    match it.kind.as_str() {
+        "integer" | "enum" | "enum_single" | "date" | "bool" => param_type(it),
+        _ => "&str".to_owned(),
+    }
+}
+
+fn has_alter(it: &Param) -> bool {
+    it.alter.as_deref().map(|it| !it.is_empty()) == Some(true)
+}
+
+fn has_constant(it: &Param) -> bool {
+    it.constant.as_deref().map(|it| !it.is_empty()) == Some(true)
+}
+
+fn api_struct(paths: &[Param], queries: &[Param], form: &[Param], body: bool) -> String {
+    let mut code = String::from(
+        "#[derive(Serialize, Deserialize, Debug, Clone, Default)]\npub struct Api {\n",
+    );
+    let form = form.iter().filter(|it| !has_alter(it) && !has_constant(it));
+    for it in paths.iter().chain(queries.iter()).chain(form) {
+        let type_name = param_type(it);
+        let type_name = if it.required {
+            type_name
+        } else {
+            code.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+            format!("Option<{}>", type_name)
+        };
+        code.push_str(&format!("    {}: {},\n", member(&it.name), type_name));
+    }
+    if body {
+        code.push_str("    body: Body,\n");
+    }
+    // Kept with the request, a queued Api is sent to the same prefix url with the same validator.
+    code.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n    twapi_options: Option<TwapiOptions>,\n}\n");
+    code
+}
+
+fn self_required<'a>(
+    paths: &'a [Param],
+    queries: &'a [Param],
+    form: &'a [Param],
+) -> Vec<&'a Param> {
+    queries
+        .iter()
+        .chain(form.iter().filter(|it| !has_alter(it)))
+        .filter(|it| it.required)
+        .chain(paths.iter())
+        .collect()
+}
+
+fn api_new(paths: &[Param], queries: &[Param], form: &[Param], body: bool) -> String {
+    let self_required = self_required(paths, queries, form);
+    let is_open_fields = |it: &Param| it.name == "media.fields" || it.name == "tweet.fields";
+    let open_flag = queries.iter().any(is_open_fields);
+    let enum_list: Vec<&Param> = queries.iter().filter(|it| it.kind == "enum").collect();
+    let max_list: Vec<&Param> = queries.iter().filter(|it| it.max.is_some()).collect();
+
+    let mut arguments: Vec<String> = self_required
+        .iter()
+        .map(|it| format!("{}: {}", member(&it.name), argument_type(it)))
+        .collect();
+    if body {
+        arguments.push("body: Body".to_owned());
+    }
+    let required_values: String = self_required
+        .iter()
+        .map(|it| {
+            if it.kind == "string" {
+                format!(
+                    "        {}: {}.to_owned(),\n",
+                    member(&it.name),
+                    field(&it.name)
+                )
+            } else {
+                format!("        {},\n", member(&it.name))
+            }
+        })
+        .collect::<String>()
+        + if body { "        body,\n" } else { "" };
+    let constructor = |fn_name: &str, open: Option<bool>| {
+        let mut code = format!(
+            "    pub fn {}({}) -> Self {{\n        Self {{\n{}",
+            fn_name,
+            arguments.join(", "),
+            required_values
+        );
+        if let Some(open) = open {
+            for it in enum_list.iter() {
+                let value = if open && is_open_fields(it) {
+                    format!("{}::open()", ucc(&it.name))
+                } else if it.name == "tweet.fields" {
+                    format!("{}::organic()", ucc(&it.name))
+                } else {
+                    format!("{}::all()", ucc(&it.name))
+                };
+                code.push_str(&format!(
+                    "            {}: {},\n",
+                    member(&it.name),
+                    optional(value, it.required)
+                ));
+            }
+            for it in max_list.iter() {
+                code.push_str(&format!(
+                    "            {}: {},\n",
+                    member(&it.name),
+                    optional(it.max.unwrap_or_default().to_string(), it.required)
+                ));
+            }
+        }
+        code.push_str("            ..Default::default()\n        }\n    }\n");
+        code
+    };
+
+    let mut code = constructor("new", None);
+    if !enum_list.is_empty() {
+        code.push('\n');
+        code.push_str(&constructor("all", Some(false)));
+    }
+    if open_flag {
+        code.push('\n');
+        code.push_str(&constructor("open", Some(true)));
+    }
+    code
+}
+
+fn optional(value: String, required: bool) -> String {
+    if required {
+        value
+    } else {
+        format!("Some({})", value)
+    }
+}
+
+fn setter(queries: &[Param]) -> String {
+    let mut code = String::new();
+    for it in queries.iter().filter(|it| !it.required) {
+        code.push_str(&format!(
+            "\n    pub fn {}(mut self, value: {}) -> Self {{\n        self.{} = Some(value{});\n        self\n    }}\n",
+            field(&it.name),
+            argument_type(it),
+            field(&it.name),
+            if it.kind == "string" { ".to_owned()" } else { "" }
+        ));
+    }
+    code.push_str(
+        "\n    pub fn twapi_options(mut self, value: TwapiOptions) -> Self {\n        self.twapi_options = Some(value);\n        self\n    }\n",
+    );
+    code
+}
+
+fn query_value(it: &Param) -> &'static str {
+    if has_alter(it) {
+        return ".clone()";
+    }
+    match it.kind.as_str() {
+        "enum" => ".iter().join(\",\")",
+        "string" => "",
+        "date" => ".format(\"%Y-%m-%dT%H%M%SZ\").to_string()",
+        _ => ".to_string()",
+    }
+}
+
+fn request(def: &ApiDef, paths: &[Param], queries: &[Param], form: &[Param], body: bool) -> String {
+    let mut code = String::from("\n    pub fn request(self) -> TwapiRequest {\n");
+    if !form.is_empty() {
+        code.push_str("        let form_parameters = vec![\n");
+        for it in form {
+            if let Some(constant) = it.constant.as_deref().filter(|it| !it.is_empty()) {
+                code.push_str(&format!(
+                    "            (\"{}\", \"{}\".to_owned()),\n",
+                    it.name, constant
+                ));
+            } else {
+                let source = it
+                    .alter
+                    .as_deref()
+                    .filter(|it| !it.is_empty())
+                    .unwrap_or(&it.name);
+                code.push_str(&format!(
+                    "            (\"{}\", self.{}{}),\n",
+                    it.name,
+                    member(source),
+                    query_value(it)
+                ));
+            }
+        }
+        code.push_str("        ];\n");
+    }
+    if !queries.is_empty() {
+        code.push_str("        let mut query_parameters = vec![];\n");
+    }
+    for it in queries.iter().filter(|it| it.required) {
+        code.push_str(&format!(
+            "        query_parameters.push((\"{}\", self.{}{}));\n",
+            it.name,
+            member(&it.name),
+            query_value(it)
+        ));
+    }
+    for it in queries.iter().filter(|it| !it.required) {
+        let member = member(&it.name);
+        code.push_str(&format!(
+            "        if let Some({}) = self.{} {{\n            query_parameters.push((\"{}\", {}{}));\n        }}\n",
+            member,
+            member,
+            it.name,
+            member,
+            query_value(it)
+        ));
+    }
+    let url = if paths.is_empty() {
+        "URL".to_owned()
+    } else {
+        let replaces: String = paths
+            .iter()
+            .map(|it| {
+                format!(
+                    ".replace(\":{}\", &self.{})",
+                    field(&it.name),
+                    field(&it.name)
+                )
+            })
+            .collect();
+        format!("&URL{}", replaces)
+    };
+    code.push_str(&format!(
+        "        let url = make_url(&self.twapi_options, {});\n",
+        url
+    ));
+    code.push_str(&format!(
+        "        TwapiRequest::new(Method::{}, &url)",
+        capitalize(&def.method)
+    ));
+    if !queries.is_empty() {
+        code.push_str("\n            .query(query_parameters)");
+    }
+    if !form.is_empty() {
+        code.push_str("\n            .form(form_parameters)");
+    }
+    if body {
+        code.push_str("\n            .json(&self.body)");
+    }
+    code.push_str("\n            .twapi_options(&self.twapi_options)\n    }\n");
+    code
+}
+
+fn capitalize(src: &str) -> String {
+    let mut chars = src.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_golden, load};
+
+    #[test]
+    fn it_modules() {
+        let source = format!(
+            "use a;\n\n{}pub mod old;\n{}\npub mod hand;\n",
+            MODULES_BEGIN, MODULES_END
+        );
+        let apis = vec![
+            ("get_2_a".to_owned(), ApiDef::default()),
+            ("post_2_b".to_owned(), ApiDef::default()),
+        ];
+        assert_eq!(
+            modules(&source, &apis).unwrap(),
+            format!(
+                "use a;\n\n{}pub mod get_2_a;\npub mod post_2_b;\n{}\npub mod hand;\n",
+                MODULES_BEGIN, MODULES_END
+            )
+        );
+        assert!(modules("use a;\n", &apis).is_err());
+    }
+
+    #[test]
+    fn it_generate() {
+        // paginated GET with expansions, JSON body, path parameters only
+        let names = [
+            "get_2_users_id_followers",
+            "post_2_tweets",
+            "delete_2_tweets_id",
+        ];
+        for (name, def) in load::<ApiDef>("api").unwrap() {
+            if names.contains(&name.as_str()) {
+                assert_golden(&format!("src/api/{}.rs", name), &generate(&def));
+            }
+        }
+    }
+}
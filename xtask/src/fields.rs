@@ -0,0 +1,89 @@
+//! Enums of fields and expansions, `src/fields/*.rs` and the enums of `src/api/*.rs`.
+
+use crate::{
+    model::{split_values, FieldsDef},
+    names::{field, ucc},
+};
+
+/// `src/fields/{name}_fields.rs`
+pub fn generate(file_name: &str, def: &FieldsDef) -> String {
+    let class_name = format!("{}Fields", ucc(&field(file_name)));
+    let mut code =
+        String::from("use serde::{Serialize, Deserialize};\nuse std::collections::HashSet;\n\n");
+    code.push_str(&expansions(
+        &class_name,
+        &split_values(&def.value),
+        true,
+        &def.methods
+            .iter()
+            .map(|it| (it.name.as_str(), split_values(&it.value)))
+            .collect::<Vec<_>>(),
+    ));
+    code
+}
+
+/// `all` and the named methods return sets of the values.
+pub fn expansions(
+    class_name: &str,
+    values: &[&str],
+    all: bool,
+    methods: &[(&str, Vec<&str>)],
+) -> String {
+    let mut code =
+        String::from("#[derive(Serialize, Deserialize, Debug, Eq, Hash, PartialEq, Clone)]\n");
+    code.push_str(&format!("pub enum {} {{\n", class_name));
+    for value in values {
+        code.push_str(&format!(
+            "    #[serde(rename = \"{}\")]\n    {},\n",
+            value,
+            ucc(value)
+        ));
+    }
+    code.push_str("}\n\n");
+    if all {
+        code.push_str(&format!("impl {} {{\n", class_name));
+        let all = [("all", values.to_vec())];
+        for (index, (name, values)) in all.iter().chain(methods.iter()).enumerate() {
+            if index > 0 {
+                code.push('\n');
+            }
+            code.push_str(&format!(
+                "    pub fn {}() -> HashSet<Self> {{\n        let mut result = HashSet::new();\n",
+                name
+            ));
+            for value in values {
+                code.push_str(&format!("        result.insert(Self::{});\n", ucc(value)));
+            }
+            code.push_str("        result\n    }\n");
+        }
+        code.push_str("}\n\n");
+    }
+    code.push_str(&display(class_name, values));
+    code.push('\n');
+    code.push_str(&default(class_name, values));
+    code
+}
+
+pub fn display(class_name: &str, values: &[&str]) -> String {
+    let mut code = format!(
+        "impl std::fmt::Display for {} {{\n    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{\n        match self {{\n",
+        class_name
+    );
+    for value in values {
+        code.push_str(&format!(
+            "            Self::{} => write!(f, \"{}\"),\n",
+            ucc(value),
+            value
+        ));
+    }
+    code.push_str("        }\n    }\n}\n");
+    code
+}
+
+pub fn default(class_name: &str, values: &[&str]) -> String {
+    format!(
+        "impl Default for {} {{\n    fn default() -> Self {{ Self::{} }}\n}}\n",
+        class_name,
+        ucc(values.first().copied().unwrap_or_default())
+    )
+}
@@ -0,0 +1,249 @@
+//! Development tasks.
+//!
+//! `cargo xtask codegen` generates `src/api`, the module list of `src/api.rs`, `src/fields`, `src/responses`, `src/select`, `src/endpoint/any.rs` and `src/validation/endpoints.rs` from the YAML files under `maker/`.
+//! `cargo xtask codegen --check` fails when the generated files are out of date.
+//! Both fail when an endpoint of `maker/api/*.yaml` has no `rate_limit`.
+//! `cargo xtask openapi <openapi.json> [--report <file>] [--write]` reports the drift against Twitter's OpenAPI specification,
+//! and writes `maker/api/*.yaml` of the added endpoints with `--write`.
+
+mod any;
+mod api;
+mod fields;
+mod model;
+mod names;
+mod openapi;
+mod responses;
+mod select;
+mod validation;
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+};
+
+use model::{ApiDef, FieldsDef};
+use serde::de::DeserializeOwned;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const USAGE: &str = "Usage: cargo xtask codegen [--check]
+       cargo xtask openapi <openapi.json> [--report <file>] [--write]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(|it| it.as_str()) {
+        Some("codegen") => codegen(args.iter().any(|it| it == "--check")),
+        Some("openapi") => openapi(&args[1..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(|it| it.to_path_buf())
+        .unwrap_or_default()
+}
+
+/// Returns false when `check` and some files differ.
+fn codegen(check: bool) -> Result<bool> {
+    let root = root();
+    let mut files = vec![];
+    let api_defs: Vec<(String, ApiDef)> = load("api")?;
+    let missing = validation::missing_rate_limits(&api_defs);
+    if !missing.is_empty() {
+        return Err(format!(
+            "rate_limit is missing in maker/api/{{{}}}.yaml",
+            missing.join(",")
+        )
+        .into());
+    }
+    for (name, def) in api_defs.iter() {
+        files.push((format!("src/api/{}.rs", name), api::generate(def)));
+    }
+    let mut response_defs = BTreeMap::new();
+    for (name, def) in load("responses")? {
+        files.push((
+            format!("src/responses/{}.rs", name),
+            responses::generate(&name, &def),
+        ));
+        response_defs.insert(name, def);
+    }
+    let mut fields_names = vec![];
+    for (name, def) in load::<FieldsDef>("fields")? {
+        let values = model::split_values(&def.value);
+        let optional = def.optional.as_deref().map(model::split_values);
+        if let Some(it) = optional
+            .unwrap_or_default()
+            .into_iter()
+            .find(|it| !values.contains(it))
+        {
+            return Err(format!(
+                "maker/fields/{}.yaml: optional {} is not in value",
+                name, it
+            )
+            .into());
+        }
+        fields_names.push(name.clone());
+        files.push((
+            format!("src/fields/{}_fields.rs", name),
+            fields::generate(&name, &def),
+        ));
+        // `tweet` -> `tweets`, `media` -> `media`
+        let response = [name.clone(), format!("{}s", name)]
+            .into_iter()
+            .find_map(|it| response_defs.get_key_value(&it));
+        if let Some((response_name, response)) = response {
+            let code = select::generate(&name, &def, response_name, response);
+            if !code.is_empty() {
+                files.push((format!("src/select/{}.rs", name), code));
+            }
+        }
+    }
+    let source = std::fs::read_to_string(root.join("src/api.rs"))?;
+    files.push(("src/api.rs".to_owned(), api::modules(&source, &api_defs)?));
+    files.push(("src/endpoint/any.rs".to_owned(), any::generate(&api_defs)));
+    files.push((
+        "src/validation/endpoints.rs".to_owned(),
+        validation::generate(&api_defs, &fields_names),
+    ));
+
+    let mut fresh = true;
+    for (file, code) in files {
+        let code = rustfmt(&code).map_err(|err| format!("{}: {}", file, err))?;
+        let path = root.join(&file);
+        if std::fs::read_to_string(&path).ok().as_deref() == Some(code.as_str()) {
+            continue;
+        }
+        if check {
+            println!("out of date: {}", file);
+            fresh = false;
+        } else {
+            println!("generated: {}", file);
+            std::fs::write(path, code)?;
+        }
+    }
+    Ok(fresh)
+}
+
+/// The drift is reported, it does not fail.
+fn openapi(args: &[String]) -> Result<bool> {
+    let mut spec_path = None;
+    let mut report_path = None;
+    let mut write = false;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--write" => write = true,
+            "--report" => report_path = Some(iter.next().ok_or(USAGE)?),
+            _ => spec_path = Some(arg),
+        }
+    }
+    let spec_path = spec_path.ok_or(USAGE)?;
+    let root = root();
+    let spec = openapi::parse(&serde_json::from_str(&std::fs::read_to_string(spec_path)?)?);
+
+    let mut current = vec![];
+    for (name, path) in yaml_files(&root.join("maker/api"))? {
+        current.push((
+            name,
+            serde_yaml::from_str(&std::fs::read_to_string(&path)?)?,
+        ));
+    }
+    let mut fields = BTreeMap::new();
+    for (name, path) in yaml_files(&root.join("maker/fields"))? {
+        let def: model::FieldsDef = serde_yaml::from_str(&std::fs::read_to_string(&path)?)?;
+        fields.insert(name, openapi::owned_values(&def.value));
+    }
+
+    let report = openapi::report(&spec, &current, &fields);
+    match report_path {
+        Some(path) => std::fs::write(path, report)?,
+        None => print!("{}", report),
+    }
+
+    if write {
+        for (name, def) in openapi::added(&spec, &current) {
+            let file = format!("maker/api/{}.yaml", name);
+            let path = root.join(&file);
+            if path.exists() {
+                continue;
+            }
+            std::fs::write(path, format!("---\n\n{}", serde_yaml::to_string(def)?))?;
+            eprintln!("written: {}", file);
+        }
+    }
+    Ok(true)
+}
+
+/// (file stem, definition) of `maker/<dir>/*.yaml` sorted by name.
+fn load<T: DeserializeOwned>(dir: &str) -> Result<Vec<(String, T)>> {
+    let mut result = vec![];
+    for (name, path) in yaml_files(&root().join("maker").join(dir))? {
+        result.push((
+            name,
+            serde_yaml::from_str(&std::fs::read_to_string(&path)?)?,
+        ));
+    }
+    Ok(result)
+}
+
+/// The emitted `code` is formatted and compared with the checked in `file`.
+#[cfg(test)]
+fn assert_golden(file: &str, code: &str) {
+    let expected = std::fs::read_to_string(root().join(file)).unwrap();
+    assert!(
+        rustfmt(code).unwrap() == expected,
+        "{} differs from the emitter output",
+        file
+    );
+}
+
+/// (file stem, path) sorted by name.
+fn yaml_files(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let mut result = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|it| it.to_str()) != Some("yaml") {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|it| it.to_str()) {
+            result.push((stem.to_owned(), path.clone()));
+        }
+    }
+    result.sort();
+    Ok(result)
+}
+
+fn rustfmt(code: &str) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .ok_or("rustfmt stdin")?
+        .write_all(code.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
@@ -0,0 +1,130 @@
+//! YAML definitions under `maker/`.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// `maker/api/*.yaml`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ApiDef {
+    pub url: String,
+    pub method: String,
+    /// Documented requests per 15 minutes window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitDef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<Param>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub queries: Vec<Param>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub form: Vec<Param>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<BodyProp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<ResponseDef>,
+}
+
+/// None if the authentication is not allowed.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub struct RateLimitDef {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<u32>,
+}
+
+/// Path, query and form parameters.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Param {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub required: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<i64>,
+    /// Form value taken from another field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alter: Option<String>,
+    /// Fixed form value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constant: Option<String>,
+}
+
+/// JSON body of POST and PUT requests.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BodyProp {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<BodyProp>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<BodyProp>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub required: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResponseDef {
+    #[serde(rename = "type", default, skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default)]
+    pub properties: Properties,
+}
+
+pub type Properties = IndexMap<String, ResponseProp>;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResponseProp {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub required: bool,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<ResponseProp>>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub properties: Properties,
+}
+
+/// `maker/fields/*.yaml`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FieldsDef {
+    pub value: String,
+    #[serde(default)]
+    pub methods: Vec<FieldsMethod>,
+    /// Fields the API omits when they have no value, `Option` in `select!`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optional: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FieldsMethod {
+    pub name: String,
+    pub value: String,
+}
+
+/// `maker/responses/*.yaml`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResponsesDef {
+    pub response: ResponseDef,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// Comma separated enum values.
+pub fn split_values(value: &str) -> Vec<&str> {
+    value.split(", ").collect()
+}
@@ -0,0 +1,69 @@
+//! Naming rules of the generated code.
+
+/// `tweet.fields` -> `TweetFields`, `mentionedUsers` -> `Mentionedusers`
+pub fn ucc(src: &str) -> String {
+    src.split(['_', '.'])
+        .map(|it| {
+            let mut chars = it.chars();
+            match chars.next() {
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `tweet.fields` -> `tweet_fields`
+pub fn field(src: &str) -> String {
+    src.replace('.', "_")
+}
+
+/// Rust keywords used as names.
+pub fn name(src: &str) -> String {
+    if src == "type" {
+        "r#type".to_owned()
+    } else {
+        src.to_owned()
+    }
+}
+
+/// Field name of a parameter, `type` -> `r#type`.
+pub fn member(src: &str) -> String {
+    field(&name(src))
+}
+
+/// Struct names of array items, `attachments` -> `attachment`.
+pub fn singularize(src: &str) -> String {
+    if let Some(stem) = src.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if src.ends_with("sses") || src.ends_with("xes") || src.ends_with("ches") {
+        src[..src.len() - 2].to_owned()
+    } else if src.ends_with('s') && !src.ends_with("ss") {
+        src[..src.len() - 1].to_owned()
+    } else {
+        src.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_names() {
+        assert_eq!(ucc("tweet.fields"), "TweetFields");
+        assert_eq!(ucc("mentionedUsers"), "Mentionedusers");
+        assert_eq!(
+            ucc("referenced_tweets.id.author_id"),
+            "ReferencedTweetsIdAuthorId"
+        );
+        assert_eq!(field("tweet.fields"), "tweet_fields");
+        assert_eq!(member("type"), "r#type");
+        assert_eq!(singularize("attachments"), "attachment");
+        assert_eq!(singularize("add"), "add");
+        assert_eq!(singularize("replies"), "reply");
+    }
+}
@@ -0,0 +1,715 @@
+//! Endpoints from Twitter's OpenAPI specification, and the drift against `maker/`.
+//!
+//! The specification is at https://api.twitter.com/2/openapi.json.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::Value;
+
+use crate::model::{split_values, ApiDef, BodyProp, Param, Properties, ResponseDef, ResponseProp};
+
+/// Endpoints and the values of `*.fields` found in the specification.
+#[derive(Debug, Default)]
+pub struct Spec {
+    /// (file stem of `maker/api`, definition) sorted by the stem.
+    pub apis: Vec<(String, ApiDef)>,
+    /// `tweet.fields` -> values
+    pub fields: BTreeMap<String, Vec<String>>,
+}
+
+const METHODS: [&str; 4] = ["get", "post", "put", "delete"];
+
+/// Component schemas defined in `maker/responses`.
+const REFS: [(&str, &str); 15] = [
+    ("ComplianceJob", "jobs"),
+    ("DmEvent", "dm_events"),
+    ("Expansions", "includes"),
+    ("List", "lists"),
+    ("Media", "media"),
+    ("Place", "places"),
+    ("Poll", "polls"),
+    ("Problem", "errors"),
+    ("SearchCount", "counts"),
+    ("Space", "spaces"),
+    ("Topic", "topics"),
+    ("Trend", "trends"),
+    ("Tweet", "tweets"),
+    ("Usage", "usage"),
+    ("User", "users"),
+];
+
+/// Nested schemas deeper than this become `json`.
+const MAX_DEPTH: usize = 4;
+
+pub fn parse(root: &Value) -> Spec {
+    let mut spec = Spec::default();
+    let Some(paths) = root["paths"].as_object() else {
+        return spec;
+    };
+    for (path, item) in paths {
+        if !path.starts_with("/2/") {
+            continue;
+        }
+        for method in METHODS {
+            let Some(operation) = item.get(method) else {
+                continue;
+            };
+            let def = api_def(root, path, method, item, operation, &mut spec.fields);
+            spec.apis.push((file_name(method, path), def));
+        }
+    }
+    spec.apis.sort_by(|a, b| a.0.cmp(&b.0));
+    spec
+}
+
+/// Endpoints of the specification which `maker/api` does not have.
+pub fn added<'a>(spec: &'a Spec, current: &[(String, ApiDef)]) -> Vec<&'a (String, ApiDef)> {
+    let keys: BTreeSet<String> = current.iter().map(|(_, it)| endpoint_key(it)).collect();
+    spec.apis
+        .iter()
+        .filter(|(_, it)| !keys.contains(&endpoint_key(it)))
+        .collect()
+}
+
+/// Markdown of added and removed endpoints, parameters, body properties, fields and expansions.
+pub fn report(
+    spec: &Spec,
+    current: &[(String, ApiDef)],
+    fields: &BTreeMap<String, Vec<String>>,
+) -> String {
+    let spec_map: BTreeMap<String, &(String, ApiDef)> = spec
+        .apis
+        .iter()
+        .map(|it| (endpoint_key(&it.1), it))
+        .collect();
+    let current_map: BTreeMap<String, &(String, ApiDef)> =
+        current.iter().map(|it| (endpoint_key(&it.1), it)).collect();
+
+    let mut sections = vec![];
+
+    let lines: Vec<String> = spec_map
+        .iter()
+        .filter(|(key, _)| !current_map.contains_key(*key))
+        .map(|(_, (name, def))| format!("- `{}` -> `maker/api/{}.yaml`", label(def), name))
+        .collect();
+    sections.push(("Added endpoints".to_owned(), lines));
+
+    let lines: Vec<String> = current_map
+        .iter()
+        .filter(|(key, _)| !spec_map.contains_key(*key))
+        .map(|(_, (name, def))| format!("- `{}` (`maker/api/{}.yaml`)", label(def), name))
+        .collect();
+    sections.push(("Removed endpoints".to_owned(), lines));
+
+    for (key, (name, def)) in current_map.iter() {
+        let Some((_, new)) = spec_map.get(key) else {
+            continue;
+        };
+        let lines = endpoint_drift(new, def);
+        sections.push((
+            format!("`{}` (`maker/api/{}.yaml`)", label(def), name),
+            lines,
+        ));
+    }
+
+    for (name, values) in spec.fields.iter() {
+        let file = name.trim_end_matches(".fields");
+        let lines = match fields.get(file) {
+            Some(current) => values_drift("values", values, current),
+            None => vec![format!("- missing `maker/fields/{}.yaml`", file)],
+        };
+        sections.push((format!("`{}` (`maker/fields/{}.yaml`)", name, file), lines));
+    }
+
+    let mut result = String::from("# OpenAPI drift\n");
+    let mut drift = false;
+    for (title, lines) in sections {
+        if lines.is_empty() {
+            continue;
+        }
+        drift = true;
+        result.push_str(&format!("\n## {}\n\n", title));
+        for line in lines {
+            result.push_str(&line);
+            result.push('\n');
+        }
+    }
+    if !drift {
+        result.push_str("\nNo drift.\n");
+    }
+    result
+}
+
+fn endpoint_drift(new: &ApiDef, current: &ApiDef) -> Vec<String> {
+    let mut lines = vec![];
+    let new_queries: BTreeMap<&str, &Param> = new
+        .queries
+        .iter()
+        .map(|it| (it.name.as_str(), it))
+        .collect();
+    let current_queries: BTreeMap<&str, &Param> = current
+        .queries
+        .iter()
+        .map(|it| (it.name.as_str(), it))
+        .collect();
+    for name in new_queries
+        .keys()
+        .filter(|it| !current_queries.contains_key(*it))
+    {
+        lines.push(format!("- query added: `{}`", name));
+    }
+    for name in current_queries
+        .keys()
+        .filter(|it| !new_queries.contains_key(*it))
+    {
+        lines.push(format!("- query removed: `{}`", name));
+    }
+    for (name, param) in new_queries.iter() {
+        let Some(current) = current_queries.get(name) else {
+            continue;
+        };
+        if param.kind != current.kind {
+            lines.push(format!(
+                "- query `{}` type: `{}` -> `{}`",
+                name, current.kind, param.kind
+            ));
+        }
+        if let (Some(new), Some(current)) = (&param.value, &current.value) {
+            lines.extend(values_drift(
+                &format!("query `{}` values", name),
+                &owned_values(new),
+                &owned_values(current),
+            ));
+        }
+    }
+
+    let mut new_body = BTreeMap::new();
+    if let Some(body) = &new.body {
+        flatten(body, "", &mut new_body);
+    }
+    let mut current_body = BTreeMap::new();
+    if let Some(body) = &current.body {
+        flatten(body, "", &mut current_body);
+    }
+    for name in new_body.keys().filter(|it| !current_body.contains_key(*it)) {
+        lines.push(format!("- body added: `{}`", name));
+    }
+    for name in current_body.keys().filter(|it| !new_body.contains_key(*it)) {
+        lines.push(format!("- body removed: `{}`", name));
+    }
+    for (name, prop) in new_body.iter() {
+        let Some(current) = current_body.get(name) else {
+            continue;
+        };
+        if let (Some(new), Some(current)) = (&prop.value, &current.value) {
+            lines.extend(values_drift(
+                &format!("body `{}` values", name),
+                &owned_values(new),
+                &owned_values(current),
+            ));
+        }
+    }
+    lines
+}
+
+fn values_drift(title: &str, new: &[String], current: &[String]) -> Vec<String> {
+    let mut lines = vec![];
+    let added: Vec<String> = new
+        .iter()
+        .filter(|it| !current.contains(it))
+        .map(|it| format!("`{}`", it))
+        .collect();
+    if !added.is_empty() {
+        lines.push(format!("- {} added: {}", title, added.join(", ")));
+    }
+    let removed: Vec<String> = current
+        .iter()
+        .filter(|it| !new.contains(it))
+        .map(|it| format!("`{}`", it))
+        .collect();
+    if !removed.is_empty() {
+        lines.push(format!("- {} removed: {}", title, removed.join(", ")));
+    }
+    lines
+}
+
+pub fn owned_values(value: &str) -> Vec<String> {
+    split_values(value).into_iter().map(str::to_owned).collect()
+}
+
+/// `poll.duration_minutes` -> property
+fn flatten<'a>(prop: &'a BodyProp, prefix: &str, result: &mut BTreeMap<String, &'a BodyProp>) {
+    for it in prop.properties.iter() {
+        let Some(name) = &it.name else {
+            continue;
+        };
+        let name = format!("{}{}", prefix, name);
+        flatten(it, &format!("{}.", name), result);
+        result.insert(name, it);
+    }
+}
+
+/// Path parameters compare by position, their names differ between the specification and `maker/api`.
+fn endpoint_key(def: &ApiDef) -> String {
+    let url: Vec<&str> = def
+        .url
+        .split('/')
+        .map(|it| if it.starts_with(':') { ":" } else { it })
+        .collect();
+    format!("{} {}", def.method.to_uppercase(), url.join("/"))
+}
+
+fn label(def: &ApiDef) -> String {
+    format!("{} {}", def.method.to_uppercase(), def.url)
+}
+
+/// `get`, `/2/tweets/{id}` -> `get_2_tweets_id`
+fn file_name(method: &str, path: &str) -> String {
+    let path: String = path
+        .chars()
+        .filter(|it| !matches!(it, '{' | '}'))
+        .map(|it| {
+            if it.is_ascii_alphanumeric() {
+                it.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}{}", method, path)
+}
+
+/// `/2/tweets/{id}` -> `/2/tweets/:id`
+fn url(path: &str) -> String {
+    path.replace('{', ":").replace('}', "")
+}
+
+fn api_def(
+    root: &Value,
+    path: &str,
+    method: &str,
+    item: &Value,
+    operation: &Value,
+    fields: &mut BTreeMap<String, Vec<String>>,
+) -> ApiDef {
+    let mut def = ApiDef {
+        url: url(path),
+        method: method.to_owned(),
+        ..Default::default()
+    };
+    let parameters = [&item["parameters"], &operation["parameters"]];
+    for value in parameters.iter().filter_map(|it| it.as_array()).flatten() {
+        let value = resolve(root, value);
+        let Some(name) = value["name"].as_str() else {
+            continue;
+        };
+        match value["in"].as_str() {
+            Some("path") => def.paths.push(Param {
+                name: name.to_owned(),
+                kind: "string".to_owned(),
+                ..Default::default()
+            }),
+            Some("query") => def.queries.push(query(root, name, value, fields)),
+            _ => {}
+        }
+    }
+    def.queries
+        .sort_by(|a, b| b.required.cmp(&a.required).then(a.name.cmp(&b.name)));
+
+    let schema = &operation["requestBody"]["content"]["application/json"]["schema"];
+    if !schema.is_null() {
+        def.body = Some(body_prop(root, None, schema, false, 0));
+    }
+
+    let responses = &operation["responses"];
+    let schema = ["200", "201"]
+        .iter()
+        .map(|it| &responses[*it]["content"]["application/json"]["schema"])
+        .find(|it| !it.is_null());
+    if let Some(schema) = schema {
+        let (properties, required) = object_properties(root, schema);
+        let mut response = ResponseDef {
+            kind: "object".to_owned(),
+            ..Default::default()
+        };
+        for (key, value) in properties {
+            let prop = response_prop(root, value, required.contains(&key), 0);
+            response.properties.insert(key, prop);
+        }
+        def.response = Some(response);
+    }
+    def
+}
+
+fn query(
+    root: &Value,
+    name: &str,
+    value: &Value,
+    fields: &mut BTreeMap<String, Vec<String>>,
+) -> Param {
+    let schema = resolve(root, &value["schema"]);
+    let mut param = Param {
+        name: name.to_owned(),
+        kind: "string".to_owned(),
+        required: value["required"].as_bool().unwrap_or_default(),
+        ..Default::default()
+    };
+    match schema["type"].as_str() {
+        Some("array") => {
+            if let Some(values) = enum_values(resolve(root, &schema["items"])) {
+                param.kind = "enum".to_owned();
+                if name.ends_with(".fields") {
+                    let current = fields.entry(name.to_owned()).or_default();
+                    for value in values {
+                        if !current.contains(&value) {
+                            current.push(value);
+                        }
+                    }
+                } else {
+                    param.value = Some(values.join(", "));
+                }
+            }
+        }
+        Some("string") => {
+            if let Some(values) = enum_values(schema) {
+                param.kind = "enum_single".to_owned();
+                param.value = Some(values.join(", "));
+            } else if schema["format"].as_str() == Some("date-time") {
+                param.kind = "date".to_owned();
+            }
+        }
+        Some("integer") => {
+            param.kind = "integer".to_owned();
+            param.max = schema["maximum"].as_i64();
+            param.min = schema["minimum"].as_i64();
+        }
+        Some("boolean") => param.kind = "bool".to_owned(),
+        _ => {}
+    }
+    param
+}
+
+fn body_prop(
+    root: &Value,
+    name: Option<String>,
+    schema: &Value,
+    required: bool,
+    depth: usize,
+) -> BodyProp {
+    let schema = resolve(root, schema);
+    let mut prop = BodyProp {
+        name,
+        kind: "string".to_owned(),
+        required,
+        ..Default::default()
+    };
+    match schema_type(schema) {
+        "object" if depth < MAX_DEPTH => {
+            prop.kind = "object".to_owned();
+            let (properties, required) = object_properties(root, schema);
+            for (key, value) in properties {
+                let required = required.contains(&key);
+                prop.properties
+                    .push(body_prop(root, Some(key), value, required, depth + 1));
+            }
+        }
+        "array" => {
+            prop.kind = "array".to_owned();
+            let items = body_prop(root, None, &schema["items"], false, depth + 1);
+            prop.items = Some(Box::new(items));
+        }
+        "string" => {
+            if let Some(values) = enum_values(schema) {
+                prop.kind = "enum_single".to_owned();
+                prop.value = Some(values.join(", "));
+            }
+        }
+        "integer" | "number" => prop.kind = "integer".to_owned(),
+        "boolean" => prop.kind = "bool".to_owned(),
+        _ => {}
+    }
+    prop
+}
+
+fn response_prop(root: &Value, value: &Value, required: bool, depth: usize) -> ResponseProp {
+    let mut prop = ResponseProp {
+        kind: "json".to_owned(),
+        required,
+        ..Default::default()
+    };
+    if let Some(reference) = component(value) {
+        prop.kind = "object".to_owned();
+        prop.reference = Some(reference.to_owned());
+        return prop;
+    }
+    let schema = resolve(root, value);
+    match schema_type(schema) {
+        "object" if depth < MAX_DEPTH => {
+            let (properties, required) = object_properties(root, schema);
+            if properties.is_empty() {
+                return prop;
+            }
+            prop.kind = "object".to_owned();
+            let mut result = Properties::new();
+            for (key, value) in properties {
+                let it = response_prop(root, value, required.contains(&key), depth + 1);
+                result.insert(key, it);
+            }
+            prop.properties = result;
+        }
+        "array" => {
+            prop.kind = "array".to_owned();
+            let items = response_prop(root, &schema["items"], false, depth + 1);
+            prop.items = Some(Box::new(items));
+        }
+        "string" if schema["format"].as_str() == Some("date-time") => prop.kind = "date".to_owned(),
+        "string" => prop.kind = "string".to_owned(),
+        "integer" => prop.kind = "integer".to_owned(),
+        "number" => prop.kind = "float".to_owned(),
+        "boolean" => prop.kind = "bool".to_owned(),
+        _ => {}
+    }
+    prop
+}
+
+/// `#/components/schemas/Tweet` -> `tweets`
+fn component(value: &Value) -> Option<&'static str> {
+    let reference = value["$ref"].as_str()?;
+    let name = reference.strip_prefix("#/components/schemas/")?;
+    REFS.iter().find(|(it, _)| *it == name).map(|(_, it)| *it)
+}
+
+/// Properties sorted by name, including those of `allOf`.
+fn object_properties<'a>(
+    root: &'a Value,
+    schema: &'a Value,
+) -> (Vec<(String, &'a Value)>, BTreeSet<String>) {
+    let schema = resolve(root, schema);
+    let mut properties = vec![];
+    let mut required = BTreeSet::new();
+    if let Some(map) = schema["properties"].as_object() {
+        properties.extend(map.iter().map(|(key, value)| (key.clone(), value)));
+    }
+    if let Some(values) = schema["required"].as_array() {
+        required.extend(
+            values
+                .iter()
+                .filter_map(|it| it.as_str())
+                .map(str::to_owned),
+        );
+    }
+    for it in schema["allOf"].as_array().into_iter().flatten() {
+        let (inner, inner_required) = object_properties(root, it);
+        properties.extend(inner);
+        required.extend(inner_required);
+    }
+    properties.sort_by(|a, b| a.0.cmp(&b.0));
+    properties.dedup_by(|a, b| a.0 == b.0);
+    (properties, required)
+}
+
+fn schema_type(schema: &Value) -> &str {
+    match schema["type"].as_str() {
+        Some(it) => it,
+        None if schema.get("properties").is_some() || schema.get("allOf").is_some() => "object",
+        None => "",
+    }
+}
+
+fn enum_values(schema: &Value) -> Option<Vec<String>> {
+    let values = schema["enum"].as_array()?;
+    Some(
+        values
+            .iter()
+            .filter_map(|it| it.as_str())
+            .map(str::to_owned)
+            .collect(),
+    )
+}
+
+/// Follows local `$ref`.
+fn resolve<'a>(root: &'a Value, mut value: &'a Value) -> &'a Value {
+    for _ in 0..MAX_DEPTH * 4 {
+        let Some(pointer) = value["$ref"].as_str().and_then(|it| it.strip_prefix('#')) else {
+            break;
+        };
+        match root.pointer(pointer) {
+            Some(it) => value = it,
+            None => break,
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn spec() -> Value {
+        json!({
+            "paths": {
+                "/2/tweets/{id}": {
+                    "get": {
+                        "parameters": [
+                            { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } },
+                            { "$ref": "#/components/parameters/TweetFieldsParameter" },
+                            {
+                                "name": "expansions", "in": "query",
+                                "schema": { "type": "array", "items": { "type": "string", "enum": ["author_id", "article.media_keys"] } }
+                            }
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "type": "object",
+                                            "properties": {
+                                                "data": { "$ref": "#/components/schemas/Tweet" },
+                                                "errors": { "type": "array", "items": { "$ref": "#/components/schemas/Problem" } }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                "/2/tweets/{id}/quote_tweets": {
+                    "get": {
+                        "parameters": [
+                            { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } },
+                            { "name": "max_results", "in": "query", "schema": { "type": "integer", "minimum": 10, "maximum": 100 } }
+                        ]
+                    }
+                },
+                "/2/tweets": {
+                    "post": {
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "object",
+                                        "properties": {
+                                            "text": { "type": "string" },
+                                            "reply_settings": { "type": "string", "enum": ["following", "mentionedUsers", "subscribers"] }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "parameters": {
+                    "TweetFieldsParameter": {
+                        "name": "tweet.fields", "in": "query",
+                        "schema": { "type": "array", "items": { "type": "string", "enum": ["id", "text", "article"] } }
+                    }
+                }
+            }
+        })
+    }
+
+    fn current() -> Vec<(String, ApiDef)> {
+        let get: ApiDef = serde_yaml::from_str(
+            "
+url: /2/tweets/:id
+method: get
+paths:
+  - name: id
+    type: string
+queries:
+  - name: expansions
+    type: enum
+    value: author_id, geo.place_id
+  - name: tweet.fields
+    type: enum
+  - name: user.fields
+    type: enum
+",
+        )
+        .unwrap();
+        let post: ApiDef = serde_yaml::from_str(
+            "
+url: /2/tweets
+method: post
+body:
+  type: object
+  properties:
+    - name: reply_settings
+      type: enum_single
+      value: mentionedUsers, following
+    - name: text
+      type: string
+",
+        )
+        .unwrap();
+        vec![
+            ("get_2_tweets_id".to_owned(), get),
+            ("post_2_tweets".to_owned(), post),
+        ]
+    }
+
+    #[test]
+    fn it_parse() {
+        let spec = parse(&spec());
+        let names: Vec<&str> = spec.apis.iter().map(|it| it.0.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "get_2_tweets_id",
+                "get_2_tweets_id_quote_tweets",
+                "post_2_tweets"
+            ]
+        );
+        let def = &spec.apis[0].1;
+        assert_eq!(def.url, "/2/tweets/:id");
+        assert_eq!(
+            def.queries[0].value.as_deref(),
+            Some("author_id, article.media_keys")
+        );
+        assert_eq!(def.queries[1].kind, "enum");
+        assert_eq!(def.queries[1].value, None);
+        let response = def.response.as_ref().unwrap();
+        assert_eq!(
+            response.properties["data"].reference.as_deref(),
+            Some("tweets")
+        );
+        assert_eq!(
+            response.properties["errors"]
+                .items
+                .as_ref()
+                .unwrap()
+                .reference
+                .as_deref(),
+            Some("errors")
+        );
+        assert_eq!(spec.apis[1].1.queries[0].max, Some(100));
+        assert_eq!(spec.fields["tweet.fields"], ["id", "text", "article"]);
+    }
+
+    #[test]
+    fn it_report() {
+        let spec = parse(&spec());
+        let current = current();
+        let mut fields = BTreeMap::new();
+        fields.insert("tweet".to_owned(), owned_values("id, text, geo"));
+        let report = report(&spec, &current, &fields);
+        assert!(report.contains(
+            "- `GET /2/tweets/:id/quote_tweets` -> `maker/api/get_2_tweets_id_quote_tweets.yaml`"
+        ));
+        assert!(report.contains("- query removed: `user.fields`"));
+        assert!(report.contains("- query `expansions` values added: `article.media_keys`"));
+        assert!(report.contains("- query `expansions` values removed: `geo.place_id`"));
+        assert!(report.contains("- body `reply_settings` values added: `subscribers`"));
+        assert!(report.contains("- values added: `article`"));
+        assert!(report.contains("- values removed: `geo`"));
+
+        let added = added(&spec, &current);
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].0, "get_2_tweets_id_quote_tweets");
+    }
+}
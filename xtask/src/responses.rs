@@ -0,0 +1,280 @@
+//! Response structs, `src/responses/*.rs` and `Response` of `src/api/*.rs`.
+
+use crate::{
+    model::{split_values, Properties, ResponseProp, ResponsesDef},
+    names::{name, ucc},
+};
+
+#[derive(Debug, Default)]
+pub struct Context {
+    /// `use chrono::prelude::*;` is needed.
+    pub date: bool,
+    /// Derive `arbitrary::Arbitrary`, only for `src/responses`.
+    pub arbitrary: bool,
+}
+
+pub struct Rendered {
+    pub code: String,
+    pub refs: Vec<String>,
+    /// Objects without `ref`, rendered as separate structs.
+    pub inner: Properties,
+}
+
+/// Response file. Inner objects follow the main struct.
+pub fn generate(file_name: &str, def: &ResponsesDef) -> String {
+    let mut context = Context {
+        arbitrary: true,
+        ..Default::default()
+    };
+    let rendered = render(file_name, &def.response.properties, &mut context);
+    let mut code = rendered.code;
+    for (key, value) in rendered.inner.iter() {
+        code.push('\n');
+        code.push_str(&render(key, &value.properties, &mut context).code);
+    }
+
+    let mut header = String::new();
+    if !rendered.refs.is_empty() {
+        header.push_str(&use_responses(&rendered.refs));
+    }
+    header.push_str("use serde::{Serialize, Deserialize};\n");
+    if context.date {
+        header.push_str("use chrono::prelude::*;\n");
+    }
+    format!("{}\n{}", header, code)
+}
+
+pub fn use_responses(refs: &[String]) -> String {
+    let refs: Vec<String> = refs
+        .iter()
+        .map(|it| format!("{}::{}", it, ucc(it)))
+        .collect();
+    format!("use crate::responses::{{{}}};\n", refs.join(", "))
+}
+
+pub fn render(struct_name: &str, properties: &Properties, context: &mut Context) -> Rendered {
+    let mut refs = vec![];
+    calc_refs(struct_name, properties, &mut refs);
+    let mut enums: Vec<(&str, &ResponseProp)> = vec![];
+    let mut inner = Properties::new();
+    let class_name = ucc(&crate::names::field(struct_name));
+
+    let mut code = String::new();
+    code.push_str("#[derive(Serialize, Deserialize, Debug, Clone, Default)]\n");
+    if context.arbitrary {
+        code.push_str(ARBITRARY);
+    }
+    code.push_str(&format!("pub struct {} {{\n", class_name));
+    for (key, value) in properties.iter() {
+        let type_name = response_type(key, value, context, &mut enums, &mut inner);
+        if context.arbitrary {
+            code.push_str(&arbitrary_with(value));
+        }
+        if !value.required {
+            code.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+        }
+        code.push_str(&format!("    pub {}: {},\n", name(key), type_name));
+    }
+    if context.arbitrary {
+        code.push_str("    #[cfg_attr(feature = \"arbitrary\", arbitrary(default))]\n");
+    }
+    code.push_str("    #[serde(flatten)]\n");
+    code.push_str("    pub extra: std::collections::HashMap<String, serde_json::Value>,\n");
+    code.push_str("}\n\n");
+
+    let mut checks = vec!["self.extra.is_empty()".to_owned()];
+    for (key, value) in properties.iter() {
+        let is_object_array = value.kind == "array"
+            && value.items.as_ref().map(|it| it.kind == "object") == Some(true);
+        let check = match (value.kind.as_str(), value.required) {
+            ("object", true) => format!("self.{}.is_empty_extra()", key),
+            ("object", false) => format!(
+                "self.{}.as_ref().map(|it| it.is_empty_extra()).unwrap_or(true)",
+                key
+            ),
+            (_, true) if is_object_array => {
+                format!("self.{}.iter().all(|it| it.is_empty_extra())", key)
+            }
+            (_, false) if is_object_array => format!(
+                "self.{}.as_ref().map(|it| it.iter().all(|item| item.is_empty_extra())).unwrap_or(true)",
+                key
+            ),
+            _ => continue,
+        };
+        checks.push(check);
+    }
+    code.push_str(&format!(
+        "impl {} {{
+    pub fn is_empty_extra(&self) -> bool {{
+        let res = {};
+        if !res {{
+            println!(\"{} {{:?}}\", self.extra);
+        }}
+        res
+    }}
+}}
+",
+        class_name,
+        checks.join(" &&\n        "),
+        class_name
+    ));
+
+    for (key, value) in enums {
+        code.push('\n');
+        code.push_str(&response_enum(
+            &ucc(key),
+            &split_values(value.value.as_deref().unwrap_or_default()),
+            context.arbitrary,
+        ));
+    }
+
+    Rendered { code, refs, inner }
+}
+
+const ARBITRARY: &str = "#[cfg_attr(feature = \"arbitrary\", derive(arbitrary::Arbitrary))]\n";
+
+fn calc_refs(struct_name: &str, properties: &Properties, refs: &mut Vec<String>) {
+    for (key, value) in properties.iter() {
+        if value.kind == "object" {
+            match &value.reference {
+                Some(reference) if reference != struct_name => push_unique(refs, reference),
+                Some(_) => {}
+                None if !value.properties.is_empty() => calc_refs(key, &value.properties, refs),
+                None => {}
+            }
+        } else if value.kind == "array" {
+            let Some(items) = &value.items else {
+                continue;
+            };
+            if items.kind != "object" {
+                continue;
+            }
+            if let Some(reference) = &items.reference {
+                if reference != struct_name {
+                    push_unique(refs, reference);
+                }
+            }
+        }
+    }
+}
+
+fn push_unique(refs: &mut Vec<String>, value: &str) {
+    if !refs.iter().any(|it| it == value) {
+        refs.push(value.to_owned());
+    }
+}
+
+fn items_type(items: &ResponseProp, context: &mut Context) -> String {
+    match items.kind.as_str() {
+        "integer" => "i64".to_owned(),
+        "object" => ucc(items.reference.as_deref().unwrap_or_default()),
+        "float" => "f64".to_owned(),
+        "date" => {
+            context.date = true;
+            "DateTime<Utc>".to_owned()
+        }
+        _ => "String".to_owned(),
+    }
+}
+
+fn response_type<'a>(
+    key: &'a str,
+    value: &'a ResponseProp,
+    context: &mut Context,
+    enums: &mut Vec<(&'a str, &'a ResponseProp)>,
+    inner: &mut Properties,
+) -> String {
+    let res = match value.kind.as_str() {
+        "integer" => "i64".to_owned(),
+        "float" => "f64".to_owned(),
+        "array" => format!(
+            "Vec<{}>",
+            value
+                .items
+                .as_ref()
+                .map(|it| items_type(it, context))
+                .unwrap_or_else(|| "String".to_owned())
+        ),
+        "date" => {
+            context.date = true;
+            "DateTime<Utc>".to_owned()
+        }
+        "bool" => "bool".to_owned(),
+        "json" => "serde_json::Value".to_owned(),
+        "object" => match &value.reference {
+            Some(reference) => ucc(reference),
+            None => {
+                inner.insert(key.to_owned(), value.clone());
+                ucc(key)
+            }
+        },
+        "enum_single" => {
+            enums.push((key, value));
+            ucc(key)
+        }
+        _ => "String".to_owned(),
+    };
+    if value.required {
+        res
+    } else {
+        format!("Option<{}>", res)
+    }
+}
+
+/// Values which survive a JSON round trip, see `src/fixtures.rs`.
+fn arbitrary_with(value: &ResponseProp) -> String {
+    let function = match value.kind.as_str() {
+        "date" => "date_time",
+        "float" => "f64",
+        "json" => "json",
+        "array" if value.items.as_ref().map(|it| it.kind == "float") == Some(true) => "vec_f64",
+        _ => return String::new(),
+    };
+    let function = if value.required {
+        function.to_owned()
+    } else {
+        format!("option_{}", function)
+    };
+    format!(
+        "    #[cfg_attr(feature = \"arbitrary\", arbitrary(with = crate::fixtures::{}))]\n",
+        function
+    )
+}
+
+fn response_enum(class_name: &str, values: &[&str], arbitrary: bool) -> String {
+    let mut code = String::from("#[derive(Serialize, Deserialize, Debug, Clone)]\n");
+    if arbitrary {
+        code.push_str(ARBITRARY);
+    }
+    code.push_str(&format!("pub enum {} {{\n", class_name));
+    for value in values {
+        code.push_str(&format!(
+            "    #[serde(rename = \"{}\")]\n    {},\n",
+            value,
+            ucc(value)
+        ));
+    }
+    code.push_str("}\n\n");
+    code.push_str(&crate::fields::display(class_name, values));
+    code.push('\n');
+    code.push_str(&crate::fields::default(class_name, values));
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_golden, load};
+
+    #[test]
+    fn it_generate() {
+        for (name, def) in load::<ResponsesDef>("responses").unwrap() {
+            if ["meta", "tweets"].contains(&name.as_str()) {
+                assert_golden(
+                    &format!("src/responses/{}.rs", name),
+                    &generate(&name, &def),
+                );
+            }
+        }
+    }
+}
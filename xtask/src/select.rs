@@ -0,0 +1,131 @@
+//! Field markers of typed selection, `src/select/*.rs`.
+
+use crate::{
+    model::{split_values, FieldsDef, ResponseProp, ResponsesDef},
+    names::{field, name, ucc},
+};
+
+/// `src/select/{file_name}.rs`, markers of the fields which `src/responses/{response_name}.rs` has.
+pub fn generate(
+    file_name: &str,
+    def: &FieldsDef,
+    response_name: &str,
+    response: &ResponsesDef,
+) -> String {
+    let fields_name = format!("{}Fields", ucc(&field(file_name)));
+    let source = format!(
+        "crate::responses::{}::{}",
+        response_name,
+        ucc(response_name)
+    );
+    let mut code = format!(
+        "pub type Source = {};\npub type Fields = crate::fields::{}_fields::{};\n\n",
+        source,
+        field(file_name),
+        fields_name
+    );
+    let optional = def
+        .optional
+        .as_deref()
+        .map(split_values)
+        .unwrap_or_default();
+    let mut count = 0;
+    for value in split_values(&def.value) {
+        let Some(prop) = response.response.properties.get(value) else {
+            continue;
+        };
+        let member = name(value);
+        let mut value_type = value_type(response_name, value, prop);
+        let mut take = if prop.required {
+            format!("Some(std::mem::take(&mut it.{}))", member)
+        } else {
+            format!("it.{}.take()", member)
+        };
+        // A missing optional field is not an error of select
+        if optional.contains(&value) {
+            value_type = format!("Option<{}>", value_type);
+            take = format!("Some({})", take);
+        }
+        code.push_str(&format!(
+            "#[allow(non_camel_case_types)]
+pub struct {member};
+
+impl super::Field for {member} {{
+    type Source = Source;
+    type Fields = Fields;
+    type Value = {value_type};
+    const FIELD: Self::Fields = Self::Fields::{variant};
+    const NAME: &'static str = \"{value}\";
+
+    fn take(it: &mut Self::Source) -> Option<Self::Value> {{
+        {take}
+    }}
+}}
+
+",
+            variant = ucc(value),
+        ));
+        count += 1;
+    }
+    if count == 0 {
+        return String::new();
+    }
+    code
+}
+
+/// Fully qualified, markers are named after the fields and would shadow the imported names.
+fn value_type(response_name: &str, key: &str, value: &ResponseProp) -> String {
+    match value.kind.as_str() {
+        "integer" => "i64".to_owned(),
+        "float" => "f64".to_owned(),
+        "array" => format!(
+            "Vec<{}>",
+            value
+                .items
+                .as_ref()
+                .map(|it| items_type(it))
+                .unwrap_or_else(|| "String".to_owned())
+        ),
+        "date" => "chrono::DateTime<chrono::Utc>".to_owned(),
+        "bool" => "bool".to_owned(),
+        "json" => "serde_json::Value".to_owned(),
+        "object" => match &value.reference {
+            Some(reference) => reference_type(reference),
+            None => format!("crate::responses::{}::{}", response_name, ucc(key)),
+        },
+        "enum_single" => format!("crate::responses::{}::{}", response_name, ucc(key)),
+        _ => "String".to_owned(),
+    }
+}
+
+fn items_type(items: &ResponseProp) -> String {
+    match items.kind.as_str() {
+        "integer" => "i64".to_owned(),
+        "object" => reference_type(items.reference.as_deref().unwrap_or_default()),
+        "float" => "f64".to_owned(),
+        "date" => "chrono::DateTime<chrono::Utc>".to_owned(),
+        _ => "String".to_owned(),
+    }
+}
+
+fn reference_type(reference: &str) -> String {
+    format!("crate::responses::{}::{}", reference, ucc(reference))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_golden, load};
+
+    #[test]
+    fn it_generate() {
+        let fields = load::<FieldsDef>("fields").unwrap();
+        let (_, def) = fields.iter().find(|(name, _)| name == "user").unwrap();
+        let responses = load::<ResponsesDef>("responses").unwrap();
+        let (_, response) = responses.iter().find(|(name, _)| name == "users").unwrap();
+        assert_golden(
+            "src/select/user.rs",
+            &generate("user", def, "users", response),
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Method, template, rate limit, expansions and fields of each endpoint, `src/validation/endpoints.rs`.
+
+use crate::model::{split_values, ApiDef};
+
+/// `fields` are the file stems of `maker/fields`, used to find the object of `data`.
+pub fn generate(apis: &[(String, ApiDef)], fields: &[String]) -> String {
+    let mut code = format!(
+        "use super::Endpoint;\nuse crate::endpoint::RateLimit;\n\npub(crate) const ENDPOINTS: [Endpoint; {}] = [\n",
+        apis.len()
+    );
+    for (_, def) in apis {
+        let expansions: Vec<String> = def
+            .queries
+            .iter()
+            .filter(|it| it.name == "expansions")
+            .flat_map(|it| split_values(it.value.as_deref().unwrap_or_default()))
+            .map(|it| format!("\"{}\"", it))
+            .collect();
+        let fields_params: Vec<String> = def
+            .queries
+            .iter()
+            .filter(|it| it.name.ends_with(".fields"))
+            .map(|it| format!("\"{}\"", it.name))
+            .collect();
+        let primary = match primary(def, fields) {
+            Some(it) => format!("Some(\"{}\")", it),
+            None => "None".to_owned(),
+        };
+        let rate_limit = match def.rate_limit {
+            Some(it) => format!(
+                "Some(RateLimit {{ app: {}, user: {} }})",
+                option(it.app),
+                option(it.user)
+            ),
+            None => "None".to_owned(),
+        };
+        code.push_str(&format!(
+            "    Endpoint {{
+        method: \"{}\",
+        template: \"{}\",
+        rate_limit: {},
+        primary: {},
+        expansions: &[{}],
+        fields: &[{}],
+    }},
+",
+            def.method.to_uppercase(),
+            def.url,
+            rate_limit,
+            primary,
+            expansions.join(", "),
+            fields_params.join(", ")
+        ));
+    }
+    code.push_str("];\n");
+    code
+}
+
+/// File stems of the endpoints without `rate_limit`, codegen fails on them.
+pub fn missing_rate_limits(apis: &[(String, ApiDef)]) -> Vec<&str> {
+    apis.iter()
+        .filter(|(_, def)| def.rate_limit.is_none())
+        .map(|(name, _)| name.as_str())
+        .collect()
+}
+
+fn option(value: Option<u32>) -> String {
+    match value {
+        Some(it) => format!("Some({})", it),
+        None => "None".to_owned(),
+    }
+}
+
+/// Object of `data`, `tweets` -> `tweet`.
+fn primary<'a>(def: &ApiDef, fields: &'a [String]) -> Option<&'a str> {
+    let data = def.response.as_ref()?.properties.get("data")?;
+    let reference = match &data.items {
+        Some(items) => items.reference.as_deref()?,
+        None => data.reference.as_deref()?,
+    };
+    fields
+        .iter()
+        .find(|it| *it == reference || format!("{}s", it) == reference)
+        .map(|it| it.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_golden, load, model::FieldsDef};
+
+    #[test]
+    fn it_generate() {
+        let apis = load::<ApiDef>("api").unwrap();
+        let fields: Vec<String> = load::<FieldsDef>("fields")
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_golden("src/validation/endpoints.rs", &generate(&apis, &fields));
+    }
+
+    #[test]
+    fn it_missing_rate_limits() {
+        let mut apis = load::<ApiDef>("api").unwrap();
+        assert!(missing_rate_limits(&apis).is_empty());
+        apis[0].1.rate_limit = None;
+        assert_eq!(missing_rate_limits(&apis), vec![apis[0].0.as_str()]);
+    }
+}